@@ -0,0 +1,129 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    measurement::WallTime,
+    BenchmarkGroup,
+    Criterion,
+};
+use fuel_core_storage::codec::{
+    compressed::{
+        Compressed,
+        Zstd,
+    },
+    postcard::Postcard,
+    raw::Raw,
+    Decode,
+    Encode,
+    Encoder,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        policies::Policies,
+        Transaction,
+        Witness,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::iter;
+
+// Use Jemalloc during benchmarks
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+/// The bytecode resembles the real contracts, which use a small subset
+/// of instructions with random registers and immediate values.
+fn bytecode(rng: &mut StdRng, size: usize) -> Vec<u8> {
+    iter::repeat_with(|| {
+        let register: u8 = rng.gen_range(0x10..0x20);
+        match rng.gen_range(0..4) {
+            0 => op::movi(register, rng.gen_range(0..1 << 18)),
+            1 => op::addi(register, register, rng.gen_range(0..1 << 12)),
+            2 => op::lw(register, RegId::FP, rng.gen_range(0..1 << 12)),
+            _ => op::jnzf(register, RegId::ZERO, rng.gen_range(0..1 << 12)),
+        }
+    })
+    .take(size / 4)
+    .collect()
+}
+
+fn transaction(rng: &mut StdRng, script_size: usize) -> Transaction {
+    let mut signature = [0u8; 64];
+    rng.fill(&mut signature[..]);
+    Transaction::script(
+        1_000_000,
+        bytecode(rng, script_size),
+        vec![0; 128],
+        Policies::new(),
+        vec![],
+        vec![],
+        vec![Witness::from(signature.to_vec())],
+    )
+    .into()
+}
+
+fn bench_codecs<T, Plain, Compact>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    value: &T,
+) where
+    T: ?Sized,
+    Plain: Encode<T> + Decode<T::Owned>,
+    Compact: Encode<T> + Decode<T::Owned>,
+    T: ToOwned,
+{
+    let plain = Plain::encode(value).as_bytes().into_owned();
+    let compact = Compact::encode(value).as_bytes().into_owned();
+    println!(
+        "{name}: {} bytes uncompressed, {} bytes compressed",
+        plain.len(),
+        compact.len()
+    );
+
+    group.bench_function(format!("{name} encode uncompressed"), |b| {
+        b.iter(|| black_box(Plain::encode(value).as_bytes().len()))
+    });
+    group.bench_function(format!("{name} encode compressed"), |b| {
+        b.iter(|| black_box(Compact::encode(value).as_bytes().len()))
+    });
+    group.bench_function(format!("{name} decode uncompressed"), |b| {
+        b.iter(|| black_box(Plain::decode(&plain).unwrap()))
+    });
+    group.bench_function(format!("{name} decode compressed"), |b| {
+        b.iter(|| black_box(Compact::decode(&compact).unwrap()))
+    });
+}
+
+fn compression(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0xF00DF00D);
+    let mut group = c.benchmark_group("compression");
+
+    for size in [1_024, 16 * 1_024, 100 * 1_024] {
+        let tx = transaction(&mut rng, size);
+        bench_codecs::<_, Postcard, Compressed<Postcard, Zstd>>(
+            &mut group,
+            &format!("transaction with {size} bytes script"),
+            &tx,
+        );
+
+        let code = bytecode(&mut rng, size);
+        bench_codecs::<[u8], Raw, Compressed<Raw, Zstd>>(
+            &mut group,
+            &format!("{size} bytes bytecode"),
+            code.as_slice(),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, compression);
+criterion_main!(benches);
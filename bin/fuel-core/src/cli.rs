@@ -23,7 +23,11 @@ pub fn default_db_path() -> PathBuf {
     dirs::home_dir().unwrap().join(".fuel").join("db")
 }
 
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub mod db;
 pub mod fee_contract;
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub mod rollback;
 pub mod run;
 #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
 pub mod snapshot;
@@ -48,6 +52,10 @@ pub enum Fuel {
     Run(run::Command),
     #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
     Snapshot(snapshot::Command),
+    #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+    Rollback(rollback::Command),
+    #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+    Db(db::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
@@ -128,6 +136,10 @@ pub async fn run_cli() -> anyhow::Result<()> {
             Fuel::Run(command) => run::exec(command).await,
             #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
             Fuel::Snapshot(command) => snapshot::exec(command),
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            Fuel::Rollback(command) => rollback::exec(command),
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            Fuel::Db(command) => db::exec(command),
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
         },
         Err(e) => {
@@ -165,6 +177,8 @@ mod tests {
     use std::path::PathBuf;
 
     use crate::cli::{
+        db,
+        rollback,
         snapshot,
         Fuel,
     };
@@ -266,6 +280,163 @@ mod tests {
         }
     }
 
+    mod rollback_tests {
+        use crate::cli::default_db_path;
+
+        use super::*;
+
+        #[test]
+        fn can_rollback() {
+            // given
+            let line = "./core rollback --to-height 10";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the rollback command")
+                .command;
+
+            // then
+            let Fuel::Rollback(rollback::Command {
+                database_path,
+                to_height,
+                ..
+            }) = command
+            else {
+                panic!("Expected a rollback command")
+            };
+            assert_eq!(to_height, 10);
+            assert_eq!(database_path, default_db_path().as_path());
+        }
+
+        #[test]
+        fn to_height_required() {
+            // given
+            let line = "./core rollback --db-path ./some/path";
+
+            // when
+            let result = parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+    }
+
+    mod db_tests {
+        use crate::cli::default_db_path;
+
+        use super::*;
+
+        #[test]
+        fn can_backup() {
+            // given
+            let line = "./core db backup --backup-path ./backup";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the db backup command")
+                .command;
+
+            // then
+            let Fuel::Db(db::Command {
+                subcommand:
+                    db::SubCommands::Backup {
+                        database_path,
+                        backup_path,
+                        ..
+                    },
+            }) = command
+            else {
+                panic!("Expected a db backup command")
+            };
+            assert_eq!(backup_path, PathBuf::from("./backup"));
+            assert_eq!(database_path, default_db_path().as_path());
+        }
+
+        #[test]
+        fn can_restore() {
+            // given
+            let line = "./core db restore --backup-path ./backup --db-path ./db";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the db restore command")
+                .command;
+
+            // then
+            let Fuel::Db(db::Command {
+                subcommand:
+                    db::SubCommands::Restore {
+                        backup_path,
+                        database_path,
+                    },
+            }) = command
+            else {
+                panic!("Expected a db restore command")
+            };
+            assert_eq!(backup_path, PathBuf::from("./backup"));
+            assert_eq!(database_path, PathBuf::from("./db"));
+        }
+
+        #[test]
+        fn can_verify() {
+            // given
+            let line = "./core db verify --db-path ./db";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the db verify command")
+                .command;
+
+            // then
+            let Fuel::Db(db::Command {
+                subcommand: db::SubCommands::Verify { database_path, .. },
+            }) = command
+            else {
+                panic!("Expected a db verify command")
+            };
+            assert_eq!(database_path, PathBuf::from("./db"));
+        }
+
+        #[test]
+        fn can_print_stats() {
+            // given
+            let line = "./core db stats --db-path ./db --largest-keys 10 --format json";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the db stats command")
+                .command;
+
+            // then
+            let Fuel::Db(db::Command {
+                subcommand:
+                    db::SubCommands::Stats {
+                        database_path,
+                        largest_keys,
+                        format,
+                    },
+            }) = command
+            else {
+                panic!("Expected a db stats command")
+            };
+            assert_eq!(database_path, PathBuf::from("./db"));
+            assert_eq!(largest_keys, 10);
+            assert_eq!(format, db::StatsFormat::Json);
+        }
+
+        #[test]
+        fn backup_path_required() {
+            // given
+            let line = "./core db restore --db-path ./db";
+
+            // when
+            let result = parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+    }
+
     mod snapshot_everything_tests {
         use anyhow::bail;
 
@@ -589,6 +760,13 @@ mod tests {
         use std::path::PathBuf;
 
         use crate::cli::run;
+        use fuel_core::{
+            database::{
+                change_journal::ChangeJournalPolicy,
+                modifications_history::StateRewindPolicy,
+            },
+            state::column_options::Compression,
+        };
 
         #[test]
         fn can_ask_for_db_prune() {
@@ -626,6 +804,271 @@ mod tests {
             assert!(!db_prune);
         }
 
+        #[test]
+        fn state_rewind_is_disabled_by_default() {
+            // given
+            let line = "./core run";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                state_rewind_policy,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert_eq!(state_rewind_policy, StateRewindPolicy::NoRewind);
+        }
+
+        #[test]
+        fn can_disable_the_state_rewind() {
+            // given
+            let line = "./core run --state-rewind-window 0";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                state_rewind_policy,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert_eq!(state_rewind_policy, StateRewindPolicy::NoRewind);
+        }
+
+        #[test]
+        fn can_give_a_state_rewind_window() {
+            // given
+            let line = "./core run --state-rewind-window 100";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                state_rewind_policy,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert_eq!(
+                state_rewind_policy,
+                StateRewindPolicy::RewindRange {
+                    size: 100.try_into().unwrap()
+                }
+            );
+        }
+
+        #[test]
+        fn can_keep_the_full_state_history() {
+            // given
+            let line = "./core run --state-rewind-window all";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                state_rewind_policy,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert_eq!(state_rewind_policy, StateRewindPolicy::RewindFullRange);
+        }
+
+        #[test]
+        fn change_journal_is_disabled_by_default() {
+            // given
+            let line = "./core run";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                change_journal_policy,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert_eq!(change_journal_policy, ChangeJournalPolicy::Disabled);
+        }
+
+        #[test]
+        fn can_give_a_change_journal_window() {
+            // given
+            let line = "./core run --change-journal-window 100";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                change_journal_policy,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert_eq!(
+                change_journal_policy,
+                ChangeJournalPolicy::Range {
+                    size: 100.try_into().unwrap()
+                }
+            );
+        }
+
+        #[test]
+        fn state_pruning_is_disabled_by_default() {
+            // given
+            let line = "./core run";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                state_pruning: None,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command without the state pruning");
+            };
+        }
+
+        #[test]
+        fn can_give_a_state_pruning_window() {
+            // given
+            let line = "./core run --state-pruning 1000";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                state_pruning: Some(state_pruning),
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command with the state pruning");
+            };
+
+            assert_eq!(state_pruning.get(), 1000);
+        }
+
+        #[test]
+        fn read_replica_is_disabled_by_default() {
+            // given
+            let line = "./core run";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                read_replica_of: None,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command without the read replica");
+            };
+        }
+
+        #[test]
+        fn can_run_a_read_replica() {
+            // given
+            let line = "./core run --read-replica-of ./primary --db-path ./replica --read-replica-catch-up-interval 5s";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(command) = command else {
+                panic!("Expected a run command");
+            };
+            let config = command.get_config().expect("should create the config");
+            assert!(config.is_read_replica());
+            assert_eq!(
+                config.combined_db_config.read_replica_of,
+                Some(PathBuf::from("./primary"))
+            );
+            assert_eq!(
+                config.combined_db_config.database_path,
+                PathBuf::from("./replica")
+            );
+            assert_eq!(
+                config.read_replica_catch_up_interval,
+                std::time::Duration::from_secs(5)
+            );
+        }
+
+        #[test]
+        fn read_replica_requires_an_explicit_db_path() {
+            // given
+            let line = "./core run --read-replica-of ./primary";
+            let super::Fuel::Run(command) = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command
+            else {
+                panic!("Expected a run command");
+            };
+
+            // when
+            let result = command.get_config();
+
+            // then
+            let err = result.err().expect("The default db path is rejected");
+            assert!(err.to_string().contains("explicit `--db-path`"));
+        }
+
+        #[test]
+        fn read_replica_conflicts_with_the_db_prune() {
+            // given
+            let line = "./core run --read-replica-of ./primary --db-prune";
+
+            // when
+            let result = super::parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+
         #[test]
         fn can_give_a_snapshot() {
             // given
@@ -663,5 +1106,32 @@ mod tests {
                 panic!("Expected a run command without a snapshot");
             };
         }
+
+        #[test]
+        fn column_profiles_are_loaded_from_the_file() {
+            // given
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("profiles.toml");
+            std::fs::write(
+                &path,
+                "[on_chain.ContractsRawCode]\ncompression = \"zstd\"\n",
+            )
+            .unwrap();
+            let line = format!("./core run --db-column-profiles {}", path.display());
+
+            // when
+            let super::Fuel::Run(command) = super::parse_cli(&line, "")
+                .expect("should parse the run command")
+                .command
+            else {
+                panic!("Expected a run command");
+            };
+            let config = command.get_config().expect("should load the profiles");
+
+            // then
+            let profile =
+                &config.combined_db_config.column_profiles.on_chain["ContractsRawCode"];
+            assert_eq!(profile.compression, Some(Compression::Zstd));
+        }
     }
 }
@@ -0,0 +1,230 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use fuel_core::combined_database::{
+    CombinedDatabase,
+    DatabaseStatistics,
+};
+use std::path::PathBuf;
+
+/// Backups, restores, verifies, and inspects the databases of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    pub(crate) subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates a backup of the databases. The node must be stopped, use the
+    /// `backupDatabase` GraphQL mutation to back up the running node.
+    Backup {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// The path to the directory where the backup is created.
+        #[arg(name = "BACKUP_PATH", long = "backup-path")]
+        backup_path: PathBuf,
+
+        /// The maximum database cache size in bytes.
+        #[arg(
+            long = "max-database-cache-size",
+            default_value_t = super::DEFAULT_DATABASE_CACHE_SIZE,
+            env
+        )]
+        max_database_cache_size: usize,
+    },
+    /// Restores the databases from the backup. The node must be stopped.
+    Restore {
+        /// The path to the directory with the backup.
+        #[arg(name = "BACKUP_PATH", long = "backup-path")]
+        backup_path: PathBuf,
+
+        /// The path to the database. It must not contain the databases of the node.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+    },
+    /// Verifies the integrity of the databases and prints the report of all found
+    /// mismatches. The node should be stopped.
+    Verify {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// The maximum database cache size in bytes.
+        #[arg(
+            long = "max-database-cache-size",
+            default_value_t = super::DEFAULT_DATABASE_CACHE_SIZE,
+            env
+        )]
+        max_database_cache_size: usize,
+    },
+    /// Prints the statistics of the columns of the databases: the estimated number
+    /// of keys, the size of SST files, the compression ratio, and the largest keys.
+    /// The node may keep running.
+    Stats {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// The number of the largest keys to print for each column.
+        /// Finding them requires scanning the whole database, `0` skips it.
+        #[arg(long = "largest-keys", default_value_t = 5)]
+        largest_keys: usize,
+
+        /// The format of the output.
+        #[arg(long = "format", value_enum, default_value_t = StatsFormat::Text)]
+        format: StatsFormat,
+    },
+}
+
+/// The output format of the database statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StatsFormat {
+    Text,
+    Json,
+}
+
+pub fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::Backup {
+            database_path,
+            backup_path,
+            max_database_cache_size,
+        } => {
+            let db = CombinedDatabase::open(&database_path, max_database_cache_size)
+                .map_err(Into::<anyhow::Error>::into)
+                .context(format!(
+                    "failed to open combined database at path {database_path:?}"
+                ))?;
+
+            let height = db
+                .backup(&backup_path)
+                .map_err(Into::<anyhow::Error>::into)
+                .context(format!("failed to backup the database to {backup_path:?}"))?;
+
+            tracing::info!(
+                "The database at the height {height:?} was backed up to {backup_path:?}"
+            );
+        }
+        SubCommands::Restore {
+            backup_path,
+            database_path,
+        } => {
+            CombinedDatabase::restore(&backup_path, &database_path)
+                .map_err(Into::<anyhow::Error>::into)
+                .context(format!(
+                    "failed to restore the database from {backup_path:?}"
+                ))?;
+
+            tracing::info!("The database was restored to {database_path:?}");
+        }
+        SubCommands::Verify {
+            database_path,
+            max_database_cache_size,
+        } => {
+            let db = CombinedDatabase::open(&database_path, max_database_cache_size)
+                .map_err(Into::<anyhow::Error>::into)
+                .context(format!(
+                    "failed to open combined database at path {database_path:?}"
+                ))?;
+
+            let report =
+                db.verify()
+                    .map_err(Into::<anyhow::Error>::into)
+                    .context(format!(
+                        "failed to verify the database at {database_path:?}"
+                    ))?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+
+            if !report.is_consistent() {
+                anyhow::bail!(
+                    "The database has {} inconsistencies",
+                    report.mismatches.len()
+                );
+            }
+        }
+        SubCommands::Stats {
+            database_path,
+            largest_keys,
+            format,
+        } => {
+            let statistics = CombinedDatabase::statistics(&database_path, largest_keys)
+                .map_err(Into::<anyhow::Error>::into)
+                .context(format!(
+                    "failed to collect the statistics of the database at {database_path:?}"
+                ))?;
+
+            match format {
+                StatsFormat::Text => print_statistics(&statistics),
+                StatsFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&statistics)?)
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_statistics(statistics: &DatabaseStatistics) {
+    let databases = [
+        ("on-chain", &statistics.on_chain),
+        ("off-chain", &statistics.off_chain),
+        ("relayer", &statistics.relayer),
+    ];
+
+    for (database, columns) in databases {
+        println!("{database}:");
+        println!(
+            "  {:<32} {:>16} {:>16} {:>16} {:>12}",
+            "COLUMN", "KEYS", "SST BYTES", "LIVE BYTES", "COMPRESSION"
+        );
+        for column in columns {
+            let compression_ratio = column
+                .compression_ratio
+                .map(|ratio| format!("{ratio:.2}"))
+                .unwrap_or_else(|| "-".to_string());
+            println!(
+                "  {:<32} {:>16} {:>16} {:>16} {:>12}",
+                column.column,
+                column.estimated_keys,
+                column.sst_files_size,
+                column.estimated_live_data_size,
+                compression_ratio
+            );
+            for key in &column.largest_keys {
+                println!(
+                    "    0x{} (key {} bytes, value {} bytes)",
+                    key.key, key.key_size, key.value_size
+                );
+            }
+        }
+        println!();
+    }
+}
@@ -0,0 +1,53 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    types::fuel_types::BlockHeight,
+};
+use std::path::PathBuf;
+
+/// Rollbacks the state of the blockchain to a specific block height.
+/// The height must be within the `--state-rewind-window` of the node.
+/// If the rollback is interrupted, the node doesn't start until it is repeated.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub(crate) database_path: PathBuf,
+
+    /// The maximum database cache size in bytes.
+    #[arg(
+        long = "max-database-cache-size",
+        default_value_t = super::DEFAULT_DATABASE_CACHE_SIZE,
+        env
+    )]
+    pub max_database_cache_size: usize,
+
+    /// The height of the block to roll back to.
+    /// All blocks above this height are reverted.
+    #[arg(long = "to-height")]
+    pub(crate) to_height: u32,
+}
+
+pub fn exec(command: Command) -> anyhow::Result<()> {
+    let path = command.database_path.as_path();
+    let db = CombinedDatabase::open(path, command.max_database_cache_size)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!("failed to open combined database at path {path:?}"))?;
+
+    let height = BlockHeight::from(command.to_height);
+    db.rollback_to(height)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to rollback the database to the height {height}"
+        ))?;
+
+    tracing::info!("The database was rolled back to the height {height}");
+    Ok(())
+}
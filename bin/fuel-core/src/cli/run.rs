@@ -14,6 +14,10 @@ use clap::Parser;
 use fuel_core::{
     chain_config::default_consensus_dev_key,
     combined_database::CombinedDatabaseConfig,
+    database::{
+        change_journal::ChangeJournalPolicy,
+        modifications_history::StateRewindPolicy,
+    },
     producer::Config as ProducerConfig,
     service::{
         config::Trigger,
@@ -23,6 +27,7 @@ use fuel_core::{
         ServiceTrait,
         VMConfig,
     },
+    state::column_options::ColumnProfiles,
     txpool::{
         config::BlackList,
         Config as TxPoolConfig,
@@ -49,7 +54,14 @@ use pyroscope_pprofrs::{
 use std::{
     env,
     net,
-    path::PathBuf,
+    num::{
+        NonZeroU32,
+        NonZeroU64,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
     str::FromStr,
 };
 use tracing::{
@@ -110,6 +122,68 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    /// The path to the TOML file with the options of the columns, like compression,
+    /// bloom filters, the share of the block cache, or the size of the read cache.
+    /// Tables are named after the database, and keys after the column:
+    /// `[on_chain.ContractsRawCode]`. Unset options keep the defaults.
+    #[clap(long = "db-column-profiles", env)]
+    pub database_column_profiles: Option<PathBuf>,
+
+    /// Measures reads and writes of each database column per component of the node,
+    /// like the executor or GraphQL. The measurements are exported with `--metrics`.
+    #[arg(long = "db-access-metrics", env)]
+    pub database_access_metrics: bool,
+
+    /// The directory where all accesses to the databases are written, one CSV file
+    /// per committed height. It slows down the node, so it is intended
+    /// for the offline analysis only.
+    #[clap(long = "db-access-trace", env)]
+    pub database_access_trace: Option<PathBuf>,
+
+    /// The number of the latest blocks for which the database keeps the history of
+    /// modifications. It allows requesting the state at previous heights and
+    /// reverting the latest blocks with `fuel-core rollback`.
+    /// `0` disables the history, `all` keeps the history for all blocks.
+    /// The history is disabled by default.
+    #[clap(
+        long = "state-rewind-window",
+        default_value = "0",
+        value_parser = parse_state_rewind_policy,
+        env
+    )]
+    pub state_rewind_policy: StateRewindPolicy,
+
+    /// The number of the latest blocks for which the database keeps the journal of
+    /// changes of the on-chain state. The journal is streamed by the `stateChanges`
+    /// GraphQL subscription. `0` disables the journal, `all` keeps it for all blocks.
+    #[clap(
+        long = "change-journal-window",
+        default_value = "0",
+        value_parser = parse_change_journal_policy,
+        env
+    )]
+    pub change_journal_policy: ChangeJournalPolicy,
+
+    /// The number of the latest blocks to keep in the database. Older blocks,
+    /// transactions, and their receipts are removed in the background.
+    /// By default, the node keeps all blocks.
+    #[clap(long = "state-pruning", env)]
+    pub state_pruning: Option<NonZeroU32>,
+
+    /// The path to the database of the primary node. If it is set, the node opens
+    /// the database as a read-only replica and serves only the GraphQL API.
+    /// The block production, the transaction submission, and the P2P are disabled.
+    /// The `--db-path` keeps only the own files of the replica and must be set
+    /// explicitly to a path separate from the primary's one.
+    /// The subscriptions follow the blocks of the primary with the delay
+    /// of the `--read-replica-catch-up-interval`.
+    #[clap(long = "read-replica-of", conflicts_with = "DB_PRUNE", env)]
+    pub read_replica_of: Option<PathBuf>,
+
+    /// The interval between catching up with the changes of the primary node.
+    #[clap(long = "read-replica-catch-up-interval", default_value = "1s", env)]
+    pub read_replica_catch_up_interval: humantime::Duration,
+
     /// Snapshot from which to do (re)genesis. Defaults to local testnet configuration.
     #[arg(name = "SNAPSHOT", long = "snapshot", env)]
     pub snapshot: Option<PathBuf>,
@@ -126,6 +200,11 @@ pub struct Command {
     #[arg(long = "debug", env)]
     pub debug: bool,
 
+    /// The directory for the backups created by the `backupDatabase` GraphQL mutation.
+    /// The mutation is disabled if the directory is not set.
+    #[arg(long = "backup-directory", env)]
+    pub backup_directory: Option<PathBuf>,
+
     /// Enable logging of backtraces from vm errors
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
@@ -208,10 +287,19 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            database_column_profiles,
+            database_access_metrics,
+            database_access_trace,
+            state_rewind_policy,
+            change_journal_policy,
+            state_pruning,
+            read_replica_of,
+            read_replica_catch_up_interval,
             db_prune,
             snapshot,
             vm_backtrace,
             debug,
+            backup_directory,
             utxo_validation,
             min_gas_price,
             consensus_key,
@@ -234,6 +322,12 @@ impl Command {
             profiling: _,
         } = self;
 
+        if read_replica_of.is_some() && database_path == default_db_path() {
+            anyhow::bail!(
+                "The read replica requires an explicit `--db-path` separate from the primary's database"
+            );
+        }
+
         let addr = net::SocketAddr::new(ip, port);
 
         let snapshot_reader = match snapshot.as_ref() {
@@ -290,10 +384,21 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
+        let column_profiles = database_column_profiles
+            .map(|path| load_column_profiles(&path))
+            .transpose()?
+            .unwrap_or_default();
+
         let combined_db_config = CombinedDatabaseConfig {
             database_path,
             database_type,
             max_database_cache_size,
+            state_rewind_policy,
+            change_journal_policy,
+            column_profiles,
+            access_metrics: database_access_metrics,
+            access_trace_directory: database_access_trace,
+            read_replica_of,
         };
 
         let block_importer =
@@ -357,6 +462,9 @@ impl Command {
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            state_pruning,
+            read_replica_catch_up_interval: read_replica_catch_up_interval.into(),
+            backup_directory,
         };
         Ok(config)
     }
@@ -392,6 +500,58 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn parse_state_rewind_policy(value: &str) -> anyhow::Result<StateRewindPolicy> {
+    parse_blocks_window(
+        value,
+        StateRewindPolicy::NoRewind,
+        StateRewindPolicy::RewindFullRange,
+        |size| StateRewindPolicy::RewindRange { size },
+    )
+}
+
+fn parse_change_journal_policy(value: &str) -> anyhow::Result<ChangeJournalPolicy> {
+    parse_blocks_window(
+        value,
+        ChangeJournalPolicy::Disabled,
+        ChangeJournalPolicy::FullRange,
+        |size| ChangeJournalPolicy::Range { size },
+    )
+}
+
+/// Parses the number of the latest blocks covered by the policy, where `0` means
+/// `disabled` and `all` means `full_range`.
+fn parse_blocks_window<Policy>(
+    value: &str,
+    disabled: Policy,
+    full_range: Policy,
+    range: impl FnOnce(NonZeroU64) -> Policy,
+) -> anyhow::Result<Policy> {
+    if value.eq_ignore_ascii_case("all") {
+        return Ok(full_range)
+    }
+
+    let size = u64::from_str(value).with_context(|| {
+        format!("expected a number of blocks or `all`, found `{value}`")
+    })?;
+    let policy = match NonZeroU64::new(size) {
+        Some(size) => range(size),
+        None => disabled,
+    };
+    Ok(policy)
+}
+
+fn load_column_profiles(path: &Path) -> anyhow::Result<ColumnProfiles> {
+    let content = std::fs::read_to_string(path).with_context(|| {
+        format!("failed to read the column profiles from {}", path.display())
+    })?;
+    toml::from_str(&content).with_context(|| {
+        format!(
+            "failed to parse the column profiles from {}",
+            path.display()
+        )
+    })
+}
+
 // Attempt to load the consensus key from cli arg first, otherwise check the env.
 fn load_consensus_key(
     cli_arg: Option<String>,
@@ -30,7 +30,11 @@ use fuel_core_chain_config::{
 };
 use fuel_core_storage::{
     blueprint::BlueprintInspect,
-    iter::IterDirection,
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+        KeyRange,
+    },
     structured_storage::TableWithBlueprint,
     tables::{
         Coins,
@@ -41,13 +45,21 @@ use fuel_core_storage::{
         Messages,
         Transactions,
     },
+    Result as StorageResult,
 };
 use itertools::Itertools;
+use rayon::iter::{
+    IntoParallelRefIterator,
+    ParallelIterator,
+};
 use std::path::{
     Path,
     PathBuf,
 };
 
+/// The number of shards of the key space of the table that are read in parallel.
+const SNAPSHOT_SHARDS: usize = 1024;
+
 /// Print a snapshot of blockchain state to stdout.
 #[derive(Debug, Clone, Parser)]
 pub struct Command {
@@ -244,11 +256,29 @@ fn full_snapshot(
     where
         T: TableWithBlueprint<Column = <DbDesc as DatabaseDescription>::Column>,
         T::Blueprint: BlueprintInspect<T, Database<DbDesc>>,
+        T::OwnedKey: Send,
+        T::OwnedValue: Send,
         TableEntry<T>: serde::Serialize,
         StateConfigBuilder: AddTable<T>,
         DbDesc: DatabaseDescription,
+        Database<DbDesc>: Sync,
     {
-        db.entries::<T>(None, IterDirection::Forward)
+        // The shards are read concurrently in waves of one shard per thread.
+        // It preserves the order of the entries and bounds the memory usage.
+        let shards = KeyRange::shards(SNAPSHOT_SHARDS);
+        shards
+            .chunks(rayon::current_num_threads())
+            .map(|wave| {
+                wave.par_iter()
+                    .map(|shard| {
+                        db.iter_all_in_range::<T>(shard)
+                            .map_ok(|(key, value)| TableEntry { key, value })
+                            .collect::<StorageResult<Vec<_>>>()
+                    })
+                    .collect::<StorageResult<Vec<_>>>()
+            })
+            .flatten_ok()
+            .flatten_ok()
             .chunks(group_size)
             .into_iter()
             .try_for_each(|chunk| writer.write(chunk.try_collect()?))
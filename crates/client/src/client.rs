@@ -5,7 +5,10 @@ use crate::client::{
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
+        contract::{
+            ContractBalanceQueryArgs,
+            ContractStorageProofArgs,
+        },
         gas_price::EstimateGasPrice,
         message::MessageStatusArgs,
         tx::DryRunArg,
@@ -19,6 +22,7 @@ use crate::client::{
             Address,
             AssetId,
             BlockId,
+            Bytes32,
             ContractId,
             UtxoId,
         },
@@ -678,6 +682,24 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions that use or create the contract.
+    pub async fn transactions_by_contract(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let query =
+            schema::tx::TransactionsByContractQuery::build((contract_id, request).into());
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_contract
+            .try_into()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
@@ -773,6 +795,85 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Subscribes to the blocks committed by the node. If `from_height` is set,
+    /// the stream starts with the already committed blocks from this height.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_new_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::Block>>> {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksSubscriptionArgs;
+        let s = schema::block::NewBlocksSubscription::build(BlocksSubscriptionArgs {
+            from_height: from_height.map(|height| U32(height.into())),
+        });
+
+        let stream = self.subscribe(s).await?.map(|r| Ok(r?.new_blocks.into()));
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the headers of the blocks committed by the node.
+    /// It follows the same rules as [`Self::subscribe_new_blocks`].
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_new_headers(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::block::Header>>> {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksSubscriptionArgs;
+        let s = schema::block::NewHeadersSubscription::build(BlocksSubscriptionArgs {
+            from_height: from_height.map(|height| U32(height.into())),
+        });
+
+        let stream = self.subscribe(s).await?.map(|r| Ok(r?.new_headers.into()));
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the final blocks, sent after the node finishes
+    /// their processing. If `from_height` is set, the stream starts
+    /// with the already processed blocks from this height.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_finalized_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::Block>>> {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksSubscriptionArgs;
+        let s =
+            schema::block::FinalizedBlocksSubscription::build(BlocksSubscriptionArgs {
+                from_height: from_height.map(|height| U32(height.into())),
+            });
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|r| Ok(r?.finalized_blocks.into()));
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the receipts emitted by the contract that match the `filter`.
+    /// If the `from_height` of the filter is set, the stream starts with
+    /// the already processed receipts from this height.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_logs(
+        &self,
+        filter: types::ReceiptFilter,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::ContractReceipt>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::receipt::LogsSubscription::build(
+            schema::receipt::LogsSubscriptionArgs {
+                filter: filter.into(),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(|r| Ok(r?.logs.try_into()?));
+
+        Ok(stream)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -871,6 +972,40 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Returns the proof of the storage slot `key` of the contract.
+    pub async fn contract_storage_proof(
+        &self,
+        id: &ContractId,
+        key: &Bytes32,
+    ) -> io::Result<types::ContractStorageProof> {
+        let query = schema::contract::ContractStorageProofQuery::build(
+            ContractStorageProofArgs {
+                contract: (*id).into(),
+                key: (*key).into(),
+            },
+        );
+
+        let proof = self.query(query).await?.contract_storage_proof.into();
+        Ok(proof)
+    }
+
+    /// Returns the proof of the balance of the `asset` of the contract.
+    pub async fn contract_balance_proof(
+        &self,
+        id: &ContractId,
+        asset: &AssetId,
+    ) -> io::Result<types::ContractBalanceProof> {
+        let query = schema::contract::ContractBalanceProofQuery::build(
+            ContractBalanceQueryArgs {
+                id: (*id).into(),
+                asset: (*asset).into(),
+            },
+        );
+
+        let proof = self.query(query).await?.contract_balance_proof.into();
+        Ok(proof)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -899,6 +1034,27 @@ impl FuelClient {
         Ok(balances)
     }
 
+    pub async fn asset(&self, id: &AssetId) -> io::Result<Option<types::Asset>> {
+        let query = schema::asset::AssetQuery::build(schema::asset::AssetArgs {
+            id: (*id).into(),
+        });
+        let asset = self.query(query).await?.asset.map(Into::into);
+        Ok(asset)
+    }
+
+    // Retrieve a page of assets minted by the contract
+    pub async fn assets(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Asset, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let query = schema::asset::AssetsQuery::build((contract_id, request).into());
+
+        let assets = self.query(query).await?.assets.into();
+        Ok(assets)
+    }
+
     pub async fn contract_balances(
         &self,
         contract: &ContractId,
@@ -913,6 +1069,21 @@ impl FuelClient {
         Ok(balances)
     }
 
+    // Retrieve a page of receipts emitted by the contract
+    pub async fn contract_receipts(
+        &self,
+        filter: types::ReceiptFilter,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractReceipt, String>> {
+        let query = schema::receipt::ContractReceiptsQuery::build(
+            (filter.into(), request).into(),
+        );
+
+        let receipts = self.query(query).await?.receipts.try_into()?;
+
+        Ok(receipts)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -26,6 +26,7 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
@@ -36,6 +37,7 @@ pub mod node_info;
 
 pub mod gas_price;
 pub mod primitives;
+pub mod receipt;
 pub mod tx;
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -0,0 +1,127 @@
+use crate::client::{
+    schema::{
+        schema,
+        AssetId,
+        Bytes32,
+        ContractId,
+        PageInfo,
+        U128,
+        U32,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetArgs {
+    pub id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetArgs"
+)]
+pub struct AssetQuery {
+    #[arguments(id: $id)]
+    pub asset: Option<Asset>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetsConnectionArgs {
+    /// The contract that mints the assets
+    pub contract: ContractId,
+    /// Skip until asset id (forward pagination)
+    pub after: Option<String>,
+    /// Skip until asset id (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n assets in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n assets in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)> for AssetsConnectionArgs {
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => AssetsConnectionArgs {
+                contract: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => AssetsConnectionArgs {
+                contract: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetsConnectionArgs"
+)]
+pub struct AssetsQuery {
+    #[arguments(contract: $contract, after: $after, before: $before, first: $first, last: $last)]
+    pub assets: AssetConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetConnection {
+    pub edges: Vec<AssetEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetEdge {
+    pub cursor: String,
+    pub node: Asset,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Asset {
+    pub id: AssetId,
+    pub contract_id: Option<ContractId>,
+    pub sub_id: Option<Bytes32>,
+    pub first_mint_height: U32,
+    pub total_supply: U128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetQuery::build(AssetArgs {
+            id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn assets_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetsQuery::build(AssetsConnectionArgs {
+            contract: ContractId::default(),
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -132,6 +132,44 @@ pub struct Header {
     pub application_hash: Bytes32,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksSubscriptionArgs {
+    pub from_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct NewBlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub new_blocks: Block,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct NewHeadersSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub new_headers: Header,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct FinalizedBlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub finalized_blocks: Block,
+}
+
 #[derive(cynic::InlineFragments, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum Consensus {
@@ -2,6 +2,7 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
@@ -53,6 +54,69 @@ pub struct ContractBalanceQuery {
     pub contract_balance: ContractBalance,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SparseMerkleLeaf {
+    pub key: Bytes32,
+    pub value_hash: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SparseMerkleProof {
+    pub inclusion: bool,
+    pub proof_set: Vec<Bytes32>,
+    pub exclusion_leaf: Option<SparseMerkleLeaf>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageProof {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Option<HexString>,
+    pub root: Bytes32,
+    pub proof: SparseMerkleProof,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageProofArgs {
+    pub contract: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageProofArgs"
+)]
+pub struct ContractStorageProofQuery {
+    #[arguments(contract: $contract, key: $key)]
+    pub contract_storage_proof: ContractStorageProof,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceProof {
+    pub contract: ContractId,
+    pub asset_id: AssetId,
+    pub amount: Option<U64>,
+    pub root: Bytes32,
+    pub proof: SparseMerkleProof,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceQueryArgs"
+)]
+pub struct ContractBalanceProofQuery {
+    #[arguments(contract: $id, asset: $asset)]
+    pub contract_balance_proof: ContractBalanceProof,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Contract {
@@ -147,4 +211,24 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_storage_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageProofQuery::build(ContractStorageProofArgs {
+            contract: ContractId::default(),
+            key: Bytes32::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_balance_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractBalanceProofQuery::build(ContractBalanceQueryArgs {
+            id: ContractId::default(),
+            asset: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
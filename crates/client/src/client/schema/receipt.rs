@@ -0,0 +1,145 @@
+use crate::client::{
+    schema::{
+        schema,
+        tx::transparent_receipt::{
+            Receipt,
+            ReceiptType,
+        },
+        ContractId,
+        PageInfo,
+        TransactionId,
+        U16,
+        U32,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReceiptFilterInput {
+    /// Filter receipts emitted by the contract
+    pub contract_id: ContractId,
+    /// Filter receipts by the type
+    pub receipt_type: Option<ReceiptType>,
+    /// Filter `LOG` and `LOG_DATA` receipts by the `rb` register
+    pub log_id: Option<U64>,
+    /// The lowest height of the block with receipts, inclusive
+    pub from_height: Option<U32>,
+    /// The highest height of the block with receipts, inclusive
+    pub to_height: Option<U32>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractReceiptsConnectionArgs {
+    /// Filter of the receipts
+    pub filter: ReceiptFilterInput,
+    /// Skip until the receipt cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the receipt cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n receipts in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n receipts in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ReceiptFilterInput, PaginationRequest<String>)>
+    for ContractReceiptsConnectionArgs
+{
+    fn from(r: (ReceiptFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ContractReceiptsConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractReceiptsConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractReceiptsConnectionArgs"
+)]
+pub struct ContractReceiptsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub receipts: ContractReceiptConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptConnection {
+    pub edges: Vec<ContractReceiptEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptEdge {
+    pub cursor: String,
+    pub node: ContractReceipt,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceipt {
+    pub block_height: U32,
+    pub transaction_index: U16,
+    pub receipt_index: U32,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct LogsSubscriptionArgs {
+    pub filter: ReceiptFilterInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "LogsSubscriptionArgs"
+)]
+pub struct LogsSubscription {
+    #[arguments(filter: $filter)]
+    pub logs: ContractReceipt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contract_receipts_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractReceiptsQuery::build(ContractReceiptsConnectionArgs {
+            filter: ReceiptFilterInput {
+                contract_id: ContractId::default(),
+                receipt_type: Some(ReceiptType::Log),
+                log_id: None,
+                from_height: None,
+                to_height: None,
+            },
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain_info;
@@ -9,7 +10,9 @@ pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod receipt;
 
+pub use asset::Asset;
 pub use balance::Balance;
 pub use block::{
     Block,
@@ -24,17 +27,27 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractBalanceProof,
+    ContractStorageProof,
 };
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
-pub use merkle_proof::MerkleProof;
+pub use merkle_proof::{
+    verify_sparse_merkle_proof,
+    MerkleProof,
+    SparseMerkleProof,
+};
 pub use message::{
     Message,
     MessageProof,
 };
 pub use node_info::NodeInfo;
+pub use receipt::{
+    ContractReceipt,
+    ReceiptFilter,
+};
 
 use crate::client::schema::{
     tx::{
@@ -0,0 +1,44 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+    PaginatedResult,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Asset {
+    pub id: AssetId,
+    pub contract_id: Option<ContractId>,
+    pub sub_id: Option<Bytes32>,
+    pub first_mint_height: BlockHeight,
+    pub total_supply: u128,
+}
+
+// GraphQL Translation
+
+impl From<schema::asset::Asset> for Asset {
+    fn from(value: schema::asset::Asset) -> Self {
+        Asset {
+            id: value.id.into(),
+            contract_id: value.contract_id.map(Into::into),
+            sub_id: value.sub_id.map(Into::into),
+            first_mint_height: value.first_mint_height.into(),
+            total_supply: value.total_supply.into(),
+        }
+    }
+}
+
+impl From<schema::asset::AssetConnection> for PaginatedResult<Asset, String> {
+    fn from(conn: schema::asset::AssetConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
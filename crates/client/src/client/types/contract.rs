@@ -1,10 +1,18 @@
 use crate::client::{
     schema,
-    types::primitives::{
-        AssetId,
-        Bytes,
-        ContractId,
-        Salt,
+    types::{
+        merkle_proof::{
+            verify_sparse_merkle_proof,
+            SparseMerkleProof,
+        },
+        primitives::{
+            AssetId,
+            Bytes,
+            Bytes32,
+            ContractId,
+            MerkleRoot,
+            Salt,
+        },
     },
     PaginatedResult,
 };
@@ -22,6 +30,55 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+/// The proof of the storage slot of the contract.
+#[derive(Clone, Debug)]
+pub struct ContractStorageProof {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    /// The value of the slot, `None` if the slot is empty.
+    pub value: Option<Bytes>,
+    /// The root of the contract's state tree returned by the node.
+    pub root: MerkleRoot,
+    pub proof: SparseMerkleProof,
+}
+
+impl ContractStorageProof {
+    /// Verifies that the slot has the `value` if it is present, or that the slot
+    /// is empty otherwise, in the contract's state tree with the `root`.
+    pub fn verify(&self, root: &MerkleRoot) -> bool {
+        let key = [self.contract.as_ref(), self.key.as_ref()].concat();
+        verify_sparse_merkle_proof(&self.proof, root, &key, self.value.as_deref())
+    }
+}
+
+/// The proof of the asset balance of the contract.
+#[derive(Clone, Debug)]
+pub struct ContractBalanceProof {
+    pub contract: ContractId,
+    pub asset_id: AssetId,
+    /// The balance of the asset, `None` if the contract doesn't have it.
+    pub amount: Option<u64>,
+    /// The root of the contract's balances tree returned by the node.
+    pub root: MerkleRoot,
+    pub proof: SparseMerkleProof,
+}
+
+impl ContractBalanceProof {
+    /// Verifies that the contract has the `amount` of the asset if it is present,
+    /// or that it doesn't have the balance otherwise, in the contract's balances
+    /// tree with the `root`.
+    pub fn verify(&self, root: &MerkleRoot) -> bool {
+        let key = [self.contract.as_ref(), self.asset_id.as_ref()].concat();
+        let value = self.amount.map(u64::to_be_bytes);
+        verify_sparse_merkle_proof(
+            &self.proof,
+            root,
+            &key,
+            value.as_ref().map(|value| value.as_slice()),
+        )
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -44,6 +101,30 @@ impl From<schema::contract::ContractBalance> for ContractBalance {
     }
 }
 
+impl From<schema::contract::ContractStorageProof> for ContractStorageProof {
+    fn from(value: schema::contract::ContractStorageProof) -> Self {
+        Self {
+            contract: value.contract.into(),
+            key: value.key.into(),
+            value: value.value.map(Into::into),
+            root: value.root.into(),
+            proof: value.proof.into(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractBalanceProof> for ContractBalanceProof {
+    fn from(value: schema::contract::ContractBalanceProof) -> Self {
+        Self {
+            contract: value.contract.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.map(Into::into),
+            root: value.root.into(),
+            proof: value.proof.into(),
+        }
+    }
+}
+
 impl From<schema::contract::ContractBalanceConnection>
     for PaginatedResult<ContractBalance, String>
 {
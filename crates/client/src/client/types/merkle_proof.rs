@@ -2,6 +2,15 @@ use crate::client::{
     schema,
     types::primitives::MerkleRoot,
 };
+use fuel_core_types::fuel_merkle::sparse::{
+    proof::{
+        ExclusionLeaf,
+        ExclusionLeafData,
+        ExclusionProof,
+        InclusionProof,
+    },
+    MerkleTreeKey,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MerkleProof {
@@ -11,6 +20,29 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+/// The sparse Merkle proof of the inclusion or exclusion of the key.
+pub type SparseMerkleProof = fuel_core_types::fuel_merkle::sparse::proof::Proof;
+
+/// Verifies the sparse Merkle `proof` of the `key` against the `root`.
+/// The `value` is the encoded value of the key for the inclusion proof
+/// and `None` for the exclusion proof.
+pub fn verify_sparse_merkle_proof(
+    proof: &SparseMerkleProof,
+    root: &MerkleRoot,
+    key: &[u8],
+    value: Option<&[u8]>,
+) -> bool {
+    let root: [u8; 32] = (*root).into();
+    let key = MerkleTreeKey::new(key);
+    match (proof, value) {
+        (SparseMerkleProof::Inclusion(proof), Some(value)) => {
+            proof.verify(&root, &key, value)
+        }
+        (SparseMerkleProof::Exclusion(proof), None) => proof.verify(&root, &key),
+        _ => false,
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::message::MerkleProof> for MerkleProof {
@@ -28,3 +60,25 @@ impl From<schema::message::MerkleProof> for MerkleProof {
         }
     }
 }
+
+impl From<schema::contract::SparseMerkleProof> for SparseMerkleProof {
+    fn from(value: schema::contract::SparseMerkleProof) -> Self {
+        let proof_set = value
+            .proof_set
+            .into_iter()
+            .map(|root| MerkleRoot::from(root).into())
+            .collect::<Vec<_>>();
+        if value.inclusion {
+            SparseMerkleProof::Inclusion(InclusionProof { proof_set })
+        } else {
+            let leaf = match value.exclusion_leaf {
+                Some(leaf) => ExclusionLeaf::Leaf(ExclusionLeafData {
+                    leaf_key: MerkleRoot::from(leaf.key).into(),
+                    leaf_value: MerkleRoot::from(leaf.value_hash).into(),
+                }),
+                None => ExclusionLeaf::Placeholder,
+            };
+            SparseMerkleProof::Exclusion(ExclusionProof { proof_set, leaf })
+        }
+    }
+}
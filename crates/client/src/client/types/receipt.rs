@@ -0,0 +1,99 @@
+use crate::client::{
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::primitives::{
+        ContractId,
+        TransactionId,
+    },
+    PaginatedResult,
+};
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+};
+
+pub use crate::client::schema::tx::transparent_receipt::ReceiptType;
+
+/// The filter of the receipts emitted by the contract.
+/// Only `Log`, `LogData`, `Transfer`, `Mint` and `Burn` receipts are indexed.
+#[derive(Clone, Debug)]
+pub struct ReceiptFilter {
+    pub contract_id: ContractId,
+    pub receipt_type: Option<ReceiptType>,
+    /// The `rb` register of `Log` and `LogData` receipts.
+    pub log_id: Option<u64>,
+    pub from_height: Option<BlockHeight>,
+    pub to_height: Option<BlockHeight>,
+}
+
+impl ReceiptFilter {
+    /// Creates the filter of all indexed receipts of the contract.
+    pub fn new(contract_id: ContractId) -> Self {
+        Self {
+            contract_id,
+            receipt_type: None,
+            log_id: None,
+            from_height: None,
+            to_height: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractReceipt {
+    pub block_height: BlockHeight,
+    pub transaction_index: u16,
+    pub receipt_index: u32,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+// GraphQL Translation
+
+impl From<ReceiptFilter> for schema::receipt::ReceiptFilterInput {
+    fn from(value: ReceiptFilter) -> Self {
+        schema::receipt::ReceiptFilterInput {
+            contract_id: value.contract_id.into(),
+            receipt_type: value.receipt_type,
+            log_id: value.log_id.map(Into::into),
+            from_height: value.from_height.map(|height| u32::from(height).into()),
+            to_height: value.to_height.map(|height| u32::from(height).into()),
+        }
+    }
+}
+
+impl TryFrom<schema::receipt::ContractReceipt> for ContractReceipt {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::receipt::ContractReceipt) -> Result<Self, Self::Error> {
+        Ok(ContractReceipt {
+            block_height: value.block_height.into(),
+            transaction_index: value.transaction_index.into(),
+            receipt_index: value.receipt_index.into(),
+            transaction_id: value.transaction_id.into(),
+            receipt: value.receipt.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<schema::receipt::ContractReceiptConnection>
+    for PaginatedResult<ContractReceipt, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        conn: schema::receipt::ContractReceiptConnection,
+    ) -> Result<Self, Self::Error> {
+        let results: Result<Vec<ContractReceipt>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
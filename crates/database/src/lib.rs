@@ -58,6 +58,34 @@ pub enum Error {
         /// The old height known by the database.
         prev_height: u64,
     },
+    /// The requested height is above the latest height of the database.
+    #[display(
+        fmt = "The requested height {requested_height:#x} is above the latest height {latest_height:#x}"
+    )]
+    RequestedHeightIsAboveLatest {
+        /// The requested height.
+        requested_height: u64,
+        /// The latest height known by the database.
+        latest_height: u64,
+    },
+    /// The history of modifications doesn't cover the requested height.
+    #[display(
+        fmt = "The state at the height {requested_height:#x} is not available in the history"
+    )]
+    HistoryIsNotAvailable {
+        /// The requested height.
+        requested_height: u64,
+    },
+    /// The data at the requested height was removed by the state pruning.
+    #[display(
+        fmt = "The data at the height {requested_height:#x} was pruned, the lowest available height is {lowest_available_height:#x}"
+    )]
+    HeightIsPruned {
+        /// The requested height.
+        requested_height: u64,
+        /// The lowest height available in the database.
+        lowest_available_height: u64,
+    },
 
     /// Not related to database error.
     #[from]
@@ -1,15 +1,45 @@
+#[cfg(feature = "rocksdb")]
+use crate::state::rocks_db::ColumnStatistics;
 use crate::{
     database,
     database::{
+        change_journal::ChangeJournalPolicy,
         database_description::{
             off_chain::OffChain,
             on_chain::OnChain,
             relayer::Relayer,
+            DatabaseDescription,
         },
+        modifications_history::StateRewindPolicy,
+        rollback::RollbackTarget,
+        state_pruning::LowestAvailableHeight,
         Database,
+        Error as DatabaseError,
         Result as DatabaseResult,
     },
+    graphql_api::{
+        storage::{
+            assets::AssetsMigration,
+            balances::BalancesMigration,
+            receipts::{
+                remove_contract_receipts,
+                ContractReceiptsMigration,
+            },
+            transactions::{
+                ContractTransactionsMigration,
+                OwnedTransactionIndexKey,
+                OwnedTransactions,
+                PrunedBlockTransactions,
+                PrunedTransactions,
+                TransactionContracts,
+                TransactionPointer,
+                TransactionStatuses,
+            },
+        },
+        worker_service::transaction_owners,
+    },
     service::DbType,
+    state::column_options::ColumnProfiles,
 };
 #[cfg(feature = "test-helpers")]
 use fuel_core_chain_config::{
@@ -25,14 +55,88 @@ use fuel_core_storage::tables::{
     ContractsState,
     Messages,
 };
-use fuel_core_storage::Result as StorageResult;
-use std::path::PathBuf;
+use fuel_core_storage::{
+    codec::{
+        primitive::Primitive,
+        Encode,
+        Encoder,
+    },
+    column::Column,
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    kv_store::KeyValueMutate,
+    tables::{
+        FuelBlocks,
+        SealedBlockConsensus,
+        Transactions,
+    },
+    transactional::ReadTransaction,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::txpool::TransactionStatus,
+};
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+pub mod verify;
+
+/// The number of attempts to capture the on-chain and off-chain databases at the same height.
+const BACKUP_ATTEMPTS: usize = 10;
+
+/// The time to wait for the off-chain database to catch up between backup attempts.
+const BACKUP_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The number of the latest pruned heights for which the queries of the pruned
+/// transactions fail with the pruned height. The transactions pruned earlier are
+/// reported as unknown. It is about a day of blocks with the one second block time.
+const PRUNED_TRANSACTIONS_RETENTION: u32 = 24 * 60 * 60;
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct CombinedDatabaseConfig {
     pub database_path: PathBuf,
     pub database_type: DbType,
     pub max_database_cache_size: usize,
+    pub state_rewind_policy: StateRewindPolicy,
+    /// The policy of the journal of changes of the on-chain database.
+    pub change_journal_policy: ChangeJournalPolicy,
+    /// The user-defined options of the RocksDB columns.
+    pub column_profiles: ColumnProfiles,
+    /// Measures accesses to the columns of the databases.
+    pub access_metrics: bool,
+    /// The directory for the traces of accesses to the databases.
+    /// The trace is recorded only if the directory is set.
+    pub access_trace_directory: Option<PathBuf>,
+    /// The path to the databases of the primary node. If it is set, the databases
+    /// are opened as read-only replicas of the primary, and the `database_path`
+    /// keeps only the own files of the replicas.
+    pub read_replica_of: Option<PathBuf>,
+}
+
+impl CombinedDatabaseConfig {
+    /// Returns `true` if the databases are read-only replicas of the primary node.
+    pub fn is_read_replica(&self) -> bool {
+        self.read_replica_of.is_some()
+    }
+}
+
+/// The statistics of the columns of the on-chain, off-chain and relayer databases.
+#[cfg(feature = "rocksdb")]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DatabaseStatistics {
+    pub on_chain: Vec<ColumnStatistics>,
+    pub off_chain: Vec<ColumnStatistics>,
+    pub relayer: Vec<ColumnStatistics>,
 }
 
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
@@ -64,15 +168,100 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Collects the statistics of the columns of the databases in the `path`.
+    /// The databases are opened in the read-only mode, so the node may keep running.
+    /// If `largest_keys` is not zero, each column is scanned to find its largest entries.
+    #[cfg(feature = "rocksdb")]
+    pub fn statistics(
+        path: &Path,
+        largest_keys: usize,
+    ) -> database::Result<DatabaseStatistics> {
+        use crate::state::rocks_db::RocksDb;
+
+        Ok(DatabaseStatistics {
+            on_chain: RocksDb::<OnChain>::open_read_only(path)?
+                .statistics(largest_keys)?,
+            off_chain: RocksDb::<OffChain>::open_read_only(path)?
+                .statistics(largest_keys)?,
+            relayer: RocksDb::<Relayer>::open_read_only(path)?
+                .statistics(largest_keys)?,
+        })
+    }
+
     #[cfg(feature = "rocksdb")]
     pub fn open(
         path: &std::path::Path,
         capacity: usize,
+    ) -> crate::database::Result<Self> {
+        Self::open_with_profiles(path, capacity, &Default::default())
+    }
+
+    /// Opens the databases with the default options of the columns
+    /// overridden by the `profiles`.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_with_profiles(
+        path: &std::path::Path,
+        capacity: usize,
+        profiles: &ColumnProfiles,
     ) -> crate::database::Result<Self> {
         // TODO: Use different cache sizes for different databases
-        let on_chain = Database::open_rocksdb(path, capacity)?;
-        let off_chain = Database::open_rocksdb(path, capacity)?;
-        let relayer = Database::open_rocksdb(path, capacity)?;
+        let on_chain =
+            Database::open_rocksdb_with_profiles(path, capacity, &profiles.on_chain)?;
+        let off_chain =
+            Database::open_rocksdb_with_profiles(path, capacity, &profiles.off_chain)?;
+        let relayer =
+            Database::open_rocksdb_with_profiles(path, capacity, &profiles.relayer)?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+        })
+    }
+
+    /// Opens the databases in the `primary_path` as secondary instances that keep
+    /// their own files in the `secondary_path`. The databases are read-only and follow
+    /// the primary only after [`Self::catch_up_with_primary`].
+    #[cfg(feature = "rocksdb")]
+    pub fn open_secondary(
+        primary_path: &Path,
+        secondary_path: &Path,
+        capacity: usize,
+        profiles: &ColumnProfiles,
+    ) -> crate::database::Result<Self> {
+        let on_chain = Database::open_rocksdb_secondary(
+            primary_path,
+            secondary_path,
+            capacity,
+            &profiles.on_chain,
+        )?;
+        let off_chain = Database::open_rocksdb_secondary(
+            primary_path,
+            secondary_path,
+            capacity,
+            &profiles.off_chain,
+        )?;
+        let relayer = Database::open_rocksdb_secondary(
+            primary_path,
+            secondary_path,
+            capacity,
+            &profiles.relayer,
+        )?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+        })
+    }
+
+    /// Opens the databases backed by the redb.
+    #[cfg(feature = "redb")]
+    pub fn open_redb(
+        path: &std::path::Path,
+        capacity: usize,
+    ) -> crate::database::Result<Self> {
+        let on_chain = Database::open_redb(path, capacity)?;
+        let off_chain = Database::open_redb(path, capacity)?;
+        let relayer = Database::open_redb(path, capacity)?;
         Ok(Self {
             on_chain,
             off_chain,
@@ -81,11 +270,41 @@ impl CombinedDatabase {
     }
 
     pub fn from_config(config: &CombinedDatabaseConfig) -> DatabaseResult<Self> {
+        if let Some(primary_path) = &config.read_replica_of {
+            if is_same_path(primary_path, &config.database_path) {
+                return Err(anyhow::anyhow!(
+                    "The read replica requires a path for its own files \
+                    separate from the path of the primary {:?}",
+                    primary_path
+                )
+                .into())
+            }
+        }
+
+        if config.is_read_replica() && config.database_type != DbType::RocksDb {
+            return Err(anyhow::anyhow!(
+                "Only the RocksDB database can be opened as a read replica"
+            )
+            .into())
+        }
+
         let combined_database = match config.database_type {
             #[cfg(feature = "rocksdb")]
             DbType::RocksDb => {
                 // use a default tmp rocksdb if no path is provided
-                if config.database_path.as_os_str().is_empty() {
+                if let Some(primary_path) = &config.read_replica_of {
+                    tracing::info!(
+                        "Opening database {:?} as a read replica with cache size \"{}\"",
+                        primary_path,
+                        config.max_database_cache_size
+                    );
+                    CombinedDatabase::open_secondary(
+                        primary_path,
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        &config.column_profiles,
+                    )?
+                } else if config.database_path.as_os_str().is_empty() {
                     tracing::warn!(
                         "No RocksDB path configured, initializing database with a tmp directory"
                     );
@@ -96,18 +315,117 @@ impl CombinedDatabase {
                         config.database_path,
                         config.max_database_cache_size
                     );
-                    CombinedDatabase::open(
+                    CombinedDatabase::open_with_profiles(
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        &config.column_profiles,
+                    )?
+                }
+            }
+            #[cfg(feature = "redb")]
+            DbType::Redb => {
+                if config.database_path.as_os_str().is_empty() {
+                    tracing::warn!(
+                        "No redb path configured, initializing database with a tmp directory"
+                    );
+                    CombinedDatabase::new(
+                        Database::redb_temp(),
+                        Database::redb_temp(),
+                        Database::redb_temp(),
+                    )
+                } else {
+                    tracing::info!(
+                        "Opening redb database {:?} with cache size \"{}\"",
+                        config.database_path,
+                        config.max_database_cache_size
+                    );
+                    CombinedDatabase::open_redb(
                         &config.database_path,
                         config.max_database_cache_size,
                     )?
                 }
             }
+            #[cfg(not(feature = "redb"))]
+            DbType::Redb => {
+                return Err(anyhow::anyhow!(
+                    "The redb database requires the `redb` feature of the `fuel-core`"
+                )
+                .into())
+            }
             DbType::InMemory => CombinedDatabase::in_memory(),
             #[cfg(not(feature = "rocksdb"))]
             _ => CombinedDatabase::in_memory(),
         };
 
-        Ok(combined_database)
+        let combined_database = match config.database_type {
+            // The in-memory database doesn't benefit from the cache.
+            DbType::InMemory => combined_database,
+            _ => combined_database.with_read_cache(&config.column_profiles)?,
+        };
+
+        let combined_database =
+            if config.access_metrics || config.access_trace_directory.is_some() {
+                combined_database
+                    .with_instrumentation(config.access_trace_directory.as_deref())?
+            } else {
+                combined_database
+            };
+
+        Ok(combined_database
+            .with_state_rewind_policy(config.state_rewind_policy)
+            .with_change_journal_policy(config.change_journal_policy))
+    }
+
+    /// Caches in memory the values of the columns with the non-zero `read_cache_size`.
+    pub fn with_read_cache(self, profiles: &ColumnProfiles) -> DatabaseResult<Self> {
+        Ok(Self {
+            on_chain: self.on_chain.with_read_cache(&profiles.on_chain)?,
+            off_chain: self.off_chain.with_read_cache(&profiles.off_chain)?,
+            relayer: self.relayer.with_read_cache(&profiles.relayer)?,
+        })
+    }
+
+    /// Measures accesses to the columns of the databases. If the `trace_directory`
+    /// is set, the trace of each database is written into its subdirectory.
+    pub fn with_instrumentation(
+        self,
+        trace_directory: Option<&Path>,
+    ) -> DatabaseResult<Self> {
+        let trace_directory =
+            |name: &str| trace_directory.map(|directory| directory.join(name));
+
+        Ok(Self {
+            on_chain: self
+                .on_chain
+                .with_instrumentation(trace_directory(OnChain::name()).as_deref())?,
+            off_chain: self
+                .off_chain
+                .with_instrumentation(trace_directory(OffChain::name()).as_deref())?,
+            relayer: self
+                .relayer
+                .with_instrumentation(trace_directory(Relayer::name()).as_deref())?,
+        })
+    }
+
+    /// Sets the state rewind policy for the on-chain and off-chain databases.
+    /// The relayer database doesn't support the historical views.
+    pub fn with_state_rewind_policy(self, policy: StateRewindPolicy) -> Self {
+        Self {
+            on_chain: self.on_chain.with_state_rewind_policy(policy),
+            off_chain: self.off_chain.with_state_rewind_policy(policy),
+            relayer: self.relayer,
+        }
+    }
+
+    /// Sets the policy of the journal of changes for the on-chain database.
+    /// The journal is intended for the consumers of the chain state,
+    /// which is fully defined by the on-chain database.
+    pub fn with_change_journal_policy(self, policy: ChangeJournalPolicy) -> Self {
+        Self {
+            on_chain: self.on_chain.with_change_journal_policy(policy),
+            off_chain: self.off_chain,
+            relayer: self.relayer,
+        }
     }
 
     pub fn in_memory() -> Self {
@@ -118,6 +436,31 @@ impl CombinedDatabase {
         )
     }
 
+    /// Makes visible the changes committed by the primary node into the databases.
+    /// Does nothing if the databases aren't read replicas.
+    pub fn catch_up_with_primary(&self) -> StorageResult<()> {
+        // The primary updates the off-chain database after the on-chain database.
+        // Catching up the off-chain database first keeps it behind the on-chain
+        // database, as it is on the primary.
+        self.relayer.catch_up_with_primary()?;
+        self.off_chain.catch_up_with_primary()?;
+        self.on_chain.catch_up_with_primary()?;
+        Ok(())
+    }
+
+    /// Upgrades the databases to the versions expected by this build.
+    pub fn migrate(&self) -> StorageResult<()> {
+        self.on_chain.migrate()?;
+        let off_chain_migrations = OffChain::migrations()
+            .with_migration(1, BalancesMigration::new(self.on_chain.clone()))
+            .with_migration(2, ContractReceiptsMigration::new(self.on_chain.clone()))
+            .with_migration(3, ContractTransactionsMigration::new(self.on_chain.clone()))
+            .with_migration(4, AssetsMigration::new(self.on_chain.clone()));
+        self.off_chain.migrate_with(&off_chain_migrations)?;
+        self.relayer.migrate()?;
+        Ok(())
+    }
+
     pub fn check_version(&self) -> StorageResult<()> {
         self.on_chain.check_version()?;
         self.off_chain.check_version()?;
@@ -125,6 +468,281 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Checks that the databases were left in a consistent state by the previous run:
+    /// the rollback was not interrupted, and the off-chain database
+    /// is not ahead of the on-chain database.
+    pub fn check_consistency(&self) -> StorageResult<()> {
+        if let Some(target) = self.on_chain.rollback_target()? {
+            return Err(anyhow::anyhow!(
+                "The rollback of the databases to the height {target} was interrupted, \
+                finish it with `fuel-core rollback --to-height {target}`"
+            )
+            .into())
+        }
+
+        let on_chain_height = self.on_chain.latest_height()?;
+        let off_chain_height = self.off_chain.latest_height()?;
+        if off_chain_height > on_chain_height {
+            return Err(anyhow::anyhow!(
+                "The off-chain database at the height {off_chain_height:?} is ahead \
+                of the on-chain database at the height {on_chain_height:?}"
+            )
+            .into())
+        }
+
+        Ok(())
+    }
+
+    /// Reverts the on-chain and off-chain databases to the `height`.
+    /// Both databases should have the history of modifications above the `height`,
+    /// otherwise, nothing is reverted.
+    ///
+    /// The heights are reverted one by one, so the `height` is recorded as the
+    /// [`RollbackTarget`] until both databases reach it. If the rollback is
+    /// interrupted, the node refuses to start until the rollback is repeated.
+    pub fn rollback_to(&self, height: BlockHeight) -> StorageResult<()> {
+        // The off-chain database may lag behind the on-chain database.
+        let off_chain_is_ahead = self
+            .off_chain
+            .latest_height()?
+            .map(|off_chain_height| off_chain_height > height)
+            .unwrap_or(false);
+
+        // Validates the history of both databases before modifying any of them.
+        self.on_chain.ensure_history_is_available(&height)?;
+        if off_chain_is_ahead {
+            self.off_chain.ensure_history_is_available(&height)?;
+        }
+
+        let mut transaction = self.on_chain.read_transaction();
+        transaction
+            .storage_as_mut::<RollbackTarget>()
+            .insert(&(), &height)?;
+        self.on_chain
+            .commit_changes_without_height_update(transaction.into_changes())?;
+
+        // The off-chain database is reverted first, so it never gets ahead
+        // of the on-chain database even if the rollback is interrupted.
+        if off_chain_is_ahead {
+            self.off_chain.rollback_to(&height)?;
+        }
+        self.on_chain.rollback_to(&height)?;
+
+        let mut transaction = self.on_chain.read_transaction();
+        transaction.storage_as_mut::<RollbackTarget>().remove(&())?;
+        self.on_chain
+            .commit_changes_without_height_update(transaction.into_changes())?;
+
+        Ok(())
+    }
+
+    /// Removes blocks, transactions, their statuses and owner indexes below the `height`.
+    /// The merkle data of blocks is kept, so message proofs can still be built.
+    /// At most `limit` heights are processed per call.
+    ///
+    /// Returns the lowest height available in the database after the pruning.
+    pub fn prune_blocks_below(
+        &self,
+        height: BlockHeight,
+        limit: u32,
+    ) -> StorageResult<BlockHeight> {
+        self.prune_blocks(height, limit, PRUNED_TRANSACTIONS_RETENTION)
+    }
+
+    /// See [`Self::prune_blocks_below`]. The ids of the pruned transactions are kept
+    /// for the `pruned_transactions_retention` latest pruned heights.
+    fn prune_blocks(
+        &self,
+        height: BlockHeight,
+        limit: u32,
+        pruned_transactions_retention: u32,
+    ) -> StorageResult<BlockHeight> {
+        let lowest_available_height = match self.on_chain.lowest_available_height()? {
+            Some(lowest_available_height) => lowest_available_height,
+            None => {
+                let first_block = self
+                    .on_chain
+                    .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+                    .next()
+                    .transpose()?;
+                match first_block {
+                    Some((first_block_height, _)) => first_block_height,
+                    // Nothing to prune in the empty database.
+                    None => return Ok(height),
+                }
+            }
+        };
+
+        let mut next_height = lowest_available_height;
+        for _ in 0..limit {
+            if next_height >= height {
+                break
+            }
+            self.prune_block(next_height, pruned_transactions_retention)?;
+            next_height = next_height
+                .succ()
+                .ok_or(DatabaseError::FailedToAdvanceHeight)?;
+        }
+
+        Ok(next_height)
+    }
+
+    /// Removes the data of the block at the `height` and marks the next height
+    /// as the lowest available one.
+    fn prune_block(
+        &self,
+        height: BlockHeight,
+        pruned_transactions_retention: u32,
+    ) -> StorageResult<()> {
+        let mut on_chain = self.on_chain.read_transaction();
+        let mut off_chain = self.off_chain.read_transaction();
+
+        let block = on_chain
+            .storage::<FuelBlocks>()
+            .get(&height)?
+            .map(|block| block.into_owned());
+
+        if let Some(block) = block {
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                    anyhow::anyhow!(
+                        "The block has more than `u16::MAX` transactions, {}",
+                        e
+                    )
+                })?;
+
+                let tx = on_chain.storage_as_mut::<Transactions>().remove(tx_id)?;
+                if let Some(tx) = tx {
+                    for owner in transaction_owners(&tx) {
+                        let key = OwnedTransactionIndexKey::new(&owner, height, tx_idx);
+                        off_chain.storage_as_mut::<OwnedTransactions>().remove(&key)?;
+                    }
+                }
+                off_chain
+                    .storage_as_mut::<TransactionContracts>()
+                    .remove(&TransactionPointer::new(height, tx_idx))?;
+                let status = off_chain
+                    .storage_as_mut::<TransactionStatuses>()
+                    .remove(tx_id)?;
+                if let Some(TransactionStatus::Success { receipts, .. }) = status {
+                    remove_contract_receipts(&mut off_chain, height, tx_idx, &receipts)?;
+                }
+                off_chain
+                    .storage_as_mut::<PrunedTransactions>()
+                    .insert(tx_id, &height)?;
+            }
+            off_chain
+                .storage_as_mut::<PrunedBlockTransactions>()
+                .insert(&height, block.transactions())?;
+
+            // The `FuelBlocks` table is merklized and forbids removal of entries,
+            // so the block is removed directly from the column. The merkle data
+            // of the block stays untouched.
+            let key = <Primitive<4> as Encode<BlockHeight>>::encode(&height);
+            on_chain.delete(key.as_bytes().as_ref(), Column::FuelBlocks)?;
+            on_chain
+                .storage_as_mut::<SealedBlockConsensus>()
+                .remove(&height)?;
+        }
+
+        // The ids of the transactions pruned long ago are removed,
+        // so the tables don't grow with the height of the chain.
+        if let Some(expired_height) =
+            u32::from(height).checked_sub(pruned_transactions_retention)
+        {
+            let expired_tx_ids = off_chain
+                .storage_as_mut::<PrunedBlockTransactions>()
+                .remove(&expired_height.into())?;
+            for tx_id in expired_tx_ids.into_iter().flatten() {
+                off_chain
+                    .storage_as_mut::<PrunedTransactions>()
+                    .remove(&tx_id)?;
+            }
+        }
+
+        let next_height = height.succ().ok_or(DatabaseError::FailedToAdvanceHeight)?;
+        on_chain
+            .storage_as_mut::<LowestAvailableHeight>()
+            .insert(&(), &next_height)?;
+
+        // The off-chain changes are committed first because their calculation
+        // requires the transactions from the on-chain database. If the process
+        // is interrupted in between, the next pruning repeats the removal.
+        self.off_chain
+            .commit_changes_without_height_update(off_chain.into_changes())?;
+        self.on_chain
+            .commit_changes_without_height_update(on_chain.into_changes())?;
+
+        Ok(())
+    }
+
+    /// Creates the backup of the on-chain, off-chain and relayer databases in the `path`.
+    /// The backup can be used as the database path of the node or restored with
+    /// [`CombinedDatabase::restore`].
+    ///
+    /// The off-chain database may lag behind the on-chain database, so the backup
+    /// is repeated until both databases are captured at the same height.
+    /// Returns the height of the on-chain database in the backup.
+    pub fn backup(&self, path: &Path) -> StorageResult<Option<BlockHeight>> {
+        std::fs::create_dir_all(path).map_err(|e| DatabaseError::Other(e.into()))?;
+
+        for _ in 0..BACKUP_ATTEMPTS {
+            let on_chain_height = self.on_chain.checkpoint(path)?;
+            let off_chain_height = self.off_chain.checkpoint(path)?;
+
+            if on_chain_height == off_chain_height {
+                self.relayer.checkpoint(path)?;
+                return Ok(on_chain_height)
+            }
+
+            for name in [OnChain::name(), OffChain::name()] {
+                std::fs::remove_dir_all(path.join(name))
+                    .map_err(|e| DatabaseError::Other(e.into()))?;
+            }
+            std::thread::sleep(BACKUP_RETRY_INTERVAL);
+        }
+
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The off-chain database didn't catch up with the on-chain database \
+            after {BACKUP_ATTEMPTS} attempts"
+        ))
+        .into())
+    }
+
+    /// Restores the databases from the backup in the `backup_path` into the `path`.
+    /// The version of each database in the backup is checked before it is copied,
+    /// and the `path` must not contain any of the databases.
+    #[cfg(feature = "rocksdb")]
+    pub fn restore(backup_path: &Path, path: &Path) -> StorageResult<()> {
+        use crate::state::rocks_db::RocksDb;
+        use std::sync::Arc;
+
+        let on_chain = RocksDb::<OnChain>::open_read_only(backup_path)?;
+        Database::<OnChain>::new(Arc::new(on_chain)).check_version()?;
+        let off_chain = RocksDb::<OffChain>::open_read_only(backup_path)?;
+        Database::<OffChain>::new(Arc::new(off_chain)).check_version()?;
+        let relayer = RocksDb::<Relayer>::open_read_only(backup_path)?;
+        Database::<Relayer>::new(Arc::new(relayer)).check_version()?;
+
+        let names = [OnChain::name(), OffChain::name(), Relayer::name()];
+        for name in names {
+            let target = path.join(name);
+            if target.exists() {
+                return Err(DatabaseError::Other(anyhow::anyhow!(
+                    "The database already exists at {target:?}, remove it before restoring"
+                ))
+                .into())
+            }
+        }
+
+        for name in names {
+            copy_dir(&backup_path.join(name), &path.join(name))
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+
+        Ok(())
+    }
+
     pub fn on_chain(&self) -> &Database<OnChain> {
         &self.on_chain
     }
@@ -183,3 +801,610 @@ impl CombinedDatabase {
         Ok(state_config)
     }
 }
+
+/// Copies the content of the `from` directory into the `to` directory.
+#[cfg(feature = "rocksdb")]
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the read replica `path` is empty or points to the `primary` path.
+fn is_same_path(primary: &std::path::Path, path: &std::path::Path) -> bool {
+    if path.as_os_str().is_empty() {
+        return true
+    }
+    match (primary.canonicalize(), path.canonicalize()) {
+        (Ok(primary), Ok(path)) => primary == path,
+        _ => primary == path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fuel_core_graphql_api::database::ReadDatabase,
+        graphql_api::storage::{
+            blocks::FuelBlockIdsToHeights,
+            receipts::{
+                index_contract_receipts,
+                ContractLogKey,
+                ContractLogs,
+                ContractReceiptKey,
+                ContractReceipts,
+                IndexedReceiptType,
+                ReceiptPointer,
+            },
+            transactions::TransactionContractsInfo,
+        },
+        query::{
+            SimpleTransactionData,
+            TransactionQueryData,
+        },
+    };
+    use fuel_core_storage::{
+        transactional::WriteTransaction,
+        Error as StorageError,
+        IsNotFound,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Address,
+            AssetId,
+            ContractId,
+            Input,
+            Output,
+            Receipt,
+            Transaction,
+            TransactionBuilder,
+            TxId,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+        services::txpool::TransactionStatus,
+        tai64::Tai64,
+    };
+
+    fn owner(height: u32) -> Address {
+        Address::from([u8::try_from(height).unwrap(); 32])
+    }
+
+    fn contract_id(height: u32) -> ContractId {
+        ContractId::from([u8::try_from(height).unwrap(); 32])
+    }
+
+    fn insert_block_with_tx(database: &mut CombinedDatabase, height: u32) -> Transaction {
+        let chain_id = ChainId::default();
+        let tx: Transaction = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id(height),
+            ))
+            .add_output(Output::contract(0, Default::default(), Default::default()))
+            .add_output(Output::coin(owner(height), 10, AssetId::default()))
+            .finalize_as_transaction();
+        let tx_id = tx.id(&chain_id);
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![tx.clone()])
+            .generate(&[], Default::default());
+
+        let mut transaction = database.on_chain_mut().write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height.into(), &block.compress(&chain_id))
+            .unwrap();
+        transaction
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        transaction.commit().unwrap();
+
+        let mut transaction = database.off_chain_mut().write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&block.id(), &height.into())
+            .unwrap();
+        let receipts = vec![Receipt::log(contract_id(height), 1, 0, 0, 0, 0, 0)];
+        index_contract_receipts(&mut transaction, height.into(), 0, &tx_id, &receipts)
+            .unwrap();
+        transaction
+            .storage_as_mut::<TransactionStatuses>()
+            .insert(
+                &tx_id,
+                &TransactionStatus::Success {
+                    block_height: height.into(),
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                    receipts,
+                },
+            )
+            .unwrap();
+        let key = OwnedTransactionIndexKey::new(&owner(height), height.into(), 0);
+        transaction
+            .storage_as_mut::<OwnedTransactions>()
+            .insert(&key, &tx_id)
+            .unwrap();
+        let info = TransactionContractsInfo {
+            tx_id,
+            contracts: vec![contract_id(height)],
+        };
+        transaction
+            .storage_as_mut::<TransactionContracts>()
+            .insert(&TransactionPointer::new(height.into(), 0), &info)
+            .unwrap();
+        transaction.commit().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn rollback_to_reverts_both_databases_and_removes_the_target() {
+        // Given
+        let mut database = CombinedDatabase::in_memory()
+            .with_state_rewind_policy(StateRewindPolicy::RewindFullRange);
+        for height in 0..=3u32 {
+            insert_block_with_tx(&mut database, height);
+        }
+
+        // When
+        database.rollback_to(1.into()).unwrap();
+
+        // Then
+        assert_eq!(database.on_chain().latest_height().unwrap(), Some(1.into()));
+        assert_eq!(
+            database.off_chain().latest_height().unwrap(),
+            Some(1.into())
+        );
+        assert_eq!(database.on_chain().rollback_target().unwrap(), None);
+        assert!(database
+            .on_chain()
+            .storage::<FuelBlocks>()
+            .get(&2.into())
+            .unwrap()
+            .is_none());
+        database.check_consistency().unwrap();
+    }
+
+    #[test]
+    fn check_consistency_fails_if_the_rollback_was_interrupted() {
+        // Given
+        let mut database = CombinedDatabase::in_memory();
+        insert_block_with_tx(&mut database, 0);
+        let mut transaction = database.on_chain_mut().write_transaction();
+        transaction
+            .storage_as_mut::<RollbackTarget>()
+            .insert(&(), &0.into())
+            .unwrap();
+        let changes = transaction.into_changes();
+        database
+            .on_chain()
+            .commit_changes_without_height_update(changes)
+            .unwrap();
+
+        // When
+        let result = database.check_consistency();
+
+        // Then
+        let err = result.expect_err("The rollback is not finished");
+        assert!(err.to_string().contains("was interrupted"));
+    }
+
+    #[test]
+    fn check_consistency_fails_if_the_off_chain_database_is_ahead() {
+        // Given
+        let mut database = CombinedDatabase::in_memory();
+        let mut transaction = database.off_chain_mut().write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&Default::default(), &1.into())
+            .unwrap();
+        transaction.commit().unwrap();
+
+        // When
+        let result = database.check_consistency();
+
+        // Then
+        let err = result.expect_err("The off-chain database is ahead");
+        assert!(err.to_string().contains("is ahead"));
+    }
+
+    #[test]
+    fn prune_blocks_below_removes_blocks_and_transactions() {
+        // Given
+        let mut database = CombinedDatabase::in_memory();
+        let txs = (0..=3u32)
+            .map(|height| insert_block_with_tx(&mut database, height))
+            .collect::<Vec<_>>();
+        let chain_id = ChainId::default();
+
+        // When
+        let lowest_available_height =
+            database.prune_blocks_below(2.into(), u32::MAX).unwrap();
+
+        // Then
+        assert_eq!(lowest_available_height, 2.into());
+        assert_eq!(
+            database.on_chain().lowest_available_height().unwrap(),
+            Some(2.into())
+        );
+        for (height, tx) in (0..=3u32).zip(txs.iter()) {
+            let is_pruned = height < 2;
+            let tx_id = tx.id(&chain_id);
+            let key = OwnedTransactionIndexKey::new(&owner(height), height.into(), 0);
+            let receipt_key = ContractReceiptKey::new(
+                &contract_id(height),
+                IndexedReceiptType::Log,
+                ReceiptPointer::new(height.into(), 0, 0),
+            );
+            let log_key = ContractLogKey::new(
+                &contract_id(height),
+                IndexedReceiptType::Log,
+                0,
+                ReceiptPointer::new(height.into(), 0, 0),
+            );
+            let on_chain = database.on_chain();
+            let off_chain = database.off_chain();
+            assert_eq!(
+                on_chain
+                    .storage::<FuelBlocks>()
+                    .get(&height.into())
+                    .unwrap()
+                    .is_none(),
+                is_pruned
+            );
+            assert_eq!(
+                on_chain
+                    .storage::<Transactions>()
+                    .get(&tx_id)
+                    .unwrap()
+                    .is_none(),
+                is_pruned
+            );
+            assert_eq!(
+                off_chain
+                    .storage::<TransactionStatuses>()
+                    .get(&tx_id)
+                    .unwrap()
+                    .is_none(),
+                is_pruned
+            );
+            assert_eq!(
+                off_chain
+                    .storage::<OwnedTransactions>()
+                    .get(&key)
+                    .unwrap()
+                    .is_none(),
+                is_pruned
+            );
+            assert_eq!(
+                off_chain
+                    .contract_transactions(contract_id(height), None, None)
+                    .next()
+                    .is_none(),
+                is_pruned
+            );
+            assert_eq!(
+                off_chain
+                    .storage::<ContractReceipts>()
+                    .get(&receipt_key)
+                    .unwrap()
+                    .is_none(),
+                is_pruned
+            );
+            assert_eq!(
+                off_chain
+                    .storage::<ContractLogs>()
+                    .get(&log_key)
+                    .unwrap()
+                    .is_none(),
+                is_pruned
+            );
+        }
+        // The merkle data is kept for the message proofs.
+        database
+            .on_chain()
+            .block_history_proof(&1.into(), &3.into())
+            .expect("The merkle proof should be available for pruned blocks");
+        // The height of the database is not affected by the pruning.
+        assert_eq!(database.on_chain().latest_height().unwrap(), Some(3.into()));
+    }
+
+    #[test]
+    fn prune_blocks_below_fails_queries_of_pruned_transactions() {
+        // Given
+        let mut database = CombinedDatabase::in_memory();
+        let txs = (0..=3u32)
+            .map(|height| insert_block_with_tx(&mut database, height))
+            .collect::<Vec<_>>();
+        let chain_id = ChainId::default();
+        let pruned_tx_id = txs[1].id(&chain_id);
+        let available_tx_id = txs[2].id(&chain_id);
+
+        // When
+        database.prune_blocks_below(2.into(), u32::MAX).unwrap();
+
+        // Then
+        assert_eq!(
+            database
+                .off_chain()
+                .storage::<PrunedTransactions>()
+                .get(&pruned_tx_id)
+                .unwrap()
+                .map(|height| height.into_owned()),
+            Some(1.into())
+        );
+        let view =
+            ReadDatabase::new(database.on_chain().clone(), database.off_chain().clone())
+                .view()
+                .unwrap();
+        assert!(matches!(
+            view.transaction(&pruned_tx_id),
+            Err(StorageError::DatabaseError(_))
+        ));
+        assert!(matches!(
+            view.status(&pruned_tx_id),
+            Err(StorageError::DatabaseError(_))
+        ));
+        assert!(view.transaction(&available_tx_id).is_ok());
+        assert!(view
+            .transaction(&TxId::zeroed())
+            .unwrap_err()
+            .is_not_found());
+    }
+
+    #[test]
+    fn prune_blocks_forgets_transactions_pruned_before_the_retention() {
+        // Given
+        let mut database = CombinedDatabase::in_memory();
+        let txs = (0..=3u32)
+            .map(|height| insert_block_with_tx(&mut database, height))
+            .collect::<Vec<_>>();
+        let chain_id = ChainId::default();
+        let expired_tx_id = txs[0].id(&chain_id);
+        let retained_tx_id = txs[1].id(&chain_id);
+
+        // When
+        database.prune_blocks(2.into(), u32::MAX, 1).unwrap();
+
+        // Then
+        let off_chain = database.off_chain();
+        assert!(off_chain
+            .storage::<PrunedTransactions>()
+            .get(&expired_tx_id)
+            .unwrap()
+            .is_none());
+        assert!(off_chain
+            .storage::<PrunedBlockTransactions>()
+            .get(&0.into())
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            off_chain
+                .storage::<PrunedTransactions>()
+                .get(&retained_tx_id)
+                .unwrap()
+                .map(|height| height.into_owned()),
+            Some(1.into())
+        );
+        let view =
+            ReadDatabase::new(database.on_chain().clone(), database.off_chain().clone())
+                .view()
+                .unwrap();
+        assert!(view.transaction(&expired_tx_id).unwrap_err().is_not_found());
+        assert!(matches!(
+            view.transaction(&retained_tx_id),
+            Err(StorageError::DatabaseError(_))
+        ));
+    }
+
+    #[test]
+    fn prune_blocks_below_respects_the_limit() {
+        // Given
+        let mut database = CombinedDatabase::in_memory();
+        for height in 0..=3u32 {
+            insert_block_with_tx(&mut database, height);
+        }
+
+        // When
+        let first = database.prune_blocks_below(3.into(), 2).unwrap();
+        let second = database.prune_blocks_below(3.into(), 2).unwrap();
+
+        // Then
+        assert_eq!(first, 2.into());
+        assert_eq!(second, 3.into());
+        assert!(database
+            .on_chain()
+            .storage::<FuelBlocks>()
+            .get(&2.into())
+            .unwrap()
+            .is_none());
+        assert!(database
+            .on_chain()
+            .storage::<FuelBlocks>()
+            .get(&3.into())
+            .unwrap()
+            .is_some());
+    }
+
+    #[cfg(feature = "rocksdb")]
+    #[test]
+    fn restored_backup_contains_databases_at_the_same_height() {
+        // Given
+        let mut database = CombinedDatabase::default();
+        for height in 0..=2u32 {
+            insert_block_with_tx(&mut database, height);
+        }
+        let backup_dir = tempfile::TempDir::new().unwrap();
+        let db_dir = tempfile::TempDir::new().unwrap();
+
+        // When
+        let height = database.backup(backup_dir.path()).unwrap();
+        CombinedDatabase::restore(backup_dir.path(), db_dir.path()).unwrap();
+
+        // Then
+        assert_eq!(height, Some(2.into()));
+        let restored = CombinedDatabase::open(db_dir.path(), 1024 * 1024).unwrap();
+        assert_eq!(restored.on_chain().latest_height().unwrap(), Some(2.into()));
+        assert_eq!(restored.off_chain().latest_height().unwrap(), Some(2.into()));
+        assert!(restored
+            .on_chain()
+            .storage::<FuelBlocks>()
+            .get(&2.into())
+            .unwrap()
+            .is_some());
+    }
+
+    #[cfg(feature = "rocksdb")]
+    #[test]
+    fn restore_fails_if_the_database_exists() {
+        // Given
+        let mut database = CombinedDatabase::default();
+        insert_block_with_tx(&mut database, 0);
+        let backup_dir = tempfile::TempDir::new().unwrap();
+        let db_dir = tempfile::TempDir::new().unwrap();
+        database.backup(backup_dir.path()).unwrap();
+        CombinedDatabase::open(db_dir.path(), 1024 * 1024).unwrap();
+
+        // When
+        let result = CombinedDatabase::restore(backup_dir.path(), db_dir.path());
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "rocksdb")]
+    #[test]
+    fn read_replica_sees_blocks_of_the_primary_after_catching_up() {
+        // Given
+        let primary_dir = tempfile::TempDir::new().unwrap();
+        let replica_dir = tempfile::TempDir::new().unwrap();
+        let mut primary =
+            CombinedDatabase::open(primary_dir.path(), 1024 * 1024).unwrap();
+        insert_block_with_tx(&mut primary, 0);
+        let replica = CombinedDatabase::open_secondary(
+            primary_dir.path(),
+            replica_dir.path(),
+            1024 * 1024,
+            &Default::default(),
+        )
+        .unwrap();
+        insert_block_with_tx(&mut primary, 1);
+        assert_eq!(replica.on_chain().latest_height().unwrap(), Some(0.into()));
+
+        // When
+        replica.catch_up_with_primary().unwrap();
+
+        // Then
+        assert_eq!(replica.on_chain().latest_height().unwrap(), Some(1.into()));
+        assert_eq!(replica.off_chain().latest_height().unwrap(), Some(1.into()));
+        assert!(replica
+            .on_chain()
+            .storage::<FuelBlocks>()
+            .get(&1.into())
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn from_config_fails_for_read_replica_in_the_primary_path() {
+        // Given
+        let primary = tempfile::TempDir::new().unwrap();
+        let config = CombinedDatabaseConfig {
+            database_path: primary.path().join("."),
+            database_type: DbType::RocksDb,
+            max_database_cache_size: 1024 * 1024,
+            state_rewind_policy: Default::default(),
+            change_journal_policy: Default::default(),
+            column_profiles: Default::default(),
+            access_metrics: false,
+            access_trace_directory: None,
+            read_replica_of: Some(primary.path().to_path_buf()),
+        };
+
+        // When
+        let result = CombinedDatabase::from_config(&config);
+
+        // Then
+        let err = result.err().expect("The replica path must be separate");
+        assert!(err
+            .to_string()
+            .contains("separate from the path of the primary"));
+    }
+
+    #[test]
+    fn from_config_fails_for_read_replica_without_path() {
+        // Given
+        let primary = tempfile::TempDir::new().unwrap();
+        let config = CombinedDatabaseConfig {
+            database_path: Default::default(),
+            database_type: DbType::RocksDb,
+            max_database_cache_size: 1024 * 1024,
+            state_rewind_policy: Default::default(),
+            change_journal_policy: Default::default(),
+            column_profiles: Default::default(),
+            access_metrics: false,
+            access_trace_directory: None,
+            read_replica_of: Some(primary.path().to_path_buf()),
+        };
+
+        // When
+        let result = CombinedDatabase::from_config(&config);
+
+        // Then
+        let err = result.err().expect("The replica path is required");
+        assert!(err
+            .to_string()
+            .contains("separate from the path of the primary"));
+    }
+
+    #[cfg(not(feature = "redb"))]
+    #[test]
+    fn from_config_fails_for_redb_without_feature() {
+        // Given
+        let config = CombinedDatabaseConfig {
+            database_path: Default::default(),
+            database_type: DbType::Redb,
+            max_database_cache_size: 1024 * 1024,
+            state_rewind_policy: Default::default(),
+            change_journal_policy: Default::default(),
+            column_profiles: Default::default(),
+            access_metrics: false,
+            access_trace_directory: None,
+            read_replica_of: None,
+        };
+
+        // When
+        let result = CombinedDatabase::from_config(&config);
+
+        // Then
+        let err = result.err().expect("The redb database is not available");
+        assert!(err.to_string().contains("`redb` feature"));
+    }
+}
@@ -0,0 +1,603 @@
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            DatabaseDescription,
+        },
+        Database,
+    },
+    graphql_api::storage::{
+        coins::{
+            owner_coin_id_key,
+            OwnedCoins,
+        },
+        messages::{
+            OwnedMessageIds,
+            OwnedMessageKey,
+        },
+        transactions::TransactionStatuses,
+    },
+};
+use fuel_core_storage::{
+    codec::{
+        primitive::Primitive,
+        Decode,
+    },
+    column::Column,
+    iter::{
+        IterDirection,
+        IterableStore,
+        IterableTable,
+        IteratorOverTable,
+    },
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleMetadata,
+            DenseMetadataKey,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+            SparseMerkleMetadata,
+        },
+        Coins,
+        FuelBlocks,
+        Messages,
+        Transactions,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsRef,
+    StorageInspect,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_merkle::{
+        binary,
+        sparse::{
+            in_memory,
+            MerkleTreeKey,
+        },
+    },
+    fuel_tx::{
+        Address,
+        Bytes32,
+        ContractId,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::txpool::TransactionStatus,
+};
+use rayon::iter::ParallelIterator;
+use std::collections::BTreeSet;
+
+/// The number of shards of the key space of the large tables, like coins,
+/// that are scanned in parallel during the verification.
+const SCAN_SHARDS: usize = 256;
+
+/// The inconsistency of the data found by the verification of the databases.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Mismatch {
+    /// The block doesn't have the metadata in the block merkle tree.
+    MissingBlockMerkleMetadata { height: BlockHeight },
+    /// The root calculated from the nodes of the block merkle tree differs from
+    /// the root stored in the metadata.
+    BlockMerkleRoot {
+        height: BlockHeight,
+        stored: Bytes32,
+        calculated: Bytes32,
+    },
+    /// The leaf of the block merkle tree doesn't match the id of the block.
+    BlockMerkleLeaf {
+        height: BlockHeight,
+        block_id: BlockId,
+    },
+    /// The root of the contract assets differs from the root stored in the metadata.
+    ContractAssetsRoot {
+        contract_id: ContractId,
+        stored: Bytes32,
+        calculated: Bytes32,
+    },
+    /// The root of the contract state differs from the root stored in the metadata.
+    ContractStateRoot {
+        contract_id: ContractId,
+        stored: Bytes32,
+        calculated: Bytes32,
+    },
+    /// The coin is not indexed by its owner in the off-chain database.
+    MissingOwnedCoin { owner: Address, utxo_id: UtxoId },
+    /// The off-chain index refers to the coin that doesn't exist or has another owner.
+    UnknownOwnedCoin { owner: Address, utxo_id: UtxoId },
+    /// The message is not indexed by its recipient in the off-chain database.
+    MissingOwnedMessage { recipient: Address, nonce: Nonce },
+    /// The off-chain index refers to the message that doesn't exist
+    /// or has another recipient.
+    UnknownOwnedMessage { recipient: Address, nonce: Nonce },
+    /// The included transaction doesn't have the status in the off-chain database.
+    MissingTransactionStatus { tx_id: TxId },
+    /// The off-chain database has the execution status of the transaction
+    /// that is not included in the on-chain database.
+    UnknownTransactionStatus { tx_id: TxId },
+}
+
+/// The report of the verification of the databases.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct VerificationReport {
+    /// The number of verified blocks.
+    pub blocks: u64,
+    /// The number of verified contract assets and state trees.
+    pub contract_trees: u64,
+    /// The number of verified coins.
+    pub coins: u64,
+    /// The number of verified messages.
+    pub messages: u64,
+    /// The number of verified transactions.
+    pub transactions: u64,
+    /// All inconsistencies found during the verification.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl VerificationReport {
+    /// Returns `true` if no inconsistencies were found.
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl CombinedDatabase {
+    /// Verifies the integrity of the on-chain and off-chain databases.
+    /// It recomputes the roots of the block and contract merkle trees and
+    /// cross-checks the off-chain indexes against the on-chain data.
+    /// The verification uses snapshots of the databases, but the off-chain database
+    /// may lag behind a running node, so it should be used on a stopped node.
+    pub fn verify(&self) -> StorageResult<VerificationReport> {
+        let on_chain = self.on_chain().snapshot()?;
+        let off_chain = self.off_chain().snapshot()?;
+
+        let mut report = VerificationReport::default();
+        verify_blocks(&on_chain, &mut report)?;
+        verify_contracts(&on_chain, &mut report)?;
+        verify_coins(&on_chain, &off_chain, &mut report)?;
+        verify_messages(&on_chain, &off_chain, &mut report)?;
+        verify_transactions(&on_chain, &off_chain, &mut report)?;
+
+        Ok(report)
+    }
+}
+
+fn verify_blocks(
+    on_chain: &Database<OnChain>,
+    report: &mut VerificationReport,
+) -> StorageResult<()> {
+    for block in on_chain.iter_all::<FuelBlocks>(Some(IterDirection::Forward)) {
+        let (height, block) = block?;
+        report.blocks = report.blocks.saturating_add(1);
+
+        let Some(metadata) = on_chain
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&DenseMetadataKey::Primary(height))?
+        else {
+            report
+                .mismatches
+                .push(Mismatch::MissingBlockMerkleMetadata { height });
+            continue
+        };
+
+        let tree: binary::MerkleTree<FuelBlockMerkleData, _> =
+            binary::MerkleTree::load(on_chain, metadata.version())
+                .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))?;
+        let proof_index = metadata
+            .version()
+            .checked_sub(1)
+            .ok_or(anyhow::anyhow!("The block merkle tree is empty"))?;
+        let (calculated, proof_set) = tree
+            .prove(proof_index)
+            .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))?;
+
+        if &calculated != metadata.root() {
+            report.mismatches.push(Mismatch::BlockMerkleRoot {
+                height,
+                stored: (*metadata.root()).into(),
+                calculated: calculated.into(),
+            });
+            continue
+        }
+
+        let block_id = block.id();
+        let leaf: Bytes32 = block_id.into();
+        if !binary::verify(
+            &calculated,
+            &leaf,
+            &proof_set,
+            proof_index,
+            metadata.version(),
+        ) {
+            report
+                .mismatches
+                .push(Mismatch::BlockMerkleLeaf { height, block_id });
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_contracts(
+    on_chain: &Database<OnChain>,
+    report: &mut VerificationReport,
+) -> StorageResult<()> {
+    verify_contract_trees::<ContractsAssetsMerkleMetadata>(
+        on_chain,
+        Column::ContractsAssets,
+        report,
+        |contract_id, stored, calculated| Mismatch::ContractAssetsRoot {
+            contract_id,
+            stored,
+            calculated,
+        },
+    )?;
+    verify_contract_trees::<ContractsStateMerkleMetadata>(
+        on_chain,
+        Column::ContractsState,
+        report,
+        |contract_id, stored, calculated| Mismatch::ContractStateRoot {
+            contract_id,
+            stored,
+            calculated,
+        },
+    )
+}
+
+/// Compares the roots stored in the `Metadata` table with the roots
+/// calculated from the entries of contracts in the `column`.
+fn verify_contract_trees<Metadata>(
+    on_chain: &Database<OnChain>,
+    column: Column,
+    report: &mut VerificationReport,
+    mismatch: impl Fn(ContractId, Bytes32, Bytes32) -> Mismatch,
+) -> StorageResult<()>
+where
+    Metadata: Mappable<
+        Key = ContractId,
+        OwnedKey = ContractId,
+        Value = SparseMerkleMetadata,
+        OwnedValue = SparseMerkleMetadata,
+    >,
+    Database<OnChain>:
+        StorageInspect<Metadata, Error = StorageError> + IterableTable<Metadata>,
+{
+    let mut contract_ids = on_chain
+        .iter_all::<Metadata>(None)
+        .map(|result| result.map(|(contract_id, _)| contract_id))
+        .collect::<StorageResult<BTreeSet<_>>>()?;
+    // The entries may exist without the metadata if the latter is corrupted.
+    for entry in on_chain.iter_store(column, None, None, IterDirection::Forward) {
+        let (key, _) = entry?;
+        let contract_id = key
+            .get(..ContractId::LEN)
+            .and_then(|bytes| ContractId::try_from(bytes).ok())
+            .ok_or(anyhow::anyhow!("The key of the {column:?} is too short"))?;
+        contract_ids.insert(contract_id);
+    }
+
+    for contract_id in contract_ids {
+        report.contract_trees = report.contract_trees.saturating_add(1);
+
+        let stored = on_chain
+            .storage::<Metadata>()
+            .get(&contract_id)?
+            .map(|metadata| *metadata.root())
+            .unwrap_or_else(|| in_memory::MerkleTree::new().root());
+        let calculated = sparse_root(on_chain, column, &contract_id)?;
+
+        if stored != calculated {
+            report.mismatches.push(mismatch(
+                contract_id,
+                stored.into(),
+                calculated.into(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the root of the sparse merkle tree from the encoded entries
+/// of the contract in the `column`, the same way the `Sparse` blueprint does.
+fn sparse_root(
+    on_chain: &Database<OnChain>,
+    column: Column,
+    contract_id: &ContractId,
+) -> StorageResult<fuel_core_types::fuel_merkle::common::Bytes32> {
+    let entries = on_chain
+        .iter_store(
+            column,
+            Some(contract_id.as_ref()),
+            None,
+            IterDirection::Forward,
+        )
+        .collect::<StorageResult<Vec<_>>>()?;
+
+    let root = in_memory::MerkleTree::root_from_set(
+        entries
+            .iter()
+            .map(|(key, value)| (MerkleTreeKey::new(key), value.as_slice())),
+    );
+    Ok(root)
+}
+
+fn verify_coins(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+    report: &mut VerificationReport,
+) -> StorageResult<()> {
+    let (coins, mismatches) = scan::<Coins, _, _>(on_chain, |utxo_id, coin| {
+        let key = owner_coin_id_key(coin.owner(), &utxo_id);
+        if off_chain.storage::<OwnedCoins>().contains_key(&key)? {
+            Ok(None)
+        } else {
+            Ok(Some(Mismatch::MissingOwnedCoin {
+                owner: *coin.owner(),
+                utxo_id,
+            }))
+        }
+    })?;
+    report.coins = report.coins.saturating_add(coins);
+    report.mismatches.extend(mismatches);
+
+    let (_, mismatches) = scan::<OwnedCoins, _, _>(off_chain, |key, _| {
+        let (owner, utxo_id) = key.split_at(Address::LEN);
+        let owner = Address::try_from(owner)
+            .map_err(|_| anyhow::anyhow!("The owner of the coin is invalid"))?;
+        let utxo_id = Primitive::<{ TxId::LEN + 2 }>::decode(utxo_id)?;
+
+        let coin = on_chain.storage::<Coins>().get(&utxo_id)?;
+        if coin.map(|coin| *coin.owner()) == Some(owner) {
+            Ok(None)
+        } else {
+            Ok(Some(Mismatch::UnknownOwnedCoin { owner, utxo_id }))
+        }
+    })?;
+    report.mismatches.extend(mismatches);
+
+    Ok(())
+}
+
+fn verify_messages(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+    report: &mut VerificationReport,
+) -> StorageResult<()> {
+    let (messages, mismatches) = scan::<Messages, _, _>(on_chain, |nonce, message| {
+        let key = OwnedMessageKey::new(message.recipient(), &nonce);
+        if off_chain.storage::<OwnedMessageIds>().contains_key(&key)? {
+            Ok(None)
+        } else {
+            Ok(Some(Mismatch::MissingOwnedMessage {
+                recipient: *message.recipient(),
+                nonce,
+            }))
+        }
+    })?;
+    report.messages = report.messages.saturating_add(messages);
+    report.mismatches.extend(mismatches);
+
+    let (_, mismatches) = scan::<OwnedMessageIds, _, _>(off_chain, |key, _| {
+        let recipient = *key.address();
+        let nonce = *key.nonce();
+
+        let message = on_chain.storage::<Messages>().get(&nonce)?;
+        if message.map(|message| *message.recipient()) == Some(recipient) {
+            Ok(None)
+        } else {
+            Ok(Some(Mismatch::UnknownOwnedMessage { recipient, nonce }))
+        }
+    })?;
+    report.mismatches.extend(mismatches);
+
+    Ok(())
+}
+
+fn verify_transactions(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+    report: &mut VerificationReport,
+) -> StorageResult<()> {
+    let (transactions, mismatches) = scan::<Transactions, _, _>(on_chain, |tx_id, _| {
+        if off_chain
+            .storage::<TransactionStatuses>()
+            .contains_key(&tx_id)?
+        {
+            Ok(None)
+        } else {
+            Ok(Some(Mismatch::MissingTransactionStatus { tx_id }))
+        }
+    })?;
+    report.transactions = report.transactions.saturating_add(transactions);
+    report.mismatches.extend(mismatches);
+
+    let (_, mismatches) =
+        scan::<TransactionStatuses, _, _>(off_chain, |tx_id, status| {
+            let is_included = matches!(
+                status,
+                TransactionStatus::Success { .. } | TransactionStatus::Failed { .. }
+            );
+
+            if is_included && !on_chain.storage::<Transactions>().contains_key(&tx_id)? {
+                Ok(Some(Mismatch::UnknownTransactionStatus { tx_id }))
+            } else {
+                Ok(None)
+            }
+        })?;
+    report.mismatches.extend(mismatches);
+
+    Ok(())
+}
+
+/// Checks all entries of the table `M` in parallel by the `check`.
+/// Returns the number of checked entries and the found mismatches.
+fn scan<M, Description, F>(
+    database: &Database<Description>,
+    check: F,
+) -> StorageResult<(u64, Vec<Mismatch>)>
+where
+    Description: DatabaseDescription,
+    M: Mappable,
+    M::OwnedKey: Send,
+    M::OwnedValue: Send,
+    Database<Description>: IterableTable<M> + Sync,
+    F: Fn(M::OwnedKey, M::OwnedValue) -> StorageResult<Option<Mismatch>> + Sync + Send,
+{
+    database
+        .par_iter_all::<M>(SCAN_SHARDS)
+        .map(|entry| {
+            let (key, value) = entry?;
+            check(key, value)
+        })
+        .try_fold(
+            || (0u64, Vec::new()),
+            |(count, mut mismatches), mismatch| {
+                mismatches.extend(mismatch?);
+                Ok((count.saturating_add(1), mismatches))
+            },
+        )
+        .try_reduce(
+            || (0, Vec::new()),
+            |(count, mut mismatches), (other_count, other_mismatches)| {
+                mismatches.extend(other_mismatches);
+                Ok((count.saturating_add(other_count), mismatches))
+            },
+        )
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        kv_store::{
+            StorageColumn,
+            WriteOperation,
+        },
+        tables::ContractsAssets,
+        transactional::{
+            Changes,
+            WriteTransaction,
+        },
+        ContractsAssetKey,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::AssetId,
+    };
+    use std::{
+        collections::BTreeMap,
+        sync::Arc,
+    };
+
+    fn database_with_block_and_coin() -> (CombinedDatabase, UtxoId) {
+        let mut database = CombinedDatabase::in_memory();
+        let utxo_id = UtxoId::new(TxId::zeroed(), 0);
+        let owner = Address::from([1; 32]);
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(owner);
+
+        let mut transaction = database.on_chain_mut().write_transaction();
+        transaction
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(
+                &ContractsAssetKey::new(&ContractId::zeroed(), &AssetId::zeroed()),
+                &100,
+            )
+            .unwrap();
+        transaction.commit().unwrap();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &CompressedBlock::default())
+            .unwrap();
+        database
+            .off_chain_mut()
+            .storage_as_mut::<OwnedCoins>()
+            .insert(&owner_coin_id_key(&owner, &utxo_id), &())
+            .unwrap();
+
+        (database, utxo_id)
+    }
+
+    #[test]
+    fn verify__consistent_database_has_no_mismatches() {
+        // Given
+        let (database, _) = database_with_block_and_coin();
+
+        // When
+        let report = database.verify().unwrap();
+
+        // Then
+        assert!(report.is_consistent(), "{:?}", report.mismatches);
+        assert_eq!(report.blocks, 1);
+        assert_eq!(report.contract_trees, 1);
+        assert_eq!(report.coins, 1);
+    }
+
+    #[test]
+    fn verify__reports_coin_without_owned_coins_index() {
+        // Given
+        let (mut database, utxo_id) = database_with_block_and_coin();
+        let owner = Address::from([1; 32]);
+        database
+            .off_chain_mut()
+            .storage_as_mut::<OwnedCoins>()
+            .remove(&owner_coin_id_key(&owner, &utxo_id))
+            .unwrap();
+
+        // When
+        let report = database.verify().unwrap();
+
+        // Then
+        assert_eq!(
+            report.mismatches,
+            vec![Mismatch::MissingOwnedCoin { owner, utxo_id }]
+        );
+    }
+
+    #[test]
+    fn verify__reports_corrupted_contract_assets() {
+        // Given
+        let (mut database, _) = database_with_block_and_coin();
+        let key = ContractsAssetKey::new(&ContractId::zeroed(), &AssetId::zeroed());
+        // Bypasses the merkle tree update to simulate the corruption.
+        let changes = Changes::from([(
+            Column::ContractsAssets.id(),
+            BTreeMap::from([(
+                key.as_ref().to_vec(),
+                WriteOperation::Insert(Arc::new(1u64.to_be_bytes().to_vec())),
+            )]),
+        )]);
+        database
+            .on_chain_mut()
+            .commit_changes_without_height_update(changes)
+            .unwrap();
+
+        // When
+        let report = database.verify().unwrap();
+
+        // Then
+        assert!(matches!(
+            report.mismatches.as_slice(),
+            [Mismatch::ContractAssetsRoot { contract_id, .. }] if *contract_id == ContractId::zeroed()
+        ));
+    }
+}
@@ -1,5 +1,12 @@
 use crate::{
     database::{
+        change_journal::{
+            journal_entries,
+            ChangeJournal,
+            ChangeJournalPolicy,
+            JournalRecord,
+            JournalRecordKind,
+        },
         database_description::{
             off_chain::OffChain,
             on_chain::OnChain,
@@ -8,11 +15,25 @@ use crate::{
             DatabaseMetadata,
         },
         metadata::MetadataTable,
+        modifications_history::{
+            reverse_changes,
+            ModificationsHistory,
+            StateRewindPolicy,
+        },
         Error as DatabaseError,
     },
     graphql_api::storage::blocks::FuelBlockIdsToHeights,
     state::{
+        column_options::{
+            columns_options,
+            DatabaseColumnProfiles,
+        },
         in_memory::memory_store::MemoryStore,
+        instrumented::{
+            AccessContext,
+            InstrumentedStorage,
+        },
+        read_cache::ReadCache,
         ChangesIterator,
         DataSource,
     },
@@ -59,6 +80,7 @@ use fuel_core_types::{
 use itertools::Itertools;
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
@@ -66,22 +88,27 @@ pub use fuel_core_database::Error;
 pub type Result<T> = core::result::Result<T, Error>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
+#[cfg(feature = "redb")]
+use crate::state::redb::RedbDb;
 #[cfg(feature = "rocksdb")]
 use crate::state::rocks_db::RocksDb;
-#[cfg(feature = "rocksdb")]
-use std::path::Path;
 
 // Storages implementation
 pub mod balances;
 pub mod block;
+pub mod change_journal;
 pub mod coin;
 pub mod contracts;
 pub mod database_description;
 pub mod genesis_progress;
 pub mod message;
 pub mod metadata;
+pub mod migrations;
+pub mod modifications_history;
+pub mod rollback;
 pub mod sealed_block;
 pub mod state;
+pub mod state_pruning;
 pub mod storage;
 pub mod transactions;
 
@@ -92,6 +119,8 @@ where
 {
     height: SharedMutex<Option<Description::Height>>,
     data: DataSource<Description>,
+    state_rewind_policy: StateRewindPolicy,
+    change_journal_policy: ChangeJournalPolicy,
 }
 
 impl Database<OnChain> {
@@ -133,6 +162,8 @@ where
         let mut database = Self {
             height: SharedMutex::new(None),
             data: data_source,
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            change_journal_policy: ChangeJournalPolicy::Disabled,
         };
         let height = database
             .latest_height()
@@ -145,8 +176,64 @@ where
 
     #[cfg(feature = "rocksdb")]
     pub fn open_rocksdb(path: &Path, capacity: impl Into<Option<usize>>) -> Result<Self> {
+        Self::open_rocksdb_with_profiles(path, capacity, &Default::default())
+    }
+
+    /// Opens the RocksDB with the default options of the columns overridden by the `profiles`.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_rocksdb_with_profiles(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        profiles: &DatabaseColumnProfiles,
+    ) -> Result<Self> {
+        use anyhow::Context;
+        let db = RocksDb::<Description>::open_with_profiles(path, capacity.into(), profiles).map_err(Into::<anyhow::Error>::into).with_context(|| format!("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db e.g. `rm -rf {path:?}`"))?;
+
+        Ok(Database::new(Arc::new(db)))
+    }
+
+    /// Opens the RocksDB in the `primary_path` as a secondary instance that keeps its own
+    /// files in the `secondary_path`. The database is read-only and follows the primary
+    /// instance only after [`Self::catch_up_with_primary`].
+    #[cfg(feature = "rocksdb")]
+    pub fn open_rocksdb_secondary(
+        primary_path: &Path,
+        secondary_path: &Path,
+        capacity: impl Into<Option<usize>>,
+        profiles: &DatabaseColumnProfiles,
+    ) -> Result<Self> {
+        use anyhow::Context;
+        let db = RocksDb::<Description>::open_secondary(
+            primary_path,
+            secondary_path,
+            columns_options::<Description>(profiles)?,
+            capacity.into(),
+        )
+        .map_err(Into::<anyhow::Error>::into)
+        .with_context(|| {
+            format!("Failed to open rocksdb at {primary_path:?} as a secondary")
+        })?;
+
+        Ok(Database::new(Arc::new(db)))
+    }
+
+    /// Makes visible the changes committed by the primary instance of the database
+    /// and updates the latest height. Does nothing if the database isn't a secondary.
+    pub fn catch_up_with_primary(&self) -> StorageResult<()> {
+        let mut guard = self.height.lock();
+        self.data.catch_up_with_primary()?;
+        *guard = self.latest_height()?;
+        Ok(())
+    }
+
+    /// Opens the database backed by the redb in the `path`.
+    /// The `capacity` is the size of the page cache in bytes.
+    #[cfg(feature = "redb")]
+    pub fn open_redb(path: &Path, capacity: impl Into<Option<usize>>) -> Result<Self> {
         use anyhow::Context;
-        let db = RocksDb::<Description>::default_open(path, capacity.into()).map_err(Into::<anyhow::Error>::into).with_context(|| format!("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db e.g. `rm -rf {path:?}`"))?;
+        let db = RedbDb::<Description>::open(path, capacity.into())
+            .map_err(Into::<anyhow::Error>::into)
+            .with_context(|| format!("Failed to open redb at {path:?}"))?;
 
         Ok(Database::new(Arc::new(db)))
     }
@@ -156,11 +243,97 @@ impl<Description> Database<Description>
 where
     Description: DatabaseDescription,
 {
+    /// Sets the policy that defines for how long the history of modifications is kept.
+    pub fn with_state_rewind_policy(mut self, policy: StateRewindPolicy) -> Self {
+        self.state_rewind_policy = policy;
+        self
+    }
+
+    pub fn state_rewind_policy(&self) -> StateRewindPolicy {
+        self.state_rewind_policy
+    }
+
+    /// Sets the policy that defines for how long the journal of changes is kept.
+    pub fn with_change_journal_policy(mut self, policy: ChangeJournalPolicy) -> Self {
+        self.change_journal_policy = policy;
+        self
+    }
+
+    pub fn change_journal_policy(&self) -> ChangeJournalPolicy {
+        self.change_journal_policy
+    }
+
+    /// Caches in memory the values of the columns with the non-zero `read_cache_size`.
+    /// The default options of the columns are overridden by the `profiles`.
+    ///
+    /// Only commits made through the returned database update the cache,
+    /// so the database shouldn't be cloned before the call.
+    pub fn with_read_cache(mut self, profiles: &DatabaseColumnProfiles) -> Result<Self> {
+        let budgets = columns_options::<Description>(profiles)?
+            .into_iter()
+            .filter(|(_, options)| options.read_cache_size > 0)
+            .map(|(column, options)| (column, options.read_cache_size))
+            .collect::<Vec<_>>();
+
+        if !budgets.is_empty() {
+            self.data = Arc::new(ReadCache::<Description>::new(self.data, budgets));
+        }
+        Ok(self)
+    }
+
+    /// Measures accesses to the columns and exports them as metrics.
+    /// If the `trace_directory` is set, accesses are also written into it,
+    /// one file per committed height.
+    pub fn with_instrumentation(mut self, trace_directory: Option<&Path>) -> Result<Self>
+    where
+        Description::Height: DatabaseHeight,
+    {
+        self.data = Arc::new(InstrumentedStorage::<Description>::new(
+            self.data,
+            trace_directory,
+        )?);
+        Ok(self)
+    }
+
+    /// Attributes accesses made through the returned database to the `context`.
+    /// Does nothing if the database isn't instrumented.
+    pub fn with_access_context(mut self, context: AccessContext) -> Self {
+        if let Some(data) = self.data.with_access_context(context) {
+            self.data = data;
+        }
+        self
+    }
+
+    /// Returns the read-only view of the database pinned to the latest committed height.
+    /// Commits made after the call are not visible through the view.
+    pub fn snapshot(&self) -> StorageResult<Self> {
+        // The lock guarantees that the view is taken between commits,
+        // so the height matches the data.
+        let guard = self.height.lock();
+        Ok(Self {
+            height: SharedMutex::new(*guard),
+            data: self.data.latest_view()?,
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            change_journal_policy: ChangeJournalPolicy::Disabled,
+        })
+    }
+
+    /// Creates the checkpoint of the database in the `path`.
+    /// Commits are blocked while the checkpoint is created, so it contains
+    /// the state at the returned height.
+    pub fn checkpoint(&self, path: &Path) -> StorageResult<Option<Description::Height>> {
+        let guard = self.height.lock();
+        self.data.checkpoint(path)?;
+        Ok(*guard)
+    }
+
     pub fn in_memory() -> Self {
         let data = Arc::<MemoryStore<Description>>::new(MemoryStore::default());
         Self {
             height: SharedMutex::new(None),
             data,
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            change_journal_policy: ChangeJournalPolicy::Disabled,
         }
     }
 
@@ -171,6 +344,20 @@ where
         Self {
             height: SharedMutex::new(None),
             data,
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            change_journal_policy: ChangeJournalPolicy::Disabled,
+        }
+    }
+
+    #[cfg(feature = "redb")]
+    pub fn redb_temp() -> Self {
+        let data =
+            Arc::<RedbDb<Description>>::new(RedbDb::default_open_temp(None).unwrap());
+        Self {
+            height: SharedMutex::new(None),
+            data,
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            change_journal_policy: ChangeJournalPolicy::Disabled,
         }
     }
 }
@@ -252,14 +439,12 @@ impl AtomicView for Database<OnChain> {
         *self.height.lock()
     }
 
-    fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
-        // TODO: Unimplemented until of the https://github.com/FuelLabs/fuel-core/issues/451
-        Ok(self.latest_view())
+    fn view_at(&self, height: &BlockHeight) -> StorageResult<Self::View> {
+        self.historical_view(height)
     }
 
-    fn latest_view(&self) -> Self::View {
-        // TODO: https://github.com/FuelLabs/fuel-core/issues/1581
-        self.clone()
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        self.snapshot()
     }
 }
 
@@ -272,14 +457,12 @@ impl AtomicView for Database<OffChain> {
         *self.height.lock()
     }
 
-    fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
-        // TODO: Unimplemented until of the https://github.com/FuelLabs/fuel-core/issues/451
-        Ok(self.latest_view())
+    fn view_at(&self, height: &BlockHeight) -> StorageResult<Self::View> {
+        self.historical_view(height)
     }
 
-    fn latest_view(&self) -> Self::View {
-        // TODO: https://github.com/FuelLabs/fuel-core/issues/1581
-        self.clone()
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        self.snapshot()
     }
 }
 
@@ -292,11 +475,11 @@ impl AtomicView for Database<Relayer> {
     }
 
     fn view_at(&self, _: &Self::Height) -> StorageResult<Self::View> {
-        Ok(self.latest_view())
+        self.latest_view()
     }
 
-    fn latest_view(&self) -> Self::View {
-        self.clone()
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        Ok(self.clone())
     }
 }
 
@@ -340,7 +523,7 @@ impl Modifiable for Database<Relayer> {
     }
 }
 
-trait DatabaseHeight: Sized {
+pub trait DatabaseHeight: Sized {
     fn as_u64(&self) -> u64;
 
     fn advance_height(&self) -> Option<Self>;
@@ -377,8 +560,9 @@ fn commit_changes_with_height_update<Description>(
 where
     Description: DatabaseDescription,
     Description::Height: Debug + PartialOrd + DatabaseHeight,
-    for<'a> StorageTransaction<&'a &'a mut Database<Description>>:
-        StorageMutate<MetadataTable<Description>, Error = StorageError>,
+    for<'a> StorageTransaction<&'a &'a mut Database<Description>>: StorageMutate<MetadataTable<Description>, Error = StorageError>
+        + StorageMutate<ModificationsHistory<Description>, Error = StorageError>
+        + StorageMutate<ChangeJournal<Description>, Error = StorageError>,
 {
     // Gets the all new heights from the `changes`
     let iterator = ChangesIterator::<Description>::new(&changes);
@@ -395,7 +579,10 @@ where
     }
 
     let new_height = new_heights.into_iter().last();
-    let prev_height = *database.height.lock();
+    // The lock is held until the end of the commit, so the commits without
+    // the height update don't interleave with the history and the journal.
+    let mut guard = database.height.lock();
+    let prev_height = *guard;
 
     match (prev_height, new_height) {
         (None, None) => {
@@ -434,7 +621,10 @@ where
         }
     };
 
-    let updated_changes = if let Some(new_height) = new_height {
+    let records_history = database.state_rewind_policy.records_history();
+    let records_changes = database.change_journal_policy.records_changes();
+
+    let updated_changes = if new_height.is_some() || records_changes {
         // We want to update the metadata table to include a new height.
         // For that, we are building a new storage transaction around `changes`.
         // Modifying this transaction will include all required updates into the `changes`.
@@ -443,22 +633,80 @@ where
             ConflictPolicy::Overwrite,
             changes,
         );
-        transaction
-            .storage_as_mut::<MetadataTable<Description>>()
-            .insert(
-                &(),
-                &DatabaseMetadata::V1 {
-                    version: Description::version(),
-                    height: new_height,
-                },
-            )?;
+        if let Some(new_height) = new_height {
+            transaction
+                .storage_as_mut::<MetadataTable<Description>>()
+                .insert(
+                    &(),
+                    &DatabaseMetadata::V1 {
+                        version: Description::version(),
+                        height: new_height,
+                    },
+                )?;
+        }
+
+        let height = new_height.as_ref().map(DatabaseHeight::as_u64);
+        let records_history = records_history && height.is_some();
+        // The reverse changes are calculated before the history and the journal
+        // are modified, so both see the same state before the commit.
+        let reverse = if records_history || records_changes {
+            Some(reverse_changes::<Description, _>(
+                database.data.as_ref(),
+                transaction.changes(),
+            )?)
+        } else {
+            None
+        };
+
+        if let Some(reverse) = reverse.as_ref().filter(|_| records_changes) {
+            // The regenesis commits the state before the first height.
+            let kind = if height.is_some() {
+                JournalRecordKind::Commit
+            } else {
+                JournalRecordKind::Maintenance
+            };
+            let record = JournalRecord {
+                kind,
+                height,
+                entries: journal_entries::<Description>(transaction.changes(), reverse),
+            };
+            database.append_to_change_journal(&mut transaction, record)?;
+        }
+
+        if let (Some(height), Some(reverse)) =
+            (height, reverse.filter(|_| records_history))
+        {
+            transaction
+                .storage_as_mut::<ModificationsHistory<Description>>()
+                .insert(&height, &reverse)?;
+
+            if let Some(prune_up_to) = database.state_rewind_policy.prune_up_to(height) {
+                let outdated_heights = database
+                    .iter_all::<ModificationsHistory<Description>>(Some(
+                        IterDirection::Forward,
+                    ))
+                    .map(|result| result.map(|(height, _)| height))
+                    .take_while(|result| {
+                        result
+                            .as_ref()
+                            .map(|height| *height <= prune_up_to)
+                            .unwrap_or(true)
+                    })
+                    .collect::<StorageResult<Vec<_>>>()?;
+
+                for height in outdated_heights {
+                    transaction
+                        .storage_as_mut::<ModificationsHistory<Description>>()
+                        .remove(&height)?;
+                }
+            }
+        }
 
         transaction.into_changes()
     } else {
         changes
     };
 
-    let mut guard = database.height.lock();
     database
         .data
         .as_ref()
@@ -509,8 +757,18 @@ mod tests {
             DatabaseHeight,
         };
         use fuel_core_storage::{
+            codec::{
+                postcard::Postcard,
+                Decode,
+            },
+            column::Column,
+            kv_store::StorageColumn,
             tables::Coins,
-            transactional::WriteTransaction,
+            transactional::{
+                ReadTransaction,
+                WriteTransaction,
+            },
+            StorageAsRef,
         };
         use fuel_core_types::{
             blockchain::block::CompressedBlock,
@@ -665,6 +923,406 @@ mod tests {
                 .to_string()
             );
         }
+
+        fn commit_block_with_coins(
+            database: &mut Database<OnChain>,
+            height: BlockHeight,
+            coins: &[(UtxoId, u64)],
+        ) {
+            let mut transaction = database.write_transaction();
+            transaction
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height, &CompressedBlock::default())
+                .unwrap();
+            for (utxo_id, amount) in coins {
+                let mut coin = CompressedCoin::default();
+                coin.set_amount(*amount);
+                transaction
+                    .storage_as_mut::<Coins>()
+                    .insert(utxo_id, &coin)
+                    .unwrap();
+            }
+            transaction.commit().unwrap();
+        }
+
+        #[test]
+        fn view_at_returns_state_at_the_requested_height() {
+            // Given
+            let mut database = Database::<OnChain>::default()
+                .with_state_rewind_policy(StateRewindPolicy::RewindFullRange);
+            let first_utxo = UtxoId::new([1; 32].into(), 0);
+            let second_utxo = UtxoId::new([2; 32].into(), 0);
+            commit_block_with_coins(&mut database, 1.into(), &[(first_utxo, 10)]);
+            commit_block_with_coins(
+                &mut database,
+                2.into(),
+                &[(first_utxo, 20), (second_utxo, 30)],
+            );
+
+            // When
+            let view = database.view_at(&1.into()).unwrap();
+
+            // Then
+            assert_eq!(view.latest_height().unwrap(), Some(1.into()));
+            let coin = view.storage::<Coins>().get(&first_utxo).unwrap().unwrap();
+            assert_eq!(*coin.amount(), 10);
+            assert!(view.storage::<Coins>().get(&second_utxo).unwrap().is_none());
+            let coins = view
+                .iter_all::<Coins>(None)
+                .map(|result| result.unwrap().0)
+                .collect_vec();
+            assert_eq!(coins, vec![first_utxo]);
+            let latest_coin = database
+                .storage::<Coins>()
+                .get(&first_utxo)
+                .unwrap()
+                .unwrap();
+            assert_eq!(*latest_coin.amount(), 20);
+        }
+
+        #[test]
+        fn view_at_fails_without_history() {
+            // Given
+            let mut database = Database::<OnChain>::default();
+            commit_block_with_coins(&mut database, 1.into(), &[]);
+            commit_block_with_coins(&mut database, 2.into(), &[]);
+
+            // When
+            let result = database.view_at(&1.into());
+
+            // Then
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                StorageError::from(DatabaseError::HistoryIsNotAvailable {
+                    requested_height: 1
+                })
+                .to_string()
+            );
+        }
+
+        #[test]
+        fn view_at_fails_for_height_above_latest() {
+            // Given
+            let mut database = Database::<OnChain>::default()
+                .with_state_rewind_policy(StateRewindPolicy::RewindFullRange);
+            commit_block_with_coins(&mut database, 1.into(), &[]);
+
+            // When
+            let result = database.view_at(&2.into());
+
+            // Then
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                StorageError::from(DatabaseError::RequestedHeightIsAboveLatest {
+                    requested_height: 2,
+                    latest_height: 1,
+                })
+                .to_string()
+            );
+        }
+
+        #[test]
+        fn history_outside_of_rewind_window_is_pruned() {
+            // Given
+            let mut database = Database::<OnChain>::default().with_state_rewind_policy(
+                StateRewindPolicy::RewindRange {
+                    size: 2.try_into().unwrap(),
+                },
+            );
+
+            // When
+            for height in 1..=5u32 {
+                commit_block_with_coins(&mut database, height.into(), &[]);
+            }
+
+            // Then
+            let history = database
+                .iter_all::<ModificationsHistory<OnChain>>(None)
+                .map(|result| result.unwrap().0)
+                .collect_vec();
+            assert_eq!(history, vec![4, 5]);
+            assert!(database.view_at(&3.into()).is_ok());
+            assert!(database.view_at(&2.into()).is_err());
+        }
+
+        #[test]
+        fn latest_view_does_not_see_later_commits() {
+            // Given
+            let mut database = Database::<OnChain>::default();
+            let utxo = UtxoId::new([1; 32].into(), 0);
+            commit_block_with_coins(&mut database, 1.into(), &[(utxo, 10)]);
+            let view = database.latest_view().unwrap();
+
+            // When
+            commit_block_with_coins(&mut database, 2.into(), &[(utxo, 20)]);
+
+            // Then
+            assert_eq!(AtomicView::latest_height(&view), Some(1.into()));
+            let coin = view.storage::<Coins>().get(&utxo).unwrap().unwrap();
+            assert_eq!(*coin.amount(), 10);
+            assert!(view
+                .storage::<FuelBlocks>()
+                .get(&2.into())
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn latest_view_fails_if_storage_fails_to_create_snapshot() {
+            // Given
+            let transaction = StorageTransaction::transaction(
+                Database::<OnChain>::default(),
+                ConflictPolicy::Overwrite,
+                Default::default(),
+            );
+            let database = Database::<OnChain>::new(Arc::new(transaction));
+
+            // When
+            let result = database.latest_view();
+
+            // Then
+            result.expect_err("Should fail because the storage doesn't support views");
+        }
+
+        #[test]
+        fn view_at_does_not_see_later_commits() {
+            // Given
+            let mut database = Database::<OnChain>::default()
+                .with_state_rewind_policy(StateRewindPolicy::RewindFullRange);
+            let utxo = UtxoId::new([1; 32].into(), 0);
+            commit_block_with_coins(&mut database, 1.into(), &[(utxo, 10)]);
+            commit_block_with_coins(&mut database, 2.into(), &[(utxo, 20)]);
+            let view = database.view_at(&1.into()).unwrap();
+
+            // When
+            commit_block_with_coins(&mut database, 3.into(), &[(utxo, 30)]);
+
+            // Then
+            let coin = view.storage::<Coins>().get(&utxo).unwrap().unwrap();
+            assert_eq!(*coin.amount(), 10);
+        }
+
+        #[test]
+        fn rollback_to_reverts_the_state_and_the_height() {
+            // Given
+            let mut database = Database::<OnChain>::default()
+                .with_state_rewind_policy(StateRewindPolicy::RewindFullRange);
+            let first_utxo = UtxoId::new([1; 32].into(), 0);
+            let second_utxo = UtxoId::new([2; 32].into(), 0);
+            commit_block_with_coins(&mut database, 1.into(), &[(first_utxo, 10)]);
+            commit_block_with_coins(&mut database, 2.into(), &[(first_utxo, 20)]);
+            commit_block_with_coins(&mut database, 3.into(), &[(second_utxo, 30)]);
+
+            // When
+            database.rollback_to(&1.into()).unwrap();
+
+            // Then
+            assert_eq!(AtomicView::latest_height(&database), Some(1.into()));
+            assert_eq!(database.latest_height().unwrap(), Some(1.into()));
+            let coin = database
+                .storage::<Coins>()
+                .get(&first_utxo)
+                .unwrap()
+                .unwrap();
+            assert_eq!(*coin.amount(), 10);
+            assert!(database
+                .storage::<Coins>()
+                .get(&second_utxo)
+                .unwrap()
+                .is_none());
+            let history = database
+                .iter_all::<ModificationsHistory<OnChain>>(None)
+                .map(|result| result.unwrap().0)
+                .collect_vec();
+            assert_eq!(history, vec![1]);
+
+            // The database continues from the reverted height.
+            commit_block_with_coins(&mut database, 2.into(), &[]);
+            assert_eq!(AtomicView::latest_height(&database), Some(2.into()));
+        }
+
+        #[test]
+        fn rollback_to_does_nothing_without_history() {
+            // Given
+            let mut database = Database::<OnChain>::default().with_state_rewind_policy(
+                StateRewindPolicy::RewindRange {
+                    size: 1.try_into().unwrap(),
+                },
+            );
+            for height in 1..=3u32 {
+                commit_block_with_coins(&mut database, height.into(), &[]);
+            }
+
+            // When
+            let result = database.rollback_to(&1.into());
+
+            // Then
+            assert!(result.is_err());
+            assert_eq!(AtomicView::latest_height(&database), Some(3.into()));
+        }
+
+        fn coin_changes(
+            database: &Database<OnChain>,
+        ) -> Vec<(
+            change_journal::JournalRecordKind,
+            Option<u64>,
+            Vec<change_journal::JournalEntry>,
+        )> {
+            database
+                .change_journal(0)
+                .map(|result| {
+                    let (_, record) = result.unwrap();
+                    let entries = record
+                        .entries
+                        .into_iter()
+                        .filter(|entry| entry.column == Column::Coins.id())
+                        .collect();
+                    (record.kind, record.height, entries)
+                })
+                .collect()
+        }
+
+        fn journal_heights(database: &Database<OnChain>) -> Vec<u64> {
+            coin_changes(database)
+                .into_iter()
+                .filter_map(|(_, height, _)| height)
+                .collect()
+        }
+
+        #[test]
+        fn change_journal_records_old_and_new_values() {
+            // Given
+            let mut database = Database::<OnChain>::default()
+                .with_change_journal_policy(ChangeJournalPolicy::FullRange);
+            let utxo = UtxoId::new([1; 32].into(), 0);
+            commit_block_with_coins(&mut database, 1.into(), &[(utxo, 10)]);
+
+            // When
+            commit_block_with_coins(&mut database, 2.into(), &[(utxo, 20)]);
+
+            // Then
+            let journal = coin_changes(&database);
+            assert_eq!(journal.len(), 2);
+            let (first_kind, first_height, first) = &journal[0];
+            let (second_kind, second_height, second) = &journal[1];
+            assert_eq!(*first_kind, JournalRecordKind::Commit);
+            assert_eq!(*second_kind, JournalRecordKind::Commit);
+            assert_eq!((*first_height, *second_height), (Some(1), Some(2)));
+            assert_eq!(first.len(), 1);
+            assert_eq!(second.len(), 1);
+            assert_eq!(first[0].old_value, None);
+            assert_eq!(second[0].key, first[0].key);
+            assert_eq!(second[0].old_value, first[0].new_value);
+
+            let new_coin = <Postcard as Decode<CompressedCoin>>::decode(
+                second[0].new_value.as_ref().unwrap(),
+            )
+            .unwrap();
+            assert_eq!(*new_coin.amount(), 20);
+        }
+
+        #[test]
+        fn change_journal_is_not_recorded_by_default() {
+            // Given
+            let mut database = Database::<OnChain>::default();
+
+            // When
+            commit_block_with_coins(&mut database, 1.into(), &[]);
+
+            // Then
+            assert_eq!(database.change_journal(0).count(), 0);
+        }
+
+        #[test]
+        fn change_journal_outside_of_window_is_pruned() {
+            // Given
+            let mut database = Database::<OnChain>::default().with_change_journal_policy(
+                ChangeJournalPolicy::Range {
+                    size: 2.try_into().unwrap(),
+                },
+            );
+
+            // When
+            for height in 1..=5u32 {
+                commit_block_with_coins(&mut database, height.into(), &[]);
+            }
+
+            // Then
+            assert_eq!(journal_heights(&database), vec![4, 5]);
+            let sequences = database
+                .change_journal(0)
+                .map(|result| result.unwrap().0)
+                .collect_vec();
+            assert_eq!(sequences, vec![3, 4]);
+        }
+
+        #[test]
+        fn rollback_to_appends_revert_records_to_change_journal() {
+            // Given
+            let mut database = Database::<OnChain>::default()
+                .with_state_rewind_policy(StateRewindPolicy::RewindFullRange)
+                .with_change_journal_policy(ChangeJournalPolicy::FullRange);
+            let utxo = UtxoId::new([1; 32].into(), 0);
+            for height in 1..=3u32 {
+                commit_block_with_coins(
+                    &mut database,
+                    height.into(),
+                    &[(utxo, height.into())],
+                );
+            }
+
+            // When
+            database.rollback_to(&1.into()).unwrap();
+
+            // Then
+            let journal = coin_changes(&database);
+            let records = journal
+                .iter()
+                .map(|(kind, height, _)| (*kind, *height))
+                .collect_vec();
+            assert_eq!(
+                records,
+                vec![
+                    (JournalRecordKind::Commit, Some(1)),
+                    (JournalRecordKind::Commit, Some(2)),
+                    (JournalRecordKind::Commit, Some(3)),
+                    (JournalRecordKind::Revert, Some(3)),
+                    (JournalRecordKind::Revert, Some(2)),
+                ]
+            );
+            // The revert of the height swaps the old and new values of its commit.
+            let (_, _, commit) = &journal[2];
+            let (_, _, revert) = &journal[3];
+            assert_eq!(revert[0].key, commit[0].key);
+            assert_eq!(revert[0].old_value, commit[0].new_value);
+            assert_eq!(revert[0].new_value, commit[0].old_value);
+        }
+
+        #[test]
+        fn commit_changes_without_height_update_appends_maintenance_record() {
+            // Given
+            let mut database = Database::<OnChain>::default()
+                .with_change_journal_policy(ChangeJournalPolicy::FullRange);
+            let utxo = UtxoId::new([1; 32].into(), 0);
+            commit_block_with_coins(&mut database, 1.into(), &[(utxo, 10)]);
+            let mut transaction = database.read_transaction();
+            transaction.storage_as_mut::<Coins>().remove(&utxo).unwrap();
+
+            // When
+            database
+                .commit_changes_without_height_update(transaction.into_changes())
+                .unwrap();
+
+            // Then
+            let journal = coin_changes(&database);
+            let (kind, height, entries) = journal.last().unwrap();
+            assert_eq!(*kind, JournalRecordKind::Maintenance);
+            assert_eq!(*height, Some(1));
+            assert_eq!(entries.len(), 1);
+            assert!(entries[0].old_value.is_some());
+            assert_eq!(entries[0].new_value, None);
+            assert_eq!(AtomicView::latest_height(&database), Some(1.into()));
+        }
     }
 
     mod off_chain {
@@ -677,7 +1335,10 @@ mod tests {
             fuel_core_graphql_api::storage::messages::OwnedMessageKey,
             graphql_api::storage::messages::OwnedMessageIds,
         };
-        use fuel_core_storage::transactional::WriteTransaction;
+        use fuel_core_storage::{
+            transactional::WriteTransaction,
+            StorageAsRef,
+        };
 
         #[test]
         fn column_keys_not_exceed_count_test() {
@@ -826,6 +1487,42 @@ mod tests {
                 .to_string()
             );
         }
+
+        #[test]
+        fn view_at_returns_state_at_the_requested_height() {
+            // Given
+            let mut database = Database::<OffChain>::default()
+                .with_state_rewind_policy(StateRewindPolicy::RewindFullRange);
+            let message_key = OwnedMessageKey::default();
+            database
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&[1; 32].into(), &1.into())
+                .unwrap();
+            let mut transaction = database.write_transaction();
+            transaction
+                .storage_as_mut::<FuelBlockIdsToHeights>()
+                .insert(&[2; 32].into(), &2.into())
+                .unwrap();
+            transaction
+                .storage_as_mut::<OwnedMessageIds>()
+                .insert(&message_key, &())
+                .unwrap();
+            transaction.commit().unwrap();
+
+            // When
+            let view = database.view_at(&1.into()).unwrap();
+
+            // Then
+            assert_eq!(view.latest_height().unwrap(), Some(1.into()));
+            assert!(!view
+                .storage_as_ref::<OwnedMessageIds>()
+                .contains_key(&message_key)
+                .unwrap());
+            assert!(database
+                .storage_as_ref::<OwnedMessageIds>()
+                .contains_key(&message_key)
+                .unwrap());
+        }
     }
 
     #[cfg(feature = "relayer")]
@@ -0,0 +1,244 @@
+use crate::database::{
+    database_description::DatabaseDescription,
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+        Decode,
+    },
+    iter::{
+        IterDirection,
+        IterableStore,
+        IteratorOverTable,
+    },
+    kv_store::{
+        StorageColumn,
+        WriteOperation,
+    },
+    structured_storage::TableWithBlueprint,
+    transactional::Changes,
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageMutate,
+};
+use std::num::NonZeroU64;
+
+/// The table stores the journal of committed changes for external consumers.
+/// Each commit appends a record under the next sequence number, so consumers can
+/// resume the stream from the last seen record. The record contains the old and new
+/// values of all keys modified by the commit, sorted by the column and the key.
+pub struct ChangeJournal<Description>(core::marker::PhantomData<Description>);
+
+impl<Description> Mappable for ChangeJournal<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Key = Self::OwnedKey;
+    type OwnedKey = u64;
+    type Value = Self::OwnedValue;
+    type OwnedValue = JournalRecord;
+}
+
+impl<Description> TableWithBlueprint for ChangeJournal<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Blueprint = Plain<Primitive<8>, Postcard>;
+    type Column = Description::Column;
+
+    fn column() -> Self::Column {
+        Description::change_journal_column()
+    }
+}
+
+/// The kind of the commit recorded in the journal.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JournalRecordKind {
+    /// The commit of the block at the height of the record.
+    Commit,
+    /// The revert of the block at the height of the record by the rollback.
+    Revert,
+    /// The commit that doesn't change the height of the database, like the pruning
+    /// of old blocks, the migration or the regenesis. The height of the record
+    /// is the height of the database at the moment of the commit.
+    Maintenance,
+}
+
+/// The record of one commit in the journal.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct JournalRecord {
+    /// The kind of the commit.
+    pub kind: JournalRecordKind,
+    /// The height the commit belongs to, `None` before the first block.
+    pub height: Option<u64>,
+    /// The changes made by the commit.
+    pub entries: Vec<JournalEntry>,
+}
+
+/// The change of one key in one column.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// The id of the column.
+    pub column: u32,
+    /// The raw key.
+    pub key: Vec<u8>,
+    /// The value before the commit, `None` if the key didn't exist.
+    pub old_value: Option<Vec<u8>>,
+    /// The value after the commit, `None` if the key was removed.
+    pub new_value: Option<Vec<u8>>,
+}
+
+/// The policy defines for how long the database keeps the journal of changes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeJournalPolicy {
+    /// The journal is not recorded.
+    #[default]
+    Disabled,
+    /// The journal is recorded for all heights.
+    FullRange,
+    /// The journal is recorded only for the last `size` heights.
+    Range { size: NonZeroU64 },
+}
+
+impl ChangeJournalPolicy {
+    /// Returns `true` if the database should record the journal of changes.
+    pub fn records_changes(&self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+
+    /// Returns the highest height of the journal that should be pruned
+    /// after the commit of the `height`.
+    pub fn prune_up_to(&self, height: u64) -> Option<u64> {
+        match self {
+            Self::Disabled | Self::FullRange => None,
+            Self::Range { size } => height.checked_sub(size.get()),
+        }
+    }
+}
+
+/// Builds the entries of the journal from the `changes` and the `reverse` changes
+/// that revert them. The history and the journal are not part of the state,
+/// so their columns are skipped.
+pub fn journal_entries<Description>(
+    changes: &Changes,
+    reverse: &Changes,
+) -> Vec<JournalEntry>
+where
+    Description: DatabaseDescription,
+{
+    let history_column = Description::modifications_history_column().id();
+    let journal_column = Description::change_journal_column().id();
+
+    let mut entries = changes
+        .iter()
+        .filter(|(column, _)| **column != history_column && **column != journal_column)
+        .flat_map(|(column, operations)| {
+            let reverse_operations = reverse.get(column);
+            operations.iter().map(move |(key, operation)| {
+                let old_value = reverse_operations
+                    .and_then(|operations| operations.get(key))
+                    .and_then(operation_value);
+                JournalEntry {
+                    column: *column,
+                    key: key.clone(),
+                    old_value,
+                    new_value: operation_value(operation),
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| (a.column, &a.key).cmp(&(b.column, &b.key)));
+    entries
+}
+
+fn operation_value(operation: &WriteOperation) -> Option<Vec<u8>> {
+    match operation {
+        WriteOperation::Insert(value) => Some(value.as_ref().clone()),
+        WriteOperation::Remove => None,
+    }
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Returns the journal of changes starting from the `sequence` in ascending order.
+    /// Sequence numbers missing in the journal were pruned.
+    pub fn change_journal(
+        &self,
+        sequence: u64,
+    ) -> impl Iterator<Item = StorageResult<(u64, JournalRecord)>> + '_ {
+        self.iter_all_by_start::<ChangeJournal<Description>>(
+            Some(&sequence),
+            Some(IterDirection::Forward),
+        )
+    }
+
+    /// Appends the `record` to the journal of changes inside the `transaction`.
+    /// The caller must hold the lock of the height, so the sequence number of
+    /// the record is not taken by a concurrent commit.
+    /// The records of commits also prune the journal outside of the policy window.
+    pub(crate) fn append_to_change_journal<T>(
+        &self,
+        transaction: &mut T,
+        record: JournalRecord,
+    ) -> StorageResult<()>
+    where
+        T: StorageMutate<ChangeJournal<Description>, Error = StorageError>,
+    {
+        let sequence = self
+            .iter_store(
+                Description::change_journal_column(),
+                None,
+                None,
+                IterDirection::Reverse,
+            )
+            .next()
+            .transpose()?
+            .map(|(key, _)| {
+                <Primitive<8> as Decode<u64>>::decode(&key).map_err(StorageError::Codec)
+            })
+            .transpose()?
+            .map_or(0, |sequence| sequence.saturating_add(1));
+
+        let prune_up_to = match (record.kind, record.height) {
+            (JournalRecordKind::Commit, Some(height)) => {
+                self.change_journal_policy.prune_up_to(height)
+            }
+            _ => None,
+        };
+        transaction
+            .storage_as_mut::<ChangeJournal<Description>>()
+            .insert(&sequence, &record)?;
+
+        if let Some(prune_up_to) = prune_up_to {
+            // Records without the height are committed before the first block,
+            // so they are pruned together with the records of the first blocks.
+            let outdated_sequences = self
+                .change_journal(0)
+                .take_while(|result| {
+                    result
+                        .as_ref()
+                        .map(|(_, record)| {
+                            record.height.map_or(true, |height| height <= prune_up_to)
+                        })
+                        .unwrap_or(true)
+                })
+                .map(|result| result.map(|(sequence, _)| sequence))
+                .collect::<StorageResult<Vec<_>>>()?;
+
+            for sequence in outdated_sequences {
+                transaction
+                    .storage_as_mut::<ChangeJournal<Description>>()
+                    .remove(&sequence)?;
+            }
+        }
+
+        Ok(())
+    }
+}
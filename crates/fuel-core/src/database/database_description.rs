@@ -1,3 +1,7 @@
+use crate::{
+    database::migrations::Migrations,
+    state::column_options::ColumnOptions,
+};
 use core::fmt::Debug;
 use fuel_core_storage::kv_store::StorageColumn;
 
@@ -21,8 +25,19 @@ pub trait DatabaseDescription: 'static + Clone + Debug + Send + Sync {
     /// Returns the column used to store the metadata.
     fn metadata_column() -> Self::Column;
 
-    /// Returns the prefix for the column.
-    fn prefix(column: &Self::Column) -> Option<usize>;
+    /// Returns the column used to store the history of modifications.
+    fn modifications_history_column() -> Self::Column;
+
+    /// Returns the column used to store the journal of committed changes.
+    fn change_journal_column() -> Self::Column;
+
+    /// Returns the default options of the column tuned for its access pattern.
+    fn column_options(column: &Self::Column) -> ColumnOptions;
+
+    /// Returns the migrations that upgrade the database from previous versions.
+    /// The migration to the new version is registered together with
+    /// the increment of the [`DatabaseDescription::version`].
+    fn migrations() -> Migrations<Self>;
 }
 
 /// The metadata of the database contains information about the version and its height.
@@ -1,6 +1,14 @@
 use crate::{
-    database::database_description::DatabaseDescription,
+    database::{
+        database_description::DatabaseDescription,
+        migrations::Migrations,
+    },
     fuel_core_graphql_api,
+    fuel_core_graphql_api::storage::{
+        receipts::ContractLogsMigration,
+        transactions::TransactionContractsMigration,
+    },
+    state::column_options::ColumnOptions,
 };
 use fuel_core_types::fuel_types::BlockHeight;
 
@@ -12,7 +20,7 @@ impl DatabaseDescription for OffChain {
     type Height = BlockHeight;
 
     fn version() -> u32 {
-        0
+        6
     }
 
     fn name() -> &'static str {
@@ -23,15 +31,40 @@ impl DatabaseDescription for OffChain {
         Self::Column::Metadata
     }
 
-    fn prefix(column: &Self::Column) -> Option<usize> {
+    fn modifications_history_column() -> Self::Column {
+        Self::Column::ModificationsHistory
+    }
+
+    fn change_journal_column() -> Self::Column {
+        Self::Column::ChangeJournal
+    }
+
+    fn column_options(column: &Self::Column) -> ColumnOptions {
         match column {
             Self::Column::OwnedCoins
             | Self::Column::TransactionsByOwnerBlockIdx
-            | Self::Column::OwnedMessageIds => {
+            | Self::Column::OwnedMessageIds
+            | Self::Column::Balances
+            | Self::Column::ContractReceipts
+            | Self::Column::TransactionsByContractBlockIdx
+            | Self::Column::ContractAssets
+            | Self::Column::ContractLogs => {
                 // prefix is address length
-                Some(32)
+                ColumnOptions::with_prefix(32)
             }
-            _ => None,
+            Self::Column::ModificationsHistory | Self::Column::ChangeJournal => {
+                ColumnOptions::cold()
+            }
+            _ => ColumnOptions::default(),
         }
     }
+
+    /// The migrations to the versions `1`, `2`, `3` and `4` require the on-chain database,
+    /// so they are registered by the
+    /// [`CombinedDatabase::migrate`](crate::combined_database::CombinedDatabase::migrate).
+    fn migrations() -> Migrations<Self> {
+        Migrations::default()
+            .with_migration(5, ContractLogsMigration)
+            .with_migration(6, TransactionContractsMigration)
+    }
 }
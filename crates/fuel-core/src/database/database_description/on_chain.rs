@@ -1,6 +1,17 @@
-use crate::database::database_description::DatabaseDescription;
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        migrations::Migrations,
+    },
+    state::column_options::{
+        ColumnOptions,
+        Compression,
+    },
+};
 use fuel_core_types::fuel_types::BlockHeight;
 
+const MEGABYTE: usize = 1024 * 1024;
+
 #[derive(Clone, Debug)]
 pub struct OnChain;
 
@@ -20,13 +31,56 @@ impl DatabaseDescription for OnChain {
         Self::Column::Metadata
     }
 
-    fn prefix(column: &Self::Column) -> Option<usize> {
+    fn modifications_history_column() -> Self::Column {
+        Self::Column::ModificationsHistory
+    }
+
+    fn change_journal_column() -> Self::Column {
+        Self::Column::ChangeJournal
+    }
+
+    fn column_options(column: &Self::Column) -> ColumnOptions {
         match column {
             Self::Column::ContractsAssets | Self::Column::ContractsState => {
                 // prefix is address length
-                Some(32)
+                ColumnOptions::with_prefix(32)
+            }
+            // The code and the merkle roots are read on each execution of the contract.
+            Self::Column::ContractsRawCode => ColumnOptions {
+                block_cache_weight: 2,
+                read_cache_size: 64 * MEGABYTE,
+                ..Default::default()
+            },
+            Self::Column::ContractsAssetsMerkleData
+            | Self::Column::ContractsStateMerkleData => ColumnOptions {
+                block_cache_weight: 2,
+                ..Default::default()
+            },
+            // Each transaction reads the contracts it uses and the consensus parameters.
+            Self::Column::ContractsLatestUtxo => ColumnOptions {
+                read_cache_size: 8 * MEGABYTE,
+                ..Default::default()
+            },
+            Self::Column::ConsensusParametersVersions
+            | Self::Column::StateTransitionBytecodeVersions => ColumnOptions {
+                read_cache_size: MEGABYTE,
+                ..Default::default()
+            },
+            // Blocks and transactions are written once and rarely read later.
+            Self::Column::FuelBlocks
+            | Self::Column::Transactions
+            | Self::Column::FuelBlockConsensus => ColumnOptions {
+                compression: Compression::Zstd,
+                ..Default::default()
+            },
+            Self::Column::ModificationsHistory | Self::Column::ChangeJournal => {
+                ColumnOptions::cold()
             }
-            _ => None,
+            _ => ColumnOptions::default(),
         }
     }
+
+    fn migrations() -> Migrations<Self> {
+        Migrations::default()
+    }
 }
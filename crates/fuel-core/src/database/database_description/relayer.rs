@@ -1,4 +1,10 @@
-use crate::database::database_description::DatabaseDescription;
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        migrations::Migrations,
+    },
+    state::column_options::ColumnOptions,
+};
 use fuel_core_storage::kv_store::StorageColumn;
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
 
@@ -16,6 +22,8 @@ use fuel_core_types::blockchain::primitives::DaBlockHeight;
 )]
 pub enum DummyColumn {
     Metadata,
+    ModificationsHistory,
+    ChangeJournal,
 }
 
 impl StorageColumn for DummyColumn {
@@ -52,7 +60,25 @@ impl DatabaseDescription for Relayer {
         Self::Column::Metadata
     }
 
-    fn prefix(_: &Self::Column) -> Option<usize> {
-        None
+    fn modifications_history_column() -> Self::Column {
+        Self::Column::ModificationsHistory
+    }
+
+    fn change_journal_column() -> Self::Column {
+        Self::Column::ChangeJournal
+    }
+
+    fn column_options(column: &Self::Column) -> ColumnOptions {
+        if *column == Self::Column::ModificationsHistory
+            || *column == Self::Column::ChangeJournal
+        {
+            ColumnOptions::cold()
+        } else {
+            ColumnOptions::default()
+        }
+    }
+
+    fn migrations() -> Migrations<Self> {
+        Migrations::default()
     }
 }
@@ -0,0 +1,446 @@
+use crate::database::{
+    change_journal::ChangeJournal,
+    database_description::{
+        DatabaseDescription,
+        DatabaseMetadata,
+    },
+    metadata::MetadataTable,
+    Database,
+    DatabaseHeight,
+    Error as DatabaseError,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        primitive::Primitive,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        StorageTransaction,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageInspect,
+    StorageMutate,
+};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+};
+
+/// The table stores the cursor of the unfinished migration by the version
+/// the migration upgrades the database to. The table shares the metadata column
+/// with the [`MetadataTable`], their keys don't overlap.
+pub struct MigrationProgress<Description>(core::marker::PhantomData<Description>);
+
+impl<Description> Mappable for MigrationProgress<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Key = Self::OwnedKey;
+    type OwnedKey = u32;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<u8>;
+}
+
+impl<Description> TableWithBlueprint for MigrationProgress<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Blueprint = Plain<Primitive<4>, Raw>;
+    type Column = Description::Column;
+
+    fn column() -> Self::Column {
+        Description::metadata_column()
+    }
+}
+
+/// The result of the migration of one batch.
+#[derive(Debug, Default)]
+pub struct MigrationBatch {
+    /// The changes made by the batch.
+    pub changes: Changes,
+    /// The cursor of the next batch. `None` if the migration is done.
+    pub cursor: Option<Vec<u8>>,
+}
+
+/// The migration upgrades the database from the previous version to the version
+/// it is registered under. The migration transforms the tables in place batch by batch.
+/// Each batch is committed together with the cursor of the next batch,
+/// so an interrupted migration resumes from the last committed batch.
+pub trait Migration<Description>: Send + Sync
+where
+    Description: DatabaseDescription,
+{
+    /// Returns the name of the migration used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Migrates the batch starting at the `cursor` returned by the previous batch.
+    /// The `cursor` is `None` for the first batch.
+    fn migrate_batch(
+        &self,
+        database: &Database<Description>,
+        cursor: Option<Vec<u8>>,
+    ) -> StorageResult<MigrationBatch>;
+}
+
+/// The registry of migrations of the database by the version they upgrade to.
+pub struct Migrations<Description> {
+    migrations: BTreeMap<u32, Box<dyn Migration<Description>>>,
+}
+
+impl<Description> Default for Migrations<Description> {
+    fn default() -> Self {
+        Self {
+            migrations: Default::default(),
+        }
+    }
+}
+
+impl<Description> Migrations<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Registers the `migration` that upgrades the database to the `version`.
+    pub fn with_migration<M>(mut self, version: u32, migration: M) -> Self
+    where
+        M: Migration<Description> + 'static,
+    {
+        self.migrations.insert(version, Box::new(migration));
+        self
+    }
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Description::Height: DatabaseHeight,
+    Self: StorageInspect<MetadataTable<Description>, Error = StorageError>
+        + StorageInspect<MigrationProgress<Description>, Error = StorageError>,
+    for<'a> StorageTransaction<&'a Self>: StorageMutate<MetadataTable<Description>, Error = StorageError>
+        + StorageMutate<MigrationProgress<Description>, Error = StorageError>
+        + StorageMutate<ChangeJournal<Description>, Error = StorageError>,
+{
+    /// Upgrades the database to the [`DatabaseDescription::version`] by running
+    /// the registered migrations one by one. The new database doesn't require migrations.
+    pub fn migrate(&self) -> StorageResult<()> {
+        self.migrate_with(&Description::migrations())
+    }
+
+    /// Upgrades the database to the [`DatabaseDescription::version`] by running
+    /// the `migrations` instead of the [`DatabaseDescription::migrations`].
+    /// It allows registering migrations that depend on other databases.
+    pub fn migrate_with(
+        &self,
+        migrations: &Migrations<Description>,
+    ) -> StorageResult<()> {
+        self.migrate_to(migrations, Description::version())
+    }
+
+    fn migrate_to(
+        &self,
+        migrations: &Migrations<Description>,
+        target_version: u32,
+    ) -> StorageResult<()> {
+        let Some(metadata) = self
+            .storage::<MetadataTable<Description>>()
+            .get(&())?
+            .map(Cow::into_owned)
+        else {
+            return Ok(())
+        };
+
+        let found = metadata.version();
+        let invalid_version = DatabaseError::InvalidDatabaseVersion {
+            found,
+            expected: target_version,
+        };
+        if found > target_version {
+            return Err(invalid_version.into())
+        }
+
+        // Fails before any modification if some of the migrations are missing.
+        let steps = (found.saturating_add(1)..=target_version)
+            .map(|version| {
+                migrations
+                    .migrations
+                    .get(&version)
+                    .map(|migration| (version, migration.as_ref()))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(invalid_version)?;
+
+        for (version, migration) in steps {
+            self.run_migration(version, migration, metadata.height())?;
+        }
+
+        Ok(())
+    }
+
+    fn run_migration(
+        &self,
+        version: u32,
+        migration: &dyn Migration<Description>,
+        height: &Description::Height,
+    ) -> StorageResult<()> {
+        let mut cursor = self
+            .storage::<MigrationProgress<Description>>()
+            .get(&version)?
+            .map(Cow::into_owned);
+        let action = if cursor.is_some() {
+            "Resuming"
+        } else {
+            "Starting"
+        };
+        tracing::info!(
+            "{action} the migration `{}` of the {} database to the version {version}",
+            migration.name(),
+            Description::name(),
+        );
+
+        loop {
+            let MigrationBatch {
+                changes,
+                cursor: next_cursor,
+            } = migration.migrate_batch(self, cursor)?;
+
+            let mut transaction =
+                StorageTransaction::transaction(self, ConflictPolicy::Overwrite, changes);
+            if let Some(next_cursor) = &next_cursor {
+                transaction
+                    .storage_as_mut::<MigrationProgress<Description>>()
+                    .insert(&version, next_cursor)?;
+            } else {
+                transaction
+                    .storage_as_mut::<MigrationProgress<Description>>()
+                    .remove(&version)?;
+                transaction
+                    .storage_as_mut::<MetadataTable<Description>>()
+                    .insert(
+                        &(),
+                        &DatabaseMetadata::V1 {
+                            version,
+                            height: *height,
+                        },
+                    )?;
+            }
+            self.commit_changes_without_height_update(transaction.into_changes())?;
+
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+
+        tracing::info!(
+            "Finished the migration `{}` of the {} database to the version {version}",
+            migration.name(),
+            Description::name(),
+        );
+        Ok(())
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::database_description::on_chain::OnChain;
+    use fuel_core_storage::{
+        codec::Decode,
+        iter::{
+            IterDirection,
+            IteratorOverTable,
+        },
+        tables::{
+            Coins,
+            FuelBlocks,
+        },
+        transactional::{
+            ReadTransaction,
+            WriteTransaction,
+        },
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::{
+            TxId,
+            UtxoId,
+        },
+        fuel_types::BlockHeight,
+    };
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    const COINS: u16 = 3;
+
+    /// Doubles the amount of one coin per batch.
+    #[derive(Default)]
+    struct DoubleCoinAmounts {
+        fail_on_batch: Option<usize>,
+        batches: AtomicUsize,
+    }
+
+    impl Migration<OnChain> for DoubleCoinAmounts {
+        fn name(&self) -> &'static str {
+            "DoubleCoinAmounts"
+        }
+
+        fn migrate_batch(
+            &self,
+            database: &Database<OnChain>,
+            cursor: Option<Vec<u8>>,
+        ) -> StorageResult<MigrationBatch> {
+            let batch = self.batches.fetch_add(1, Ordering::SeqCst);
+            if self.fail_on_batch == Some(batch) {
+                return Err(anyhow::anyhow!("The migration is interrupted").into())
+            }
+
+            let start = cursor
+                .map(|cursor| Primitive::<{ TxId::LEN + 2 }>::decode(&cursor))
+                .transpose()?;
+            let mut coins = database
+                .iter_all_by_start::<Coins>(start.as_ref(), Some(IterDirection::Forward));
+
+            let mut transaction = database.read_transaction();
+            if let Some(coin) = coins.next() {
+                let (utxo_id, mut coin) = coin?;
+                coin.set_amount(coin.amount().saturating_mul(2));
+                transaction
+                    .storage_as_mut::<Coins>()
+                    .insert(&utxo_id, &coin)?;
+            }
+            let cursor = coins
+                .next()
+                .transpose()?
+                .map(|(utxo_id, _)| utxo_id_bytes(&utxo_id));
+
+            Ok(MigrationBatch {
+                changes: transaction.into_changes(),
+                cursor,
+            })
+        }
+    }
+
+    fn utxo_id_bytes(utxo_id: &UtxoId) -> Vec<u8> {
+        fuel_core_storage::codec::primitive::utxo_id_to_bytes(utxo_id).to_vec()
+    }
+
+    fn database_with_coins() -> Database<OnChain> {
+        let mut database = Database::<OnChain>::default();
+        let mut transaction = database.write_transaction();
+        for output_index in 0..COINS {
+            let mut coin = CompressedCoin::default();
+            coin.set_amount(1);
+            transaction
+                .storage_as_mut::<Coins>()
+                .insert(&UtxoId::new(TxId::zeroed(), output_index), &coin)
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&BlockHeight::from(1), &CompressedBlock::default())
+            .unwrap();
+        database
+    }
+
+    fn amounts(database: &Database<OnChain>) -> Vec<u64> {
+        database
+            .iter_all::<Coins>(None)
+            .map(|coin| *coin.unwrap().1.amount())
+            .collect()
+    }
+
+    fn version(database: &Database<OnChain>) -> u32 {
+        database
+            .storage::<MetadataTable<OnChain>>()
+            .get(&())
+            .unwrap()
+            .unwrap()
+            .version()
+    }
+
+    #[test]
+    fn migrate_to__runs_migrations_and_updates_version() {
+        // Given
+        let database = database_with_coins();
+        let migrations =
+            Migrations::default().with_migration(1, DoubleCoinAmounts::default());
+
+        // When
+        database.migrate_to(&migrations, 1).unwrap();
+
+        // Then
+        assert_eq!(amounts(&database), vec![2; COINS as usize]);
+        assert_eq!(version(&database), 1);
+        assert_eq!(database.latest_height().unwrap(), Some(1.into()));
+        assert!(database
+            .storage::<MigrationProgress<OnChain>>()
+            .get(&1)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn migrate_to__resumes_interrupted_migration() {
+        // Given
+        let database = database_with_coins();
+        let interrupted = Migrations::default().with_migration(
+            1,
+            DoubleCoinAmounts {
+                fail_on_batch: Some(1),
+                ..Default::default()
+            },
+        );
+        database.migrate_to(&interrupted, 1).unwrap_err();
+        assert_eq!(amounts(&database), vec![2, 1, 1]);
+        assert_eq!(version(&database), 0);
+
+        // When
+        let migrations =
+            Migrations::default().with_migration(1, DoubleCoinAmounts::default());
+        database.migrate_to(&migrations, 1).unwrap();
+
+        // Then
+        assert_eq!(amounts(&database), vec![2; COINS as usize]);
+        assert_eq!(version(&database), 1);
+    }
+
+    #[test]
+    fn migrate_to__fails_without_registered_migration() {
+        // Given
+        let database = database_with_coins();
+        let migrations =
+            Migrations::default().with_migration(1, DoubleCoinAmounts::default());
+
+        // When
+        let result = database.migrate_to(&migrations, 2);
+
+        // Then
+        assert!(result.is_err());
+        assert_eq!(amounts(&database), vec![1; COINS as usize]);
+        assert_eq!(version(&database), 0);
+    }
+
+    #[test]
+    fn migrate_to__skips_new_database() {
+        // Given
+        let database = Database::<OnChain>::default();
+
+        // When
+        let result = database.migrate_to(&Migrations::default(), 1);
+
+        // Then
+        assert!(result.is_ok());
+    }
+}
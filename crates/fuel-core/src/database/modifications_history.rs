@@ -0,0 +1,326 @@
+use crate::{
+    database::{
+        change_journal::{
+            journal_entries,
+            ChangeJournal,
+            ChangeJournalPolicy,
+            JournalRecord,
+            JournalRecordKind,
+        },
+        database_description::DatabaseDescription,
+        metadata::MetadataTable,
+        Database,
+        DatabaseHeight,
+        Error as DatabaseError,
+    },
+    state::historical::HistoricalView,
+};
+use fuel_core_services::SharedMutex;
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+        WriteOperation,
+    },
+    structured_storage::TableWithBlueprint,
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        StorageTransaction,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageInspect,
+    StorageMutate,
+};
+use std::{
+    collections::HashMap,
+    num::NonZeroU64,
+    sync::Arc,
+};
+
+/// The table stores the history of modifications of the database.
+/// The entry under the height contains the changes required to revert
+/// the state of the database to the previous height.
+pub struct ModificationsHistory<Description>(core::marker::PhantomData<Description>);
+
+impl<Description> Mappable for ModificationsHistory<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Key = Self::OwnedKey;
+    type OwnedKey = u64;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Changes;
+}
+
+impl<Description> TableWithBlueprint for ModificationsHistory<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Blueprint = Plain<Primitive<8>, Postcard>;
+    type Column = Description::Column;
+
+    fn column() -> Self::Column {
+        Description::modifications_history_column()
+    }
+}
+
+/// The policy defines for how long the database keeps the history of modifications.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateRewindPolicy {
+    /// The history is not recorded, only the latest state is available.
+    #[default]
+    NoRewind,
+    /// The history is recorded for all heights.
+    RewindFullRange,
+    /// The history is recorded only for the last `size` heights.
+    RewindRange { size: NonZeroU64 },
+}
+
+impl StateRewindPolicy {
+    /// Returns `true` if the database should record the history of modifications.
+    pub fn records_history(&self) -> bool {
+        !matches!(self, Self::NoRewind)
+    }
+
+    /// Returns the highest height of the history that should be pruned
+    /// after the commit of the `height`.
+    pub fn prune_up_to(&self, height: u64) -> Option<u64> {
+        match self {
+            Self::NoRewind | Self::RewindFullRange => None,
+            Self::RewindRange { size } => height.checked_sub(size.get()),
+        }
+    }
+}
+
+/// Calculates the changes that revert the `changes` applied on top of the `storage`.
+/// The history and the journal of changes are not reverted.
+pub fn reverse_changes<Description, S>(
+    storage: &S,
+    changes: &Changes,
+) -> StorageResult<Changes>
+where
+    Description: DatabaseDescription,
+    S: KeyValueInspect<Column = Description::Column> + ?Sized,
+{
+    let columns = enum_iterator::all::<Description::Column>()
+        .map(|column| (column.id(), column))
+        .collect::<HashMap<_, _>>();
+    let history_column = Description::modifications_history_column().id();
+    let journal_column = Description::change_journal_column().id();
+
+    let mut reverse = Changes::default();
+    for (column_id, operations) in changes {
+        if *column_id == history_column || *column_id == journal_column {
+            continue
+        }
+        let column = columns.get(column_id).ok_or_else(|| {
+            DatabaseError::Other(anyhow::anyhow!("Unknown column id {column_id}"))
+        })?;
+        let reverse_operations = reverse.entry(*column_id).or_default();
+
+        for key in operations.keys() {
+            let operation = match storage.get(key, *column)? {
+                Some(value) => WriteOperation::Insert(value),
+                None => WriteOperation::Remove,
+            };
+            reverse_operations.insert(key.clone(), operation);
+        }
+    }
+
+    Ok(reverse)
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Description::Height: DatabaseHeight,
+{
+    /// Returns the read-only view of the database at the `height`.
+    /// The view is built from the snapshot of the latest state by reverting
+    /// all modifications recorded in the [`ModificationsHistory`] above the `height`.
+    pub fn historical_view(&self, height: &Description::Height) -> StorageResult<Self> {
+        let requested_height = height.as_u64();
+        // The history and the state must be read from the same point in time.
+        let latest = self.snapshot()?;
+        let latest_height = latest
+            .height
+            .lock()
+            .as_ref()
+            .map(DatabaseHeight::as_u64)
+            .ok_or(DatabaseError::HistoryIsNotAvailable { requested_height })?;
+
+        if requested_height > latest_height {
+            return Err(DatabaseError::RequestedHeightIsAboveLatest {
+                requested_height,
+                latest_height,
+            }
+            .into())
+        }
+
+        let first_reverted_height = requested_height.saturating_add(1);
+        let mut expected_height = first_reverted_height;
+        let mut overlay = Changes::default();
+        let history = latest
+            .iter_all_by_start::<ModificationsHistory<Description>>(
+                Some(&first_reverted_height),
+                Some(IterDirection::Forward),
+            )
+            .take_while(|result| {
+                result
+                    .as_ref()
+                    .map(|(height, _)| *height <= latest_height)
+                    .unwrap_or(true)
+            });
+
+        for result in history {
+            let (height, reverse_changes) = result?;
+
+            // Each height between the requested and the latest one must be present,
+            // otherwise the history was pruned or wasn't recorded.
+            if height != expected_height {
+                return Err(
+                    DatabaseError::HistoryIsNotAvailable { requested_height }.into()
+                )
+            }
+            expected_height = expected_height.saturating_add(1);
+
+            // The closest to the requested height modification defines the value.
+            for (column, operations) in reverse_changes {
+                let overlay_operations = overlay.entry(column).or_default();
+                for (key, operation) in operations {
+                    overlay_operations.entry(key).or_insert(operation);
+                }
+            }
+        }
+
+        if expected_height != latest_height.saturating_add(1) {
+            return Err(DatabaseError::HistoryIsNotAvailable { requested_height }.into())
+        }
+        let view = HistoricalView::<Description>::new(latest.data, overlay);
+        Ok(Self {
+            height: SharedMutex::new(Some(*height)),
+            data: Arc::new(view),
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            change_journal_policy: ChangeJournalPolicy::Disabled,
+        })
+    }
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Description::Height: DatabaseHeight,
+    Self: StorageInspect<ModificationsHistory<Description>, Error = StorageError>,
+    for<'a> StorageTransaction<&'a Self>: StorageInspect<MetadataTable<Description>, Error = StorageError>
+        + StorageMutate<ModificationsHistory<Description>, Error = StorageError>
+        + StorageMutate<ChangeJournal<Description>, Error = StorageError>,
+{
+    /// Returns an error if the history doesn't contain the modifications of all
+    /// heights above the `height`. Only the keys of the history are checked.
+    pub fn ensure_history_is_available(
+        &self,
+        height: &Description::Height,
+    ) -> StorageResult<()> {
+        let requested_height = height.as_u64();
+        let latest_height = self
+            .height
+            .lock()
+            .as_ref()
+            .map(DatabaseHeight::as_u64)
+            .ok_or(DatabaseError::HistoryIsNotAvailable { requested_height })?;
+        if requested_height > latest_height {
+            return Err(DatabaseError::RequestedHeightIsAboveLatest {
+                requested_height,
+                latest_height,
+            }
+            .into())
+        }
+        for reverted_height in requested_height.saturating_add(1)..=latest_height {
+            if !self
+                .storage::<ModificationsHistory<Description>>()
+                .contains_key(&reverted_height)?
+            {
+                return Err(
+                    DatabaseError::HistoryIsNotAvailable { requested_height }.into()
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// Reverts the state of the database to the `height` by applying the modifications
+    /// recorded in the [`ModificationsHistory`]. Heights are reverted one by one
+    /// starting from the latest, each in a separate atomic commit, so an interrupted
+    /// rollback leaves the database at a consistent intermediate height.
+    /// The journal of changes records the revert of each height.
+    pub fn rollback_to(&self, height: &Description::Height) -> StorageResult<()> {
+        // Fails before any modification if the history doesn't cover the `height`.
+        self.ensure_history_is_available(height)?;
+        let requested_height = height.as_u64();
+
+        loop {
+            let mut guard = self.height.lock();
+            let latest_height = guard
+                .as_ref()
+                .map(DatabaseHeight::as_u64)
+                .ok_or(DatabaseError::HistoryIsNotAvailable { requested_height })?;
+
+            if latest_height <= requested_height {
+                break
+            }
+
+            let reverse = self
+                .storage::<ModificationsHistory<Description>>()
+                .get(&latest_height)?
+                .ok_or(DatabaseError::HistoryIsNotAvailable { requested_height })?
+                .into_owned();
+
+            // The journal records the revert, so consumers can revert the height too.
+            let record = if self.change_journal_policy().records_changes() {
+                let current =
+                    reverse_changes::<Description, _>(self.data.as_ref(), &reverse)?;
+                Some(JournalRecord {
+                    kind: JournalRecordKind::Revert,
+                    height: Some(latest_height),
+                    entries: journal_entries::<Description>(&reverse, &current),
+                })
+            } else {
+                None
+            };
+
+            // The reverse changes also restore the metadata of the previous height.
+            let mut transaction =
+                StorageTransaction::transaction(self, ConflictPolicy::Overwrite, reverse);
+            transaction
+                .storage_as_mut::<ModificationsHistory<Description>>()
+                .remove(&latest_height)?;
+            if let Some(record) = record {
+                self.append_to_change_journal(&mut transaction, record)?;
+            }
+            let new_height = transaction
+                .storage::<MetadataTable<Description>>()
+                .get(&())?
+                .map(|metadata| *metadata.height());
+            let changes = transaction.into_changes();
+
+            self.data.as_ref().commit_changes(new_height, changes)?;
+            *guard = new_height;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::database::{
+    database_description::on_chain::OnChain,
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    column::Column,
+    structured_storage::TableWithBlueprint,
+    Mappable,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The table stores the height to which the on-chain and off-chain databases
+/// are being rolled back. The value is set before the first height is reverted
+/// and removed after both databases reach the height, so its presence means
+/// that the rollback was interrupted and must be finished.
+pub struct RollbackTarget;
+
+impl Mappable for RollbackTarget {
+    type Key = ();
+    type OwnedKey = ();
+    type Value = BlockHeight;
+    type OwnedValue = BlockHeight;
+}
+
+impl TableWithBlueprint for RollbackTarget {
+    type Blueprint = Plain<Postcard, Primitive<4>>;
+    type Column = Column;
+
+    fn column() -> Self::Column {
+        Column::RollbackTarget
+    }
+}
+
+impl Database<OnChain> {
+    /// Returns the height of the unfinished rollback, if any.
+    pub fn rollback_target(&self) -> StorageResult<Option<BlockHeight>> {
+        let height = self
+            .storage::<RollbackTarget>()
+            .get(&())?
+            .map(|height| height.into_owned());
+
+        Ok(height)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::database::{
+    change_journal::{
+        journal_entries,
+        ChangeJournal,
+        JournalRecord,
+        JournalRecordKind,
+    },
+    database_description::{
+        on_chain::OnChain,
+        DatabaseDescription,
+    },
+    modifications_history::reverse_changes,
+    Database,
+    DatabaseHeight,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    column::Column,
+    structured_storage::TableWithBlueprint,
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        StorageTransaction,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsRef,
+    StorageMutate,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The table stores the lowest height of the block available in the on-chain database.
+/// Blocks, transactions and their off-chain indexes below this height were removed
+/// by the state pruning. If the value is not set, nothing was pruned.
+pub struct LowestAvailableHeight;
+
+impl Mappable for LowestAvailableHeight {
+    type Key = ();
+    type OwnedKey = ();
+    type Value = BlockHeight;
+    type OwnedValue = BlockHeight;
+}
+
+impl TableWithBlueprint for LowestAvailableHeight {
+    type Blueprint = Plain<Postcard, Primitive<4>>;
+    type Column = Column;
+
+    fn column() -> Self::Column {
+        Column::LowestAvailableHeight
+    }
+}
+
+impl Database<OnChain> {
+    /// Returns the lowest height of the block available in the database.
+    pub fn lowest_available_height(&self) -> StorageResult<Option<BlockHeight>> {
+        let height = self
+            .storage::<LowestAvailableHeight>()
+            .get(&())?
+            .map(|height| height.into_owned());
+
+        Ok(height)
+    }
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Description::Height: DatabaseHeight,
+    for<'a> StorageTransaction<&'a Self>:
+        StorageMutate<ChangeJournal<Description>, Error = StorageError>,
+{
+    /// Commits the `changes` that don't belong to any height, like the removal
+    /// of the outdated data. The height of the database stays the same, and
+    /// the changes are not recorded in the history of modifications.
+    /// The journal of changes records them as a maintenance commit.
+    pub(crate) fn commit_changes_without_height_update(
+        &self,
+        changes: Changes,
+    ) -> StorageResult<()> {
+        let guard = self.height.lock();
+        let changes = if self.change_journal_policy().records_changes() {
+            let reverse =
+                reverse_changes::<Description, _>(self.data.as_ref(), &changes)?;
+            let record = JournalRecord {
+                kind: JournalRecordKind::Maintenance,
+                height: guard.as_ref().map(DatabaseHeight::as_u64),
+                entries: journal_entries::<Description>(&changes, &reverse),
+            };
+            let mut transaction =
+                StorageTransaction::transaction(self, ConflictPolicy::Overwrite, changes);
+            self.append_to_change_journal(&mut transaction, record)?;
+            transaction.into_changes()
+        } else {
+            changes
+        };
+        self.data.as_ref().commit_changes(*guard, changes)
+    }
+}
@@ -6,9 +6,11 @@ use fuel_core_storage::{
     structured_storage::StructuredStorage,
     Error as StorageError,
     Mappable,
+    MerkleProofStorage,
     MerkleRoot,
     MerkleRootStorage,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageAsRef,
     StorageInspect,
     StorageRead,
@@ -114,6 +116,17 @@ where
     }
 }
 
+impl<Description, M> MerkleProofStorage<M> for Database<Description>
+where
+    Description: DatabaseDescription,
+    M: Mappable,
+    for<'a> StructuredStorage<&'a Self>: MerkleProofStorage<M>,
+{
+    fn proof(&self, key: &M::Key) -> StorageResult<SparseMerkleProof> {
+        <_ as MerkleProofStorage<M>>::proof(&StructuredStorage::new(self), key)
+    }
+}
+
 impl<Description, M> StorageRead<M> for Database<Description>
 where
     Description: DatabaseDescription,
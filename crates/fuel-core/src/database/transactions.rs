@@ -4,20 +4,26 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::storage::transactions::{
+        ContractTransactions,
         OwnedTransactionIndexCursor,
         OwnedTransactionIndexKey,
         OwnedTransactions,
+        TransactionContracts,
+        TransactionPointer,
         TransactionStatuses,
     },
 };
 
 use fuel_core_storage::{
+    blueprint::indexed::IteratorOverIndex,
     iter::{
         IterDirection,
         IteratorOverTable,
     },
+    not_found,
     tables::Transactions,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_types::{
     self,
@@ -26,7 +32,10 @@ use fuel_core_types::{
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        ContractId,
+    },
     services::txpool::TransactionStatus,
 };
 
@@ -65,11 +74,35 @@ impl Database<OffChain> {
         })
     }
 
+    /// Iterates over the transactions using or creating the contract by the
+    /// [`ContractTransactions`] index. It is the same as [`Self::owned_transactions`],
+    /// but for contracts.
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let start = start.map(TransactionPointer::from);
+        self.iter_by_index::<TransactionContracts, ContractTransactions>(
+            &contract_id,
+            start.as_ref(),
+            direction,
+        )
+        .map(|pointer| {
+            let pointer = pointer?;
+            let info = self
+                .storage::<TransactionContracts>()
+                .get(&pointer)?
+                .ok_or(not_found!(TransactionContracts))?;
+            Ok((pointer.into(), info.tx_id))
+        })
+    }
+
     pub fn get_tx_status(
         &self,
         id: &Bytes32,
     ) -> StorageResult<Option<TransactionStatus>> {
-        use fuel_core_storage::StorageAsRef;
         self.storage::<TransactionStatuses>()
             .get(id)
             .map(|v| v.map(|v| v.into_owned()))
@@ -169,11 +169,11 @@ mod tests {
         }
 
         fn view_at(&self, _: &Self::Height) -> StorageResult<Self::View> {
-            Ok(self.latest_view())
+            self.latest_view()
         }
 
-        fn latest_view(&self) -> Self::View {
-            self.clone()
+        fn latest_view(&self) -> StorageResult<Self::View> {
+            Ok(self.clone())
         }
     }
 
@@ -512,6 +512,7 @@ mod tests {
             } = producer
                 .storage_view_provider
                 .latest_view()
+                .unwrap()
                 .contract_balances(recipient, None, IterDirection::Forward)
                 .next()
                 .unwrap()
@@ -606,6 +607,7 @@ mod tests {
             } = producer
                 .storage_view_provider
                 .latest_view()
+                .unwrap()
                 .contract_balances(recipient, None, IterDirection::Forward)
                 .next()
                 .unwrap()
@@ -724,6 +726,7 @@ mod tests {
             } = validator
                 .storage_view_provider
                 .latest_view()
+                .unwrap()
                 .contract_balances(recipient, None, IterDirection::Forward)
                 .next()
                 .unwrap()
@@ -2370,7 +2373,7 @@ mod tests {
         };
 
         let mut exec = make_executor(&messages);
-        let view = exec.storage_view_provider.latest_view();
+        let view = exec.storage_view_provider.latest_view().unwrap();
         assert!(!view.message_is_spent(message_coin.nonce()).unwrap());
         assert!(!view.message_is_spent(message_data.nonce()).unwrap());
 
@@ -2383,7 +2386,7 @@ mod tests {
         assert_eq!(skipped_transactions.len(), 0);
 
         // Successful execution consumes `message_coin` and `message_data`.
-        let view = exec.storage_view_provider.latest_view();
+        let view = exec.storage_view_provider.latest_view().unwrap();
         assert!(view.message_is_spent(message_coin.nonce()).unwrap());
         assert!(view.message_is_spent(message_data.nonce()).unwrap());
         assert_eq!(
@@ -2419,7 +2422,7 @@ mod tests {
         };
 
         let mut exec = make_executor(&messages);
-        let view = exec.storage_view_provider.latest_view();
+        let view = exec.storage_view_provider.latest_view().unwrap();
         assert!(!view.message_is_spent(message_coin.nonce()).unwrap());
         assert!(!view.message_is_spent(message_data.nonce()).unwrap());
 
@@ -2432,7 +2435,7 @@ mod tests {
         assert_eq!(skipped_transactions.len(), 0);
 
         // We should spend only `message_coin`. The `message_data` should be unspent.
-        let view = exec.storage_view_provider.latest_view();
+        let view = exec.storage_view_provider.latest_view().unwrap();
         assert!(view.message_is_spent(message_coin.nonce()).unwrap());
         assert!(!view.message_is_spent(message_data.nonce()).unwrap());
         assert_eq!(*view.coin(&UtxoId::new(tx_id, 0)).unwrap().amount(), amount);
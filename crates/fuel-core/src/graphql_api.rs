@@ -7,7 +7,10 @@ use fuel_core_types::{
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+};
 
 pub mod api_service;
 pub mod database;
@@ -28,6 +31,8 @@ pub struct Config {
     pub chain_name: String,
     pub consensus_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The directory for the backups created by the `backupDatabase` mutation.
+    pub backup_directory: Option<PathBuf>,
 }
 
 pub trait IntoApiResult<T> {
@@ -2,6 +2,7 @@ use crate::{
     fuel_core_graphql_api::{
         metrics_extension::MetricsExtension,
         ports::{
+            BlockImporterPort,
             BlockProducerPort,
             ConsensusModulePort,
             GasPriceEstimate,
@@ -10,7 +11,10 @@ use crate::{
             P2pPort,
             TxPoolPort,
         },
-        view_extension::ViewExtension,
+        view_extension::{
+            LiveView,
+            ViewExtension,
+        },
         Config,
     },
     schema::{
@@ -88,6 +92,7 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
 
 pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
@@ -176,13 +181,14 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    block_importer: BlockImporter,
     gas_price_provider: GasPriceProvider,
     log_threshold_ms: Duration,
     request_timeout: Duration,
 ) -> anyhow::Result<Service>
 where
-    OnChain: AtomicView<Height = BlockHeight> + 'static,
-    OffChain: AtomicView<Height = BlockHeight> + 'static,
+    OnChain: AtomicView<Height = BlockHeight> + OnChainDatabase + Clone + 'static,
+    OffChain: AtomicView<Height = BlockHeight> + OffChainDatabase + Clone + 'static,
     OnChain::View: OnChainDatabase,
     OffChain::View: OffChainDatabase,
 {
@@ -196,6 +202,7 @@ where
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(block_importer)
         .data(gas_price_provider)
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
@@ -261,7 +268,7 @@ async fn graphql_subscription_handler(
     req: Json<Request>,
 ) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
     let stream = schema
-        .execute_stream(req.0)
+        .execute_stream(req.0.data(LiveView))
         .map(|r| Ok(Event::default().json_data(r).unwrap()));
     Sse::new(stream)
         .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
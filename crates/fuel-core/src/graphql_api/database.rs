@@ -9,6 +9,14 @@ use crate::fuel_core_graphql_api::{
         OffChainDatabase,
         OnChainDatabase,
     },
+    storage::{
+        assets::AssetDetails,
+        receipts::{
+            ContractReceipt,
+            IndexedReceiptType,
+            ReceiptPointer,
+        },
+    },
 };
 use fuel_core_storage::{
     iter::{
@@ -18,7 +26,11 @@ use fuel_core_storage::{
     transactional::AtomicView,
     Error as StorageError,
     Mappable,
+    MerkleProofStorage,
+    MerkleRoot,
+    MerkleRootStorage,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageInspect,
 };
 use fuel_core_txpool::types::{
@@ -37,6 +49,7 @@ use fuel_core_types::{
         MerkleProof,
         Message,
     },
+    fuel_asm::Word,
     fuel_tx::{
         Address,
         AssetId,
@@ -72,31 +85,46 @@ pub struct ReadDatabase {
     on_chain: Box<dyn AtomicView<View = OnChainView, Height = BlockHeight>>,
     /// The off-chain database view provider.
     off_chain: Box<dyn AtomicView<View = OffChainView, Height = BlockHeight>>,
+    /// The on-chain database that always returns the latest data.
+    live_on_chain: OnChainView,
+    /// The off-chain database that always returns the latest data.
+    live_off_chain: OffChainView,
 }
 
 impl ReadDatabase {
     /// Creates a new [`ReadDatabase`] with the given on-chain and off-chain database view providers.
     pub fn new<OnChain, OffChain>(on_chain: OnChain, off_chain: OffChain) -> Self
     where
-        OnChain: AtomicView<Height = BlockHeight> + 'static,
-        OffChain: AtomicView<Height = BlockHeight> + 'static,
+        OnChain: AtomicView<Height = BlockHeight> + OnChainDatabase + Clone + 'static,
+        OffChain: AtomicView<Height = BlockHeight> + OffChainDatabase + Clone + 'static,
         OnChain::View: OnChainDatabase,
         OffChain::View: OffChainDatabase,
     {
         Self {
+            live_on_chain: Arc::new(on_chain.clone()),
+            live_off_chain: Arc::new(off_chain.clone()),
             on_chain: Box::new(ArcWrapper::new(on_chain)),
             off_chain: Box::new(ArcWrapper::new(off_chain)),
         }
     }
 
     /// Creates a consistent view of the database.
-    pub fn view(&self) -> ReadView {
+    pub fn view(&self) -> StorageResult<ReadView> {
         // TODO: Use the same height for both views to guarantee consistency.
         //  It is not possible to implement until `view_at` is implemented for the `AtomicView`.
         //  https://github.com/FuelLabs/fuel-core/issues/1582
+        Ok(ReadView {
+            on_chain: self.on_chain.latest_view()?,
+            off_chain: self.off_chain.latest_view()?,
+        })
+    }
+
+    /// Creates a view that always returns the latest data of the database.
+    /// Long-living subscriptions use it to see the blocks committed after their start.
+    pub fn live_view(&self) -> ReadView {
         ReadView {
-            on_chain: self.on_chain.latest_view(),
-            off_chain: self.off_chain.latest_view(),
+            on_chain: self.live_on_chain.clone(),
+            off_chain: self.live_off_chain.clone(),
         }
     }
 }
@@ -118,6 +146,10 @@ impl DatabaseBlocks for ReadView {
     fn latest_height(&self) -> StorageResult<BlockHeight> {
         self.on_chain.latest_height()
     }
+
+    fn lowest_available_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.on_chain.lowest_available_height()
+    }
 }
 
 impl<M> StorageInspect<M> for ReadView
@@ -136,6 +168,26 @@ where
     }
 }
 
+impl<Key, M> MerkleRootStorage<Key, M> for ReadView
+where
+    M: Mappable,
+    dyn OnChainDatabase: MerkleRootStorage<Key, M, Error = StorageError>,
+{
+    fn root(&self, key: &Key) -> StorageResult<MerkleRoot> {
+        <dyn OnChainDatabase as MerkleRootStorage<Key, M>>::root(&*self.on_chain, key)
+    }
+}
+
+impl<M> MerkleProofStorage<M> for ReadView
+where
+    M: Mappable,
+    dyn OnChainDatabase: MerkleProofStorage<M>,
+{
+    fn proof(&self, key: &M::Key) -> StorageResult<SparseMerkleProof> {
+        <dyn OnChainDatabase as MerkleProofStorage<M>>::proof(&*self.on_chain, key)
+    }
+}
+
 impl DatabaseMessages for ReadView {
     fn all_messages(
         &self,
@@ -194,6 +246,13 @@ impl OffChainDatabase for ReadView {
         self.off_chain.tx_status(tx_id)
     }
 
+    fn pruned_transaction_height(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<BlockHeight>> {
+        self.off_chain.pruned_transaction_height(tx_id)
+    }
+
     fn owned_coins_ids(
         &self,
         owner: &Address,
@@ -223,7 +282,66 @@ impl OffChainDatabase for ReadView {
             .owned_transactions_ids(owner, start, direction)
     }
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        self.off_chain
+            .contract_transactions_ids(contract_id, start, direction)
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         self.off_chain.contract_salt(contract_id)
     }
+
+    fn balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u128> {
+        self.off_chain.balance(owner, asset_id)
+    }
+
+    fn balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u128)>> {
+        self.off_chain.balances(owner, direction)
+    }
+
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptPointer, ContractReceipt)>> {
+        self.off_chain
+            .contract_receipts(contract_id, receipt_type, start, direction)
+    }
+
+    fn contract_logs(
+        &self,
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        log_id: Word,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptPointer, ContractReceipt)>> {
+        self.off_chain
+            .contract_logs(contract_id, receipt_type, log_id, start, direction)
+    }
+
+    fn asset_details(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        self.off_chain.asset_details(asset_id)
+    }
+
+    fn contract_asset_ids(
+        &self,
+        contract_id: &ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<AssetId>> {
+        self.off_chain
+            .contract_asset_ids(contract_id, start_asset, direction)
+    }
 }
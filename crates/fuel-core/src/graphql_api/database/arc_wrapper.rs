@@ -47,8 +47,9 @@ where
         Ok(Arc::new(view))
     }
 
-    fn latest_view(&self) -> Self::View {
-        Arc::new(self.inner.latest_view())
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        let view = self.inner.latest_view()?;
+        Ok(Arc::new(view))
     }
 }
 
@@ -69,7 +70,8 @@ where
         Ok(Arc::new(view))
     }
 
-    fn latest_view(&self) -> Self::View {
-        Arc::new(self.inner.latest_view())
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        let view = self.inner.latest_view()?;
+        Ok(Arc::new(view))
     }
 }
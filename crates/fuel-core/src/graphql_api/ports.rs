@@ -1,3 +1,11 @@
+use crate::fuel_core_graphql_api::storage::{
+    assets::AssetDetails,
+    receipts::{
+        ContractReceipt,
+        IndexedReceiptType,
+        ReceiptPointer,
+    },
+};
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -9,12 +17,15 @@ use fuel_core_storage::{
         Coins,
         ContractsAssets,
         ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         SealedBlockConsensus,
         Transactions,
     },
     Error as StorageError,
+    MerkleProofStorage,
+    MerkleRootStorage,
     Result as StorageResult,
     StorageInspect,
 };
@@ -31,6 +42,7 @@ use fuel_core_types::{
         MerkleProof,
         Message,
     },
+    fuel_asm::Word,
     fuel_tx::{
         Salt,
         Transaction,
@@ -63,6 +75,12 @@ pub trait OffChainDatabase: Send + Sync {
 
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    /// Returns the height of the transaction if it was removed by the state pruning.
+    fn pruned_transaction_height(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<BlockHeight>>;
+
     fn owned_coins_ids(
         &self,
         owner: &Address,
@@ -84,7 +102,58 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt>;
+
+    /// Returns the total amount of the `asset_id` owned by the `owner`.
+    fn balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u128>;
+
+    /// Returns the total amounts of all assets owned by the `owner`.
+    fn balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u128)>>;
+
+    /// Returns the receipts of the `receipt_type` emitted by the `contract_id`
+    /// in the order of their appearance, starting from the `start` pointer.
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptPointer, ContractReceipt)>>;
+
+    /// Returns the logs of the `receipt_type` with the `log_id` emitted by
+    /// the `contract_id` in the order of their appearance, starting from
+    /// the `start` pointer.
+    fn contract_logs(
+        &self,
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        log_id: Word,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptPointer, ContractReceipt)>>;
+
+    /// Returns the details of the asset with the `asset_id`.
+    fn asset_details(&self, asset_id: &AssetId) -> StorageResult<AssetDetails>;
+
+    /// Returns the ids of the assets minted by the `contract_id`,
+    /// starting from the `start_asset`.
+    fn contract_asset_ids(
+        &self,
+        contract_id: &ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<AssetId>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -113,6 +182,9 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<CompressedBlock>>;
 
     fn latest_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Returns the lowest height of the block available after the state pruning.
+    fn lowest_available_height(&self) -> StorageResult<Option<BlockHeight>>;
 }
 
 /// Trait that specifies all the getters required for messages.
@@ -132,6 +204,11 @@ pub trait DatabaseMessages: StorageInspect<Messages, Error = StorageError> {
 pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
+    + MerkleRootStorage<ContractId, ContractsAssets, Error = StorageError>
+    + MerkleRootStorage<ContractId, ContractsState, Error = StorageError>
+    + MerkleProofStorage<ContractsAssets>
+    + MerkleProofStorage<ContractsState>
 {
     fn contract_balances(
         &self,
@@ -198,6 +275,16 @@ pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
 }
 
+/// The port to follow the blocks processed by the node.
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns a stream of heights of the blocks committed into the on-chain database.
+    fn imported_heights(&self) -> BoxStream<BlockHeight>;
+
+    /// Returns a stream of heights of the blocks processed by the off-chain worker,
+    /// starting from the latest processed height.
+    fn processed_heights(&self) -> BoxStream<BlockHeight>;
+}
+
 /// Trait for defining how to estimate gas price for future blocks
 #[async_trait::async_trait]
 pub trait GasPriceEstimate: Send + Sync {
@@ -208,9 +295,16 @@ pub trait GasPriceEstimate: Send + Sync {
 pub mod worker {
     use super::super::storage::blocks::FuelBlockIdsToHeights;
     use crate::fuel_core_graphql_api::storage::{
+        assets::Assets,
+        balances::Balances,
         coins::OwnedCoins,
         contracts::ContractsInfo,
         messages::OwnedMessageIds,
+        receipts::{
+            ContractLogs,
+            ContractReceipts,
+        },
+        transactions::TransactionContractsInfo,
     };
     use fuel_core_services::stream::BoxStream;
     use fuel_core_storage::{
@@ -222,6 +316,7 @@ pub mod worker {
         fuel_tx::{
             Address,
             Bytes32,
+            ContractId,
         },
         fuel_types::BlockHeight,
         services::{
@@ -237,11 +332,18 @@ pub mod worker {
 
         /// Creates a write database transaction.
         fn transaction(&mut self) -> Self::Transaction<'_>;
+
+        /// Returns the height of the latest processed block.
+        fn latest_height(&self) -> Option<BlockHeight>;
     }
 
     pub trait OffChainDatabase:
         StorageMutate<OwnedMessageIds, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<Balances, Error = StorageError>
+        + StorageMutate<ContractReceipts, Error = StorageError>
+        + StorageMutate<ContractLogs, Error = StorageError>
+        + StorageMutate<Assets, Error = StorageError>
         + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
         + StorageMutate<ContractsInfo, Error = StorageError>
     {
@@ -253,6 +355,16 @@ pub mod worker {
             tx_id: &Bytes32,
         ) -> StorageResult<Option<Bytes32>>;
 
+        /// Records the `contracts` used or created by the transaction. The transaction
+        /// can be found by any of them later.
+        fn record_tx_contracts(
+            &mut self,
+            block_height: BlockHeight,
+            tx_idx: u16,
+            tx_id: &Bytes32,
+            contracts: Vec<ContractId>,
+        ) -> StorageResult<Option<TransactionContractsInfo>>;
+
         fn update_tx_status(
             &mut self,
             id: &Bytes32,
@@ -1,11 +1,20 @@
 use crate::{
     fuel_core_graphql_api::storage::{
+        assets::Assets,
+        balances::Balances,
         blocks::FuelBlockIdsToHeights,
         coins::OwnedCoins,
         messages::OwnedMessageIds,
+        receipts::{
+            ContractLogs,
+            ContractReceipts,
+        },
         transactions::{
             OwnedTransactionIndexKey,
             OwnedTransactions,
+            TransactionContracts,
+            TransactionContractsInfo,
+            TransactionPointer,
             TransactionStatuses,
         },
     },
@@ -29,16 +38,20 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        ContractId,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
 };
 use statistic::StatisticTable;
 
+pub mod assets;
+pub mod balances;
 pub mod blocks;
 pub mod coins;
 pub mod contracts;
 pub mod messages;
+pub mod receipts;
 pub mod statistic;
 pub mod transactions;
 
@@ -74,6 +87,30 @@ pub enum Column {
     FuelBlockIdsToHeights = 7,
     /// See [`ContractsInfo`](contracts::ContractsInfo)
     ContractsInfo = 8,
+    /// The history of modifications of the state. The entry for each height contains
+    /// the changes required to revert the state to the previous height.
+    ModificationsHistory = 9,
+    /// The journal of committed changes for external consumers. The record for each commit
+    /// contains the old and new values of all keys modified by the commit.
+    ChangeJournal = 10,
+    /// See [`Balances`](balances::Balances)
+    Balances = 11,
+    /// See [`ContractReceipts`](receipts::ContractReceipts)
+    ContractReceipts = 12,
+    /// See [`ContractTransactions`](transactions::ContractTransactions)
+    TransactionsByContractBlockIdx = 13,
+    /// See [`Assets`](assets::Assets)
+    Assets = 14,
+    /// See [`ContractAssets`](assets::ContractAssets)
+    ContractAssets = 15,
+    /// See [`ContractLogs`](receipts::ContractLogs)
+    ContractLogs = 16,
+    /// See [`TransactionContracts`](transactions::TransactionContracts)
+    TransactionContracts = 17,
+    /// See [`PrunedTransactions`](transactions::PrunedTransactions)
+    PrunedTransactions = 18,
+    /// See [`PrunedBlockTransactions`](transactions::PrunedBlockTransactions)
+    PrunedBlockTransactions = 19,
 }
 
 impl Column {
@@ -101,6 +138,10 @@ where
     S: KeyValueInspect<Column = Column> + Modifiable,
     StorageTransaction<S>: StorageMutate<OwnedMessageIds, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<Balances, Error = StorageError>
+        + StorageMutate<ContractReceipts, Error = StorageError>
+        + StorageMutate<ContractLogs, Error = StorageError>
+        + StorageMutate<Assets, Error = StorageError>
         + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>,
 {
     fn record_tx_id_owner(
@@ -116,6 +157,22 @@ where
         )
     }
 
+    fn record_tx_contracts(
+        &mut self,
+        block_height: BlockHeight,
+        tx_idx: u16,
+        tx_id: &Bytes32,
+        contracts: Vec<ContractId>,
+    ) -> StorageResult<Option<TransactionContractsInfo>> {
+        self.storage::<TransactionContracts>().insert(
+            &TransactionPointer::new(block_height, tx_idx),
+            &TransactionContractsInfo {
+                tx_id: *tx_id,
+                contracts,
+            },
+        )
+    }
+
     fn update_tx_status(
         &mut self,
         id: &Bytes32,
@@ -0,0 +1,552 @@
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        migrations::{
+            Migration,
+            MigrationBatch,
+        },
+        Database,
+    },
+    fuel_core_graphql_api::storage::transactions::TransactionStatuses,
+};
+use fuel_core_storage::{
+    blueprint::{
+        indexed::{
+            Indexed,
+            SecondaryIndex,
+        },
+        plain::Plain,
+    },
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+        raw::Raw,
+        Decode,
+    },
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::FuelBlocks,
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        Bytes32,
+        ContractIdExt,
+        Receipt,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        ContractId,
+    },
+    services::txpool::TransactionStatus,
+};
+
+/// The table stores the details of known assets. The assets of contracts are
+/// registered by their first `Mint` receipt, the base asset is registered at the genesis.
+pub struct Assets;
+
+impl Mappable for Assets {
+    type Key = AssetId;
+    type OwnedKey = Self::Key;
+    type Value = AssetDetails;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for Assets {
+    type Blueprint = Indexed<Plain<Raw, Postcard>, ContractAssets>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::Assets
+    }
+}
+
+/// The details of the asset.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AssetDetails {
+    /// The contract that mints the asset. `None` for the base asset.
+    pub contract_id: Option<ContractId>,
+    /// The sub id of the asset in the contract. `None` for the base asset.
+    pub sub_id: Option<Bytes32>,
+    /// The height of the block with the first mint of the asset.
+    /// The genesis height for the base asset.
+    pub first_mint_height: BlockHeight,
+    /// The amount of the asset minted and not burned yet.
+    pub total_supply: u128,
+}
+
+/// The index of the [`Assets`] by the contracts that mint them.
+pub struct ContractAssets;
+
+impl SecondaryIndex<Assets> for ContractAssets {
+    type Key = ContractId;
+    type KeyCodec = Raw;
+    type Keys = Option<ContractId>;
+
+    fn column() -> super::Column {
+        super::Column::ContractAssets
+    }
+
+    fn keys(_: &AssetId, details: &AssetDetails) -> Option<ContractId> {
+        details.contract_id
+    }
+}
+
+/// Registers the base asset with zero supply at the genesis `block_height`.
+/// The supply is changed by the genesis coins, relayed messages and withdrawals.
+pub fn register_base_asset<S>(
+    storage: &mut S,
+    base_asset_id: &AssetId,
+    block_height: BlockHeight,
+) -> StorageResult<()>
+where
+    S: StorageMutate<Assets, Error = StorageError>,
+{
+    if storage
+        .storage_as_ref::<Assets>()
+        .contains_key(base_asset_id)?
+    {
+        return Ok(())
+    }
+
+    let details = AssetDetails {
+        first_mint_height: block_height,
+        ..Default::default()
+    };
+    storage
+        .storage_as_mut::<Assets>()
+        .insert(base_asset_id, &details)?;
+    Ok(())
+}
+
+/// Increases the supply of the base asset by the `amount`. The supply of the base asset
+/// is unknown if it wasn't registered at the genesis, so the change is skipped.
+pub fn increase_base_asset_supply<S>(
+    storage: &mut S,
+    base_asset_id: &AssetId,
+    amount: Word,
+) -> StorageResult<()>
+where
+    S: StorageMutate<Assets, Error = StorageError>,
+{
+    update_base_asset_supply(storage, base_asset_id, |supply| {
+        supply.saturating_add(u128::from(amount))
+    })
+}
+
+/// Decreases the supply of the base asset by the `amount`. The supply of the base asset
+/// is unknown if it wasn't registered at the genesis, so the change is skipped.
+pub fn decrease_base_asset_supply<S>(
+    storage: &mut S,
+    base_asset_id: &AssetId,
+    amount: Word,
+) -> StorageResult<()>
+where
+    S: StorageMutate<Assets, Error = StorageError>,
+{
+    update_base_asset_supply(storage, base_asset_id, |supply| {
+        supply.saturating_sub(u128::from(amount))
+    })
+}
+
+fn update_base_asset_supply<S, F>(
+    storage: &mut S,
+    base_asset_id: &AssetId,
+    update: F,
+) -> StorageResult<()>
+where
+    S: StorageMutate<Assets, Error = StorageError>,
+    F: FnOnce(u128) -> u128,
+{
+    let Some(mut details) = storage
+        .storage_as_ref::<Assets>()
+        .get(base_asset_id)?
+        .map(|details| details.into_owned())
+    else {
+        return Ok(())
+    };
+    details.total_supply = update(details.total_supply);
+    storage
+        .storage_as_mut::<Assets>()
+        .insert(base_asset_id, &details)?;
+    Ok(())
+}
+
+/// Applies `Mint` and `Burn` receipts of the successful transaction at
+/// the `block_height` to the supply of the assets of contracts.
+///
+/// The assets minted before the regenesis are registered by their first mint after it,
+/// so their supply doesn't include the previous mints and doesn't go below zero on burns.
+pub fn process_contract_asset_receipts<S>(
+    storage: &mut S,
+    block_height: BlockHeight,
+    receipts: &[Receipt],
+) -> StorageResult<()>
+where
+    S: StorageMutate<Assets, Error = StorageError>,
+{
+    for receipt in receipts {
+        match receipt {
+            Receipt::Mint {
+                sub_id,
+                contract_id,
+                val,
+                ..
+            } => {
+                let asset_id = contract_id.asset_id(sub_id);
+                let mut details = storage
+                    .storage_as_ref::<Assets>()
+                    .get(&asset_id)?
+                    .map(|details| details.into_owned())
+                    .unwrap_or_else(|| AssetDetails {
+                        contract_id: Some(*contract_id),
+                        sub_id: Some(*sub_id),
+                        first_mint_height: block_height,
+                        total_supply: 0,
+                    });
+                details.total_supply =
+                    details.total_supply.saturating_add(u128::from(*val));
+                storage
+                    .storage_as_mut::<Assets>()
+                    .insert(&asset_id, &details)?;
+            }
+            Receipt::Burn {
+                sub_id,
+                contract_id,
+                val,
+                ..
+            } => {
+                let asset_id = contract_id.asset_id(sub_id);
+                let Some(mut details) = storage
+                    .storage_as_ref::<Assets>()
+                    .get(&asset_id)?
+                    .map(|details| details.into_owned())
+                else {
+                    continue
+                };
+                details.total_supply =
+                    details.total_supply.saturating_sub(u128::from(*val));
+                storage
+                    .storage_as_mut::<Assets>()
+                    .insert(&asset_id, &details)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Applies `MessageOut` receipts of the successful transaction to the supply
+/// of the base asset, since withdrawn coins leave the chain.
+pub fn process_withdrawal_receipts<S>(
+    storage: &mut S,
+    base_asset_id: &AssetId,
+    receipts: &[Receipt],
+) -> StorageResult<()>
+where
+    S: StorageMutate<Assets, Error = StorageError>,
+{
+    for receipt in receipts {
+        if let Receipt::MessageOut { amount, .. } = receipt {
+            decrease_base_asset_supply(storage, base_asset_id, *amount)?;
+        }
+    }
+    Ok(())
+}
+
+/// The number of blocks processed by one batch of the [`AssetsMigration`].
+const MIGRATION_BATCH_SIZE: usize = 1_000;
+
+/// The migration of the off-chain database to the version `4` builds the [`Assets`]
+/// of contracts from the blocks of the on-chain database and the statuses of their
+/// transactions. The supply of the base asset can't be restored because the spent
+/// genesis coins are not stored, so the base asset stays unregistered.
+pub struct AssetsMigration {
+    on_chain: Database<OnChain>,
+}
+
+impl AssetsMigration {
+    pub fn new(on_chain: Database<OnChain>) -> Self {
+        Self { on_chain }
+    }
+}
+
+impl Migration<OffChain> for AssetsMigration {
+    fn name(&self) -> &'static str {
+        "assets"
+    }
+
+    fn migrate_batch(
+        &self,
+        database: &Database<OffChain>,
+        cursor: Option<Vec<u8>>,
+    ) -> StorageResult<MigrationBatch> {
+        let start = cursor
+            .as_deref()
+            .map(<Primitive<4> as Decode<BlockHeight>>::decode)
+            .transpose()
+            .map_err(StorageError::Codec)?;
+
+        let mut transaction = StorageTransaction::transaction(
+            database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        let mut blocks = self.on_chain.iter_all_by_start::<FuelBlocks>(
+            start.as_ref(),
+            Some(IterDirection::Forward),
+        );
+        for block in blocks.by_ref().take(MIGRATION_BATCH_SIZE) {
+            let (height, block) = block?;
+            for tx_id in block.transactions() {
+                let status = database
+                    .storage::<TransactionStatuses>()
+                    .get(tx_id)?
+                    .map(|status| status.into_owned());
+                if let Some(TransactionStatus::Success { receipts, .. }) = status {
+                    process_contract_asset_receipts(&mut transaction, height, &receipts)?;
+                }
+            }
+        }
+        let cursor = blocks
+            .next()
+            .transpose()?
+            .map(|(next, _)| next.to_bytes().to_vec());
+
+        Ok(MigrationBatch {
+            changes: transaction.into_changes(),
+            cursor,
+        })
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fuel_core_storage::{
+        blueprint::indexed::IteratorOverIndex,
+        transactional::WriteTransaction,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        fuel_tx::{
+            Address,
+            TxId,
+        },
+        tai64::Tai64,
+    };
+
+    fuel_core_storage::basic_storage_tests!(
+        Assets,
+        AssetId::default(),
+        AssetDetails::default()
+    );
+
+    const CONTRACT_ID: ContractId = ContractId::new([1; 32]);
+    const SUB_ID: Bytes32 = Bytes32::new([2; 32]);
+    const BASE_ASSET_ID: AssetId = AssetId::new([3; 32]);
+
+    fn contract_assets(database: &Database<OffChain>) -> Vec<(AssetId, AssetDetails)> {
+        database
+            .iter_by_index::<Assets, ContractAssets>(&CONTRACT_ID, None, None)
+            .map(|result| {
+                let asset_id = result.unwrap();
+                let details = database
+                    .storage::<Assets>()
+                    .get(&asset_id)
+                    .unwrap()
+                    .unwrap()
+                    .into_owned();
+                (asset_id, details)
+            })
+            .collect()
+    }
+
+    fn withdrawal(amount: Word) -> Receipt {
+        Receipt::message_out(
+            &TxId::zeroed(),
+            0,
+            Address::zeroed(),
+            Address::zeroed(),
+            amount,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn process_contract_asset_receipts__tracks_first_mint_and_total_supply() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        let other_sub_id = Bytes32::new([4; 32]);
+        process_contract_asset_receipts(
+            &mut transaction,
+            1.into(),
+            &[Receipt::mint(SUB_ID, CONTRACT_ID, 10, 0, 0)],
+        )
+        .unwrap();
+
+        // When
+        process_contract_asset_receipts(
+            &mut transaction,
+            2.into(),
+            &[
+                Receipt::mint(SUB_ID, CONTRACT_ID, 5, 0, 0),
+                Receipt::burn(SUB_ID, CONTRACT_ID, 7, 0, 0),
+                Receipt::burn(other_sub_id, CONTRACT_ID, 7, 0, 0),
+            ],
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        assert_eq!(
+            contract_assets(&database),
+            vec![(
+                CONTRACT_ID.asset_id(&SUB_ID),
+                AssetDetails {
+                    contract_id: Some(CONTRACT_ID),
+                    sub_id: Some(SUB_ID),
+                    first_mint_height: 1.into(),
+                    total_supply: 8,
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn process_withdrawal_receipts__decreases_supply_of_registered_base_asset() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        register_base_asset(&mut transaction, &BASE_ASSET_ID, 0.into()).unwrap();
+        increase_base_asset_supply(&mut transaction, &BASE_ASSET_ID, 100).unwrap();
+
+        // When
+        process_withdrawal_receipts(&mut transaction, &BASE_ASSET_ID, &[withdrawal(30)])
+            .unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        let details = database
+            .storage::<Assets>()
+            .get(&BASE_ASSET_ID)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(details.total_supply, 70);
+    }
+
+    #[test]
+    fn process_withdrawal_receipts__skips_unregistered_base_asset() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        increase_base_asset_supply(&mut transaction, &BASE_ASSET_ID, 100).unwrap();
+
+        // When
+        process_withdrawal_receipts(&mut transaction, &BASE_ASSET_ID, &[withdrawal(30)])
+            .unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        assert!(!database
+            .storage::<Assets>()
+            .contains_key(&BASE_ASSET_ID)
+            .unwrap());
+    }
+
+    #[test]
+    fn assets_migration__builds_assets_from_successful_transactions() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let tx_id = |height: u8| TxId::new([height; 32]);
+        for height in [3u8, 4] {
+            let mut block = CompressedBlock::default();
+            block
+                .header_mut()
+                .set_block_height(u32::from(height).into());
+            *block.transactions_mut() = vec![tx_id(height)];
+            let mut transaction = on_chain.write_transaction();
+            transaction
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&u32::from(height).into(), &block)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+        let mut transaction = off_chain.write_transaction();
+        let receipts = vec![Receipt::mint(SUB_ID, CONTRACT_ID, 10, 0, 0)];
+        // The transaction of the first block fails, so the asset is minted at the second.
+        for (height, success) in [(3u8, false), (4, true)] {
+            let tx_id = tx_id(height);
+            let block_height = u32::from(height).into();
+            let status = if success {
+                TransactionStatus::Success {
+                    block_height,
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                    receipts: receipts.clone(),
+                }
+            } else {
+                TransactionStatus::Failed {
+                    block_height,
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                    receipts: receipts.clone(),
+                }
+            };
+            transaction
+                .storage_as_mut::<TransactionStatuses>()
+                .insert(&tx_id, &status)
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+        let migration = AssetsMigration::new(on_chain);
+
+        // When
+        let mut cursor = None;
+        loop {
+            let batch = migration.migrate_batch(&off_chain, cursor).unwrap();
+            off_chain
+                .commit_changes_without_height_update(batch.changes)
+                .unwrap();
+            cursor = batch.cursor;
+            if cursor.is_none() {
+                break
+            }
+        }
+
+        // Then
+        assert_eq!(
+            contract_assets(&off_chain),
+            vec![(
+                CONTRACT_ID.asset_id(&SUB_ID),
+                AssetDetails {
+                    contract_id: Some(CONTRACT_ID),
+                    sub_id: Some(SUB_ID),
+                    first_mint_height: 4.into(),
+                    total_supply: 10,
+                }
+            )]
+        );
+    }
+}
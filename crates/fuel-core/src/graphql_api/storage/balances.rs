@@ -0,0 +1,420 @@
+use crate::database::{
+    database_description::{
+        off_chain::OffChain,
+        on_chain::OnChain,
+    },
+    migrations::{
+        Migration,
+        MigrationBatch,
+    },
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        primitive::{
+            utxo_id_to_bytes,
+            Primitive,
+        },
+        raw::Raw,
+        Decode,
+    },
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ConsensusParametersVersions,
+        Messages,
+    },
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        AssetId,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        Nonce,
+    },
+};
+use rand::{
+    distributions::{
+        Distribution,
+        Standard,
+    },
+    Rng,
+};
+use std::collections::HashMap;
+
+fuel_core_types::fuel_vm::double_key!(BalancesKey, Address, address, AssetId, asset_id);
+
+impl Distribution<BalancesKey> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BalancesKey {
+        let mut bytes = [0u8; 64];
+
+        rng.fill_bytes(bytes.as_mut());
+
+        BalancesKey::from_array(bytes)
+    }
+}
+
+/// The table that stores the total amount of spendable coins and messages
+/// of each asset per owner. Assets with zero amount are removed.
+pub struct Balances;
+
+impl Mappable for Balances {
+    type Key = BalancesKey;
+    type OwnedKey = Self::Key;
+    type Value = u128;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for Balances {
+    type Blueprint = Plain<Raw, Primitive<16>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::Balances
+    }
+}
+
+/// Increases the balance of the `owner` in the `asset_id` by the `amount`.
+pub fn increase_balance<S>(
+    storage: &mut S,
+    owner: &Address,
+    asset_id: &AssetId,
+    amount: u64,
+) -> StorageResult<()>
+where
+    S: StorageMutate<Balances, Error = StorageError>,
+{
+    let key = BalancesKey::new(owner, asset_id);
+    let balance = storage
+        .storage_as_ref::<Balances>()
+        .get(&key)?
+        .map(|balance| *balance)
+        .unwrap_or_default();
+    let balance = balance.saturating_add(u128::from(amount));
+    storage
+        .storage_as_mut::<Balances>()
+        .insert(&key, &balance)?;
+    Ok(())
+}
+
+/// Decreases the balance of the `owner` in the `asset_id` by the `amount`.
+/// If the balance is lower than the `amount`, the index is inconsistent with
+/// the on-chain state. The inconsistency is logged, and the balance becomes zero.
+pub fn decrease_balance<S>(
+    storage: &mut S,
+    owner: &Address,
+    asset_id: &AssetId,
+    amount: u64,
+) -> StorageResult<()>
+where
+    S: StorageMutate<Balances, Error = StorageError>,
+{
+    let key = BalancesKey::new(owner, asset_id);
+    let balance = storage
+        .storage_as_ref::<Balances>()
+        .get(&key)?
+        .map(|balance| *balance)
+        .unwrap_or_default();
+    let balance = balance.checked_sub(u128::from(amount)).unwrap_or_else(|| {
+        tracing::error!(
+            "The balance {balance} of the owner {owner} in the asset {asset_id} \
+            is lower than the consumed amount {amount}"
+        );
+        0
+    });
+
+    if balance == 0 {
+        storage.storage_as_mut::<Balances>().remove(&key)?;
+    } else {
+        storage
+            .storage_as_mut::<Balances>()
+            .insert(&key, &balance)?;
+    }
+    Ok(())
+}
+
+/// The number of coins or messages processed by one batch of the [`BalancesMigration`].
+const MIGRATION_BATCH_SIZE: usize = 10_000;
+
+/// The cursor prefix of the [`BalancesMigration`] over the coins.
+const COINS_CURSOR: u8 = 0;
+
+/// The cursor prefix of the [`BalancesMigration`] over the messages.
+const MESSAGES_CURSOR: u8 = 1;
+
+/// The migration of the off-chain database to the version `1` builds the [`Balances`]
+/// from the coins and messages of the on-chain database. The off-chain database
+/// is expected to be at the same height as the on-chain database.
+pub struct BalancesMigration {
+    on_chain: Database<OnChain>,
+}
+
+impl BalancesMigration {
+    pub fn new(on_chain: Database<OnChain>) -> Self {
+        Self { on_chain }
+    }
+
+    fn base_asset_id(&self) -> StorageResult<Option<AssetId>> {
+        let parameters = self
+            .on_chain
+            .iter_all::<ConsensusParametersVersions>(Some(IterDirection::Reverse))
+            .next()
+            .transpose()?;
+
+        Ok(parameters.map(|(_, parameters)| *parameters.base_asset_id()))
+    }
+}
+
+impl Migration<OffChain> for BalancesMigration {
+    fn name(&self) -> &'static str {
+        "balances"
+    }
+
+    fn migrate_batch(
+        &self,
+        database: &Database<OffChain>,
+        cursor: Option<Vec<u8>>,
+    ) -> StorageResult<MigrationBatch> {
+        // The chain without the consensus parameters has neither coins nor messages.
+        let Some(base_asset_id) = self.base_asset_id()? else {
+            return Ok(MigrationBatch::default())
+        };
+
+        let (phase, start) = match cursor.as_deref() {
+            None => (COINS_CURSOR, None),
+            Some([phase, start @ ..]) => (*phase, Some(start)),
+            Some([]) => {
+                return Err(StorageError::Other(anyhow::anyhow!(
+                    "The cursor of the balances migration is empty"
+                )))
+            }
+        };
+
+        let mut amounts = HashMap::<(Address, AssetId), u64>::new();
+        let mut add = |owner: Address, asset_id: AssetId, amount: u64| {
+            let entry = amounts.entry((owner, asset_id)).or_default();
+            *entry = entry.saturating_add(amount);
+        };
+
+        let cursor = match phase {
+            COINS_CURSOR => {
+                let start = start
+                    .map(<Primitive<{ TxId::LEN + 2 }> as Decode<UtxoId>>::decode)
+                    .transpose()
+                    .map_err(StorageError::Codec)?;
+                let mut coins = self.on_chain.iter_all_by_start::<Coins>(
+                    start.as_ref(),
+                    Some(IterDirection::Forward),
+                );
+                for coin in coins.by_ref().take(MIGRATION_BATCH_SIZE) {
+                    let (_, coin) = coin?;
+                    add(*coin.owner(), *coin.asset_id(), *coin.amount());
+                }
+                match coins.next().transpose()? {
+                    Some((next, _)) => {
+                        let mut cursor = vec![COINS_CURSOR];
+                        cursor.extend_from_slice(&utxo_id_to_bytes(&next));
+                        Some(cursor)
+                    }
+                    None => Some(vec![MESSAGES_CURSOR]),
+                }
+            }
+            MESSAGES_CURSOR => {
+                let start = start
+                    .filter(|start| !start.is_empty())
+                    .map(Nonce::try_from)
+                    .transpose()?;
+                let mut messages = self.on_chain.iter_all_by_start::<Messages>(
+                    start.as_ref(),
+                    Some(IterDirection::Forward),
+                );
+                for message in messages.by_ref().take(MIGRATION_BATCH_SIZE) {
+                    let (_, message) = message?;
+                    // Messages with data are not spendable, so they don't count.
+                    if message.data().is_empty() {
+                        add(*message.recipient(), base_asset_id, message.amount());
+                    }
+                }
+                match messages.next().transpose()? {
+                    Some((next, _)) => {
+                        let mut cursor = vec![MESSAGES_CURSOR];
+                        cursor.extend_from_slice(next.as_ref());
+                        Some(cursor)
+                    }
+                    None => None,
+                }
+            }
+            _ => {
+                return Err(StorageError::Other(anyhow::anyhow!(
+                    "Unknown phase {phase} of the balances migration"
+                )))
+            }
+        };
+
+        let mut transaction = StorageTransaction::transaction(
+            database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        for ((owner, asset_id), amount) in amounts {
+            increase_balance(&mut transaction, &owner, &asset_id, amount)?;
+        }
+
+        Ok(MigrationBatch {
+            changes: transaction.into_changes(),
+            cursor,
+        })
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fuel_core_storage::transactional::WriteTransaction;
+    use fuel_core_types::{
+        entities::{
+            coins::coin::CompressedCoin,
+            relayer::message::Message,
+        },
+        fuel_tx::ConsensusParameters,
+    };
+
+    fuel_core_storage::basic_storage_tests!(
+        Balances,
+        <Balances as Mappable>::Key::default(),
+        <Balances as Mappable>::Value::default()
+    );
+
+    const OWNER: Address = Address::new([1; 32]);
+    const ASSET_ID: AssetId = AssetId::new([2; 32]);
+    const BASE_ASSET_ID: AssetId = AssetId::new([3; 32]);
+
+    fn balances(database: &Database<OffChain>) -> Vec<(AssetId, u128)> {
+        database
+            .iter_all_filtered::<Balances, _>(Some(OWNER), None, None)
+            .map(|result| {
+                let (key, balance) = result.unwrap();
+                (*key.asset_id(), balance)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decrease_balance__removes_the_entry_when_balance_is_zero() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        increase_balance(&mut transaction, &OWNER, &ASSET_ID, 10).unwrap();
+        increase_balance(&mut transaction, &OWNER, &BASE_ASSET_ID, 5).unwrap();
+        transaction.commit().unwrap();
+        assert_eq!(
+            balances(&database),
+            vec![(ASSET_ID, 10), (BASE_ASSET_ID, 5)]
+        );
+
+        // When
+        let mut transaction = database.write_transaction();
+        decrease_balance(&mut transaction, &OWNER, &ASSET_ID, 10).unwrap();
+        decrease_balance(&mut transaction, &OWNER, &BASE_ASSET_ID, 2).unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        assert_eq!(balances(&database), vec![(BASE_ASSET_ID, 3)]);
+    }
+
+    #[test]
+    fn decrease_balance__saturates_to_zero_when_balance_is_lower_than_amount() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        increase_balance(&mut transaction, &OWNER, &ASSET_ID, 1).unwrap();
+        increase_balance(&mut transaction, &OWNER, &BASE_ASSET_ID, 5).unwrap();
+        transaction.commit().unwrap();
+
+        // When
+        let mut transaction = database.write_transaction();
+        let result = decrease_balance(&mut transaction, &OWNER, &ASSET_ID, 2);
+        transaction.commit().unwrap();
+
+        // Then
+        assert!(result.is_ok());
+        assert_eq!(balances(&database), vec![(BASE_ASSET_ID, 5)]);
+    }
+
+    #[test]
+    fn balances_migration__builds_balances_from_coins_and_messages() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut transaction = on_chain.write_transaction();
+        let mut parameters = ConsensusParameters::default();
+        parameters.set_base_asset_id(BASE_ASSET_ID);
+        transaction
+            .storage_as_mut::<ConsensusParametersVersions>()
+            .insert(&0, &parameters)
+            .unwrap();
+        for (output_index, asset_id) in (0u16..).zip([ASSET_ID, ASSET_ID, BASE_ASSET_ID])
+        {
+            let mut coin = CompressedCoin::default();
+            coin.set_owner(OWNER);
+            coin.set_asset_id(asset_id);
+            coin.set_amount(10);
+            let utxo_id = UtxoId::new(TxId::zeroed(), output_index);
+            transaction
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id, &coin)
+                .unwrap();
+        }
+        for (nonce, data) in [(1u64, vec![]), (2, vec![1])] {
+            let mut message = Message::default();
+            message.set_recipient(OWNER);
+            message.set_nonce(nonce.into());
+            message.set_amount(100);
+            message.set_data(data);
+            transaction
+                .storage_as_mut::<Messages>()
+                .insert(message.nonce(), &message)
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+        let off_chain = Database::<OffChain>::default();
+        let migration = BalancesMigration::new(on_chain);
+
+        // When
+        let mut cursor = None;
+        loop {
+            let batch = migration.migrate_batch(&off_chain, cursor).unwrap();
+            off_chain
+                .commit_changes_without_height_update(batch.changes)
+                .unwrap();
+            cursor = batch.cursor;
+            if cursor.is_none() {
+                break
+            }
+        }
+
+        // Then
+        assert_eq!(
+            balances(&off_chain),
+            vec![(ASSET_ID, 20), (BASE_ASSET_ID, 110)]
+        );
+    }
+}
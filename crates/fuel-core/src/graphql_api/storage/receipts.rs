@@ -0,0 +1,808 @@
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        migrations::{
+            Migration,
+            MigrationBatch,
+        },
+        Database,
+    },
+    fuel_core_graphql_api::storage::transactions::TransactionStatuses,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        primitive::Primitive,
+        Decode,
+        Encode,
+    },
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::FuelBlocks,
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        Receipt,
+        TxId,
+    },
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+    services::txpool::TransactionStatus,
+};
+use std::mem::size_of;
+
+/// The table indexes the receipts emitted by contracts. It allows iteration over
+/// receipts of one type emitted by the contract in the order of their appearance.
+/// Only receipts of successful transactions are indexed.
+pub struct ContractReceipts;
+
+impl Mappable for ContractReceipts {
+    type Key = ContractReceiptKey;
+    type OwnedKey = Self::Key;
+    type Value = ContractReceipt;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractReceipts {
+    type Blueprint = Plain<Manual<ContractReceiptKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractReceipts
+    }
+}
+
+/// The table indexes the `Log` and `LogData` receipts of the [`ContractReceipts`]
+/// by the `rb` register used as the id of the log. It allows iteration over logs
+/// with one id emitted by the contract in the order of their appearance.
+pub struct ContractLogs;
+
+impl Mappable for ContractLogs {
+    type Key = ContractLogKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractLogs {
+    type Blueprint = Plain<Manual<ContractLogKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractLogs
+    }
+}
+
+/// The type of receipts indexed by the [`ContractReceipts`].
+#[repr(u8)]
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, enum_iterator::Sequence,
+)]
+pub enum IndexedReceiptType {
+    Log = 0,
+    LogData = 1,
+    Transfer = 2,
+    Mint = 3,
+    Burn = 4,
+}
+
+impl IndexedReceiptType {
+    /// Returns the type of the `receipt` and the contract that emitted it,
+    /// or `None` if receipts of this type are not indexed.
+    pub fn of(receipt: &Receipt) -> Option<(ContractId, Self)> {
+        match receipt {
+            Receipt::Log { id, .. } => Some((*id, Self::Log)),
+            Receipt::LogData { id, .. } => Some((*id, Self::LogData)),
+            Receipt::Transfer { id, .. } => Some((*id, Self::Transfer)),
+            Receipt::Mint { contract_id, .. } => Some((*contract_id, Self::Mint)),
+            Receipt::Burn { contract_id, .. } => Some((*contract_id, Self::Burn)),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the log if the `receipt` is indexed by the [`ContractLogs`].
+    pub fn log_id(receipt: &Receipt) -> Option<Word> {
+        match receipt {
+            Receipt::Log { rb, .. } | Receipt::LogData { rb, .. } => Some(*rb),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if receipts of this type are indexed by the [`ContractLogs`].
+    pub fn is_log(&self) -> bool {
+        matches!(self, Self::Log | Self::LogData)
+    }
+}
+
+impl TryFrom<u8> for IndexedReceiptType {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        enum_iterator::all::<Self>()
+            .find(|receipt_type| *receipt_type as u8 == value)
+            .ok_or_else(|| anyhow::anyhow!("Unknown indexed receipt type {value}"))
+    }
+}
+
+/// The position of the receipt on the chain.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReceiptPointer {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u32,
+}
+
+impl ReceiptPointer {
+    pub fn new(block_height: BlockHeight, tx_idx: u16, receipt_idx: u32) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+
+    /// Returns the first pointer at the `block_height`.
+    pub fn first_at(block_height: BlockHeight) -> Self {
+        Self::new(block_height, 0, 0)
+    }
+
+    /// Returns the last pointer at the `block_height`.
+    pub fn last_at(block_height: BlockHeight) -> Self {
+        Self::new(block_height, u16::MAX, u32::MAX)
+    }
+}
+
+const RECEIPT_POINTER_SIZE: usize =
+    size_of::<BlockHeight>() + size_of::<u16>() + size_of::<u32>();
+const KEY_SIZE: usize = ContractId::LEN + size_of::<u8>() + RECEIPT_POINTER_SIZE;
+const LOG_KEY_PREFIX_SIZE: usize = ContractId::LEN + size_of::<u8>() + size_of::<Word>();
+const LOG_KEY_SIZE: usize = LOG_KEY_PREFIX_SIZE + RECEIPT_POINTER_SIZE;
+
+fn encode_pointer(pointer: &ReceiptPointer, bytes: &mut [u8]) {
+    let (height, indexes) = bytes.split_at_mut(size_of::<BlockHeight>());
+    height.copy_from_slice(&pointer.block_height.to_bytes());
+    let (tx_idx, receipt_idx) = indexes.split_at_mut(size_of::<u16>());
+    tx_idx.copy_from_slice(&pointer.tx_idx.to_be_bytes());
+    receipt_idx.copy_from_slice(&pointer.receipt_idx.to_be_bytes());
+}
+
+fn decode_pointer(bytes: &[u8]) -> anyhow::Result<ReceiptPointer> {
+    let (height, rest) = bytes.split_at(size_of::<BlockHeight>());
+    let (tx_idx, receipt_idx) = rest.split_at(size_of::<u16>());
+    Ok(ReceiptPointer {
+        block_height: u32::from_be_bytes(height.try_into()?).into(),
+        tx_idx: u16::from_be_bytes(tx_idx.try_into()?),
+        receipt_idx: u32::from_be_bytes(receipt_idx.try_into()?),
+    })
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractReceiptKey {
+    pub contract_id: ContractId,
+    pub receipt_type: IndexedReceiptType,
+    pub pointer: ReceiptPointer,
+}
+
+impl ContractReceiptKey {
+    pub fn new(
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        pointer: ReceiptPointer,
+    ) -> Self {
+        Self {
+            contract_id: *contract_id,
+            receipt_type,
+            pointer,
+        }
+    }
+
+    /// Returns the prefix of keys of the receipts of the `receipt_type` emitted
+    /// by the `contract_id`.
+    pub fn prefix(
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+    ) -> [u8; ContractId::LEN + 1] {
+        let mut prefix = [0u8; ContractId::LEN + 1];
+        prefix[..ContractId::LEN].copy_from_slice(contract_id.as_ref());
+        prefix[ContractId::LEN] = receipt_type as u8;
+        prefix
+    }
+}
+
+impl Encode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    type Encoder<'a> = [u8; KEY_SIZE];
+
+    fn encode(t: &ContractReceiptKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; KEY_SIZE];
+        let (prefix, pointer) = bytes.split_at_mut(ContractId::LEN + 1);
+        prefix
+            .copy_from_slice(&ContractReceiptKey::prefix(&t.contract_id, t.receipt_type));
+        encode_pointer(&t.pointer, pointer);
+        bytes
+    }
+}
+
+impl Decode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractReceiptKey> {
+        let bytes: [u8; KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (contract_id, rest) = bytes.split_at(ContractId::LEN);
+        let (receipt_type, pointer) = rest.split_at(1);
+
+        Ok(ContractReceiptKey {
+            contract_id: ContractId::try_from(contract_id)?,
+            receipt_type: IndexedReceiptType::try_from(receipt_type[0])?,
+            pointer: decode_pointer(pointer)?,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractLogKey {
+    pub contract_id: ContractId,
+    pub receipt_type: IndexedReceiptType,
+    pub log_id: Word,
+    pub pointer: ReceiptPointer,
+}
+
+impl ContractLogKey {
+    pub fn new(
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        log_id: Word,
+        pointer: ReceiptPointer,
+    ) -> Self {
+        Self {
+            contract_id: *contract_id,
+            receipt_type,
+            log_id,
+            pointer,
+        }
+    }
+
+    /// Returns the prefix of keys of the logs of the `receipt_type` with
+    /// the `log_id` emitted by the `contract_id`.
+    pub fn prefix(
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        log_id: Word,
+    ) -> [u8; LOG_KEY_PREFIX_SIZE] {
+        let mut prefix = [0u8; LOG_KEY_PREFIX_SIZE];
+        let (receipt_prefix, log_id_bytes) = prefix.split_at_mut(ContractId::LEN + 1);
+        receipt_prefix
+            .copy_from_slice(&ContractReceiptKey::prefix(contract_id, receipt_type));
+        log_id_bytes.copy_from_slice(&log_id.to_be_bytes());
+        prefix
+    }
+
+    /// Returns the key of the log in the [`ContractReceipts`].
+    pub fn receipt_key(&self) -> ContractReceiptKey {
+        ContractReceiptKey::new(&self.contract_id, self.receipt_type, self.pointer)
+    }
+}
+
+impl Encode<ContractLogKey> for Manual<ContractLogKey> {
+    type Encoder<'a> = [u8; LOG_KEY_SIZE];
+
+    fn encode(t: &ContractLogKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; LOG_KEY_SIZE];
+        let (prefix, pointer) = bytes.split_at_mut(LOG_KEY_PREFIX_SIZE);
+        prefix.copy_from_slice(&ContractLogKey::prefix(
+            &t.contract_id,
+            t.receipt_type,
+            t.log_id,
+        ));
+        encode_pointer(&t.pointer, pointer);
+        bytes
+    }
+}
+
+impl Decode<ContractLogKey> for Manual<ContractLogKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractLogKey> {
+        let bytes: [u8; LOG_KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (contract_id, rest) = bytes.split_at(ContractId::LEN);
+        let (receipt_type, rest) = rest.split_at(1);
+        let (log_id, pointer) = rest.split_at(size_of::<Word>());
+
+        Ok(ContractLogKey {
+            contract_id: ContractId::try_from(contract_id)?,
+            receipt_type: IndexedReceiptType::try_from(receipt_type[0])?,
+            log_id: Word::from_be_bytes(log_id.try_into()?),
+            pointer: decode_pointer(pointer)?,
+        })
+    }
+}
+
+/// The receipt with the id of the transaction that emitted it.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ContractReceipt {
+    pub tx_id: TxId,
+    pub receipt: Receipt,
+}
+
+/// Indexes the `receipts` of the transaction at the `block_height` and `tx_idx`
+/// by contracts that emitted them, and the logs by their ids.
+pub fn index_contract_receipts<S>(
+    storage: &mut S,
+    block_height: BlockHeight,
+    tx_idx: u16,
+    tx_id: &TxId,
+    receipts: &[Receipt],
+) -> StorageResult<()>
+where
+    S: StorageMutate<ContractReceipts, Error = StorageError>
+        + StorageMutate<ContractLogs, Error = StorageError>,
+{
+    for (receipt_idx, receipt) in (0u32..).zip(receipts) {
+        let Some((contract_id, receipt_type)) = IndexedReceiptType::of(receipt) else {
+            continue
+        };
+        let key = ContractReceiptKey::new(
+            &contract_id,
+            receipt_type,
+            ReceiptPointer::new(block_height, tx_idx, receipt_idx),
+        );
+        let value = ContractReceipt {
+            tx_id: *tx_id,
+            receipt: receipt.clone(),
+        };
+        storage
+            .storage_as_mut::<ContractReceipts>()
+            .insert(&key, &value)?;
+        if let Some(log_id) = IndexedReceiptType::log_id(receipt) {
+            let key =
+                ContractLogKey::new(&contract_id, receipt_type, log_id, key.pointer);
+            storage.storage_as_mut::<ContractLogs>().insert(&key, &())?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes the `receipts` of the transaction at the `block_height` and `tx_idx`
+/// indexed by the [`index_contract_receipts`].
+pub fn remove_contract_receipts<S>(
+    storage: &mut S,
+    block_height: BlockHeight,
+    tx_idx: u16,
+    receipts: &[Receipt],
+) -> StorageResult<()>
+where
+    S: StorageMutate<ContractReceipts, Error = StorageError>
+        + StorageMutate<ContractLogs, Error = StorageError>,
+{
+    for (receipt_idx, receipt) in (0u32..).zip(receipts) {
+        let Some((contract_id, receipt_type)) = IndexedReceiptType::of(receipt) else {
+            continue
+        };
+        let key = ContractReceiptKey::new(
+            &contract_id,
+            receipt_type,
+            ReceiptPointer::new(block_height, tx_idx, receipt_idx),
+        );
+        storage.storage_as_mut::<ContractReceipts>().remove(&key)?;
+        if let Some(log_id) = IndexedReceiptType::log_id(receipt) {
+            let key =
+                ContractLogKey::new(&contract_id, receipt_type, log_id, key.pointer);
+            storage.storage_as_mut::<ContractLogs>().remove(&key)?;
+        }
+    }
+    Ok(())
+}
+
+/// The number of blocks processed by one batch of the [`ContractReceiptsMigration`].
+const MIGRATION_BATCH_SIZE: usize = 1_000;
+
+/// The migration of the off-chain database to the version `2` builds the
+/// [`ContractReceipts`] from the blocks of the on-chain database and the statuses
+/// of their transactions. Blocks without statuses in the off-chain database are skipped.
+pub struct ContractReceiptsMigration {
+    on_chain: Database<OnChain>,
+}
+
+impl ContractReceiptsMigration {
+    pub fn new(on_chain: Database<OnChain>) -> Self {
+        Self { on_chain }
+    }
+}
+
+impl Migration<OffChain> for ContractReceiptsMigration {
+    fn name(&self) -> &'static str {
+        "contract_receipts"
+    }
+
+    fn migrate_batch(
+        &self,
+        database: &Database<OffChain>,
+        cursor: Option<Vec<u8>>,
+    ) -> StorageResult<MigrationBatch> {
+        let start = cursor
+            .as_deref()
+            .map(<Primitive<4> as Decode<BlockHeight>>::decode)
+            .transpose()
+            .map_err(StorageError::Codec)?;
+
+        let mut transaction = StorageTransaction::transaction(
+            database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        let mut blocks = self.on_chain.iter_all_by_start::<FuelBlocks>(
+            start.as_ref(),
+            Some(IterDirection::Forward),
+        );
+        for block in blocks.by_ref().take(MIGRATION_BATCH_SIZE) {
+            let (height, block) = block?;
+            for (tx_idx, tx_id) in (0u16..).zip(block.transactions()) {
+                let status = database
+                    .storage::<TransactionStatuses>()
+                    .get(tx_id)?
+                    .map(|status| status.into_owned());
+                if let Some(TransactionStatus::Success { receipts, .. }) = status {
+                    index_contract_receipts(
+                        &mut transaction,
+                        height,
+                        tx_idx,
+                        tx_id,
+                        &receipts,
+                    )?;
+                }
+            }
+        }
+        let cursor = blocks
+            .next()
+            .transpose()?
+            .map(|(next, _)| next.to_bytes().to_vec());
+
+        Ok(MigrationBatch {
+            changes: transaction.into_changes(),
+            cursor,
+        })
+    }
+}
+
+/// The number of receipts processed by one batch of the [`ContractLogsMigration`].
+const LOGS_MIGRATION_BATCH_SIZE: usize = 10_000;
+
+/// The migration of the off-chain database to the version `5` builds the
+/// [`ContractLogs`] from the logs indexed by the [`ContractReceipts`].
+#[derive(Default)]
+pub struct ContractLogsMigration;
+
+impl Migration<OffChain> for ContractLogsMigration {
+    fn name(&self) -> &'static str {
+        "contract_logs"
+    }
+
+    fn migrate_batch(
+        &self,
+        database: &Database<OffChain>,
+        cursor: Option<Vec<u8>>,
+    ) -> StorageResult<MigrationBatch> {
+        let start = cursor
+            .as_deref()
+            .map(<Manual<ContractReceiptKey> as Decode<ContractReceiptKey>>::decode)
+            .transpose()
+            .map_err(StorageError::Codec)?;
+
+        let mut transaction = StorageTransaction::transaction(
+            database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        let mut receipts = database.iter_all_by_start::<ContractReceipts>(
+            start.as_ref(),
+            Some(IterDirection::Forward),
+        );
+        for receipt in receipts.by_ref().take(LOGS_MIGRATION_BATCH_SIZE) {
+            let (key, receipt) = receipt?;
+            if let Some(log_id) = IndexedReceiptType::log_id(&receipt.receipt) {
+                let key = ContractLogKey::new(
+                    &key.contract_id,
+                    key.receipt_type,
+                    log_id,
+                    key.pointer,
+                );
+                transaction
+                    .storage_as_mut::<ContractLogs>()
+                    .insert(&key, &())?;
+            }
+        }
+        let cursor = receipts
+            .next()
+            .transpose()?
+            .map(|(next, _)| Manual::<ContractReceiptKey>::encode(&next).to_vec());
+
+        Ok(MigrationBatch {
+            changes: transaction.into_changes(),
+            cursor,
+        })
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fuel_core_storage::transactional::WriteTransaction;
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        fuel_tx::{
+            AssetId,
+            Bytes32,
+        },
+        tai64::Tai64,
+    };
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <ContractReceipts as Mappable>::Key {
+        let receipt_type = enum_iterator::all::<IndexedReceiptType>()
+            .nth(rng.gen_range(0..5))
+            .unwrap();
+        ContractReceiptKey::new(
+            &rng.gen(),
+            receipt_type,
+            ReceiptPointer::new(rng.gen::<u32>().into(), rng.gen(), rng.gen()),
+        )
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractReceipts,
+        ContractReceiptKey::new(
+            &ContractId::default(),
+            IndexedReceiptType::Log,
+            ReceiptPointer::default()
+        ),
+        ContractReceipt {
+            tx_id: TxId::default(),
+            receipt: Receipt::log(ContractId::default(), 1, 2, 3, 4, 5, 6),
+        },
+        ContractReceipt {
+            tx_id: TxId::default(),
+            receipt: Receipt::log(ContractId::default(), 1, 2, 3, 4, 5, 6),
+        },
+        generate_key
+    );
+
+    fn generate_log_key(rng: &mut impl rand::Rng) -> <ContractLogs as Mappable>::Key {
+        let receipt_type =
+            [IndexedReceiptType::Log, IndexedReceiptType::LogData][rng.gen_range(0..2)];
+        ContractLogKey::new(
+            &rng.gen(),
+            receipt_type,
+            rng.gen(),
+            ReceiptPointer::new(rng.gen::<u32>().into(), rng.gen(), rng.gen()),
+        )
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractLogs,
+        ContractLogKey::new(
+            &ContractId::default(),
+            IndexedReceiptType::Log,
+            0,
+            ReceiptPointer::default()
+        ),
+        (),
+        (),
+        generate_log_key
+    );
+
+    const CONTRACT_ID: ContractId = ContractId::new([1; 32]);
+
+    fn receipts() -> Vec<Receipt> {
+        vec![
+            Receipt::call(
+                ContractId::zeroed(),
+                CONTRACT_ID,
+                0,
+                AssetId::zeroed(),
+                0,
+                0,
+                0,
+                0,
+                0,
+            ),
+            Receipt::log(CONTRACT_ID, 1, 0, 0, 0, 0, 0),
+            Receipt::mint(Bytes32::zeroed(), CONTRACT_ID, 10, 0, 0),
+        ]
+    }
+
+    fn indexed_receipts(
+        database: &Database<OffChain>,
+    ) -> Vec<(IndexedReceiptType, ReceiptPointer)> {
+        database
+            .iter_all::<ContractReceipts>(None)
+            .map(|result| {
+                let (key, _) = result.unwrap();
+                (key.receipt_type, key.pointer)
+            })
+            .collect()
+    }
+
+    fn indexed_logs(database: &Database<OffChain>) -> Vec<(Word, ReceiptPointer)> {
+        database
+            .iter_all::<ContractLogs>(None)
+            .map(|result| {
+                let (key, _) = result.unwrap();
+                (key.log_id, key.pointer)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn index_contract_receipts__indexes_only_receipts_emitted_by_contracts() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+
+        // When
+        index_contract_receipts(
+            &mut transaction,
+            5.into(),
+            1,
+            &TxId::zeroed(),
+            &receipts(),
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        assert_eq!(
+            indexed_receipts(&database),
+            vec![
+                (IndexedReceiptType::Log, ReceiptPointer::new(5.into(), 1, 1)),
+                (
+                    IndexedReceiptType::Mint,
+                    ReceiptPointer::new(5.into(), 1, 2)
+                ),
+            ]
+        );
+        assert_eq!(
+            indexed_logs(&database),
+            vec![(0, ReceiptPointer::new(5.into(), 1, 1))]
+        );
+    }
+
+    #[test]
+    fn contract_receipts_migration__indexes_receipts_of_successful_transactions() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let successful_tx = TxId::new([1; 32]);
+        let failed_tx = TxId::new([2; 32]);
+        let unknown_tx = TxId::new([3; 32]);
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(3.into());
+        *block.transactions_mut() = vec![failed_tx, successful_tx, unknown_tx];
+        let mut transaction = on_chain.write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&3.into(), &block)
+            .unwrap();
+        transaction.commit().unwrap();
+        let mut transaction = off_chain.write_transaction();
+        for (tx_id, success) in [(successful_tx, true), (failed_tx, false)] {
+            let status = if success {
+                TransactionStatus::Success {
+                    block_height: 3.into(),
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                    receipts: receipts(),
+                }
+            } else {
+                TransactionStatus::Failed {
+                    block_height: 3.into(),
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                    receipts: receipts(),
+                }
+            };
+            transaction
+                .storage_as_mut::<TransactionStatuses>()
+                .insert(&tx_id, &status)
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+        let migration = ContractReceiptsMigration::new(on_chain);
+
+        // When
+        let mut cursor = None;
+        loop {
+            let batch = migration.migrate_batch(&off_chain, cursor).unwrap();
+            off_chain
+                .commit_changes_without_height_update(batch.changes)
+                .unwrap();
+            cursor = batch.cursor;
+            if cursor.is_none() {
+                break
+            }
+        }
+
+        // Then
+        assert_eq!(
+            indexed_receipts(&off_chain),
+            vec![
+                (IndexedReceiptType::Log, ReceiptPointer::new(3.into(), 1, 1)),
+                (
+                    IndexedReceiptType::Mint,
+                    ReceiptPointer::new(3.into(), 1, 2)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn contract_logs_migration__indexes_logs_of_contract_receipts() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        let receipts = [
+            (ReceiptPointer::new(1.into(), 0, 0), receipts()[1].clone()),
+            (
+                ReceiptPointer::new(2.into(), 0, 0),
+                Receipt::log_data(CONTRACT_ID, 0, 7, 0, 0, 0, vec![]),
+            ),
+            (ReceiptPointer::new(3.into(), 0, 0), receipts()[2].clone()),
+        ];
+        for (pointer, receipt) in receipts {
+            let (contract_id, receipt_type) = IndexedReceiptType::of(&receipt).unwrap();
+            let key = ContractReceiptKey::new(&contract_id, receipt_type, pointer);
+            let value = ContractReceipt {
+                tx_id: TxId::zeroed(),
+                receipt,
+            };
+            transaction
+                .storage_as_mut::<ContractReceipts>()
+                .insert(&key, &value)
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+
+        // When
+        let mut cursor = None;
+        loop {
+            let batch = ContractLogsMigration
+                .migrate_batch(&database, cursor)
+                .unwrap();
+            database
+                .commit_changes_without_height_update(batch.changes)
+                .unwrap();
+            cursor = batch.cursor;
+            if cursor.is_none() {
+                break
+            }
+        }
+
+        // Then
+        assert_eq!(
+            indexed_logs(&database),
+            vec![
+                (0, ReceiptPointer::new(1.into(), 0, 0)),
+                (7, ReceiptPointer::new(2.into(), 0, 0)),
+            ]
+        );
+    }
+}
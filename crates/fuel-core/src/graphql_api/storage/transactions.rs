@@ -1,3 +1,17 @@
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        migrations::{
+            Migration,
+            MigrationBatch,
+        },
+        Database,
+    },
+    graphql_api::worker_service::transaction_contracts,
+};
 use fuel_core_chain_config::{
     AddTable,
     AsTable,
@@ -6,21 +20,47 @@ use fuel_core_chain_config::{
     TableEntry,
 };
 use fuel_core_storage::{
-    blueprint::plain::Plain,
+    blueprint::{
+        indexed::{
+            Indexed,
+            SecondaryIndex,
+        },
+        plain::Plain,
+    },
     codec::{
         manual::Manual,
         postcard::Postcard,
+        primitive::Primitive,
         raw::Raw,
         Decode,
         Encode,
     },
+    iter::{
+        IterDirection,
+        IterableStore,
+        IteratorOverTable,
+    },
     structured_storage::TableWithBlueprint,
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
+    Error as StorageError,
     Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        ContractId,
+        TxPointer,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
@@ -92,6 +132,88 @@ impl AddTable<TransactionStatuses> for StateConfigBuilder {
     }
 }
 
+/// The table stores the heights of the transactions removed by the state pruning.
+/// It allows the queries of pruned transactions to fail with the pruned height
+/// instead of reporting unknown transactions. The entries are removed together
+/// with the [`PrunedBlockTransactions`] entry of their height.
+pub struct PrunedTransactions;
+
+impl Mappable for PrunedTransactions {
+    type Key = Bytes32;
+    type OwnedKey = Self::Key;
+    type Value = BlockHeight;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for PrunedTransactions {
+    type Blueprint = Plain<Raw, Primitive<4>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::PrunedTransactions
+    }
+}
+
+/// The table stores the ids of the transactions of the pruned blocks.
+/// It allows removing the expired entries of the [`PrunedTransactions`] by the height.
+pub struct PrunedBlockTransactions;
+
+impl Mappable for PrunedBlockTransactions {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = [Bytes32];
+    type OwnedValue = Vec<Bytes32>;
+}
+
+impl TableWithBlueprint for PrunedBlockTransactions {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::PrunedBlockTransactions
+    }
+}
+
+/// The table stores the transactions that use contracts as inputs or create them
+/// by the position of the transactions in the chain.
+pub struct TransactionContracts;
+
+impl Mappable for TransactionContracts {
+    type Key = TransactionPointer;
+    type OwnedKey = Self::Key;
+    type Value = TransactionContractsInfo;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TransactionContracts {
+    type Blueprint =
+        Indexed<Plain<Manual<TransactionPointer>, Postcard>, ContractTransactions>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionContracts
+    }
+}
+
+/// The index of the [`TransactionContracts`] by the contracts. It allows iteration over
+/// all transactions that use a contract as an input or create it. The transactions are
+/// sorted by block height and index within the block.
+pub struct ContractTransactions;
+
+impl SecondaryIndex<TransactionContracts> for ContractTransactions {
+    type Key = ContractId;
+    type KeyCodec = Raw;
+    type Keys = Vec<ContractId>;
+
+    fn column() -> super::Column {
+        super::Column::TransactionsByContractBlockIdx
+    }
+
+    fn keys(_: &TransactionPointer, info: &TransactionContractsInfo) -> Vec<ContractId> {
+        info.contracts.clone()
+    }
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
@@ -179,6 +301,69 @@ impl Decode<OwnedTransactionIndexKey> for Manual<OwnedTransactionIndexKey> {
     }
 }
 
+/// The position of the transaction in the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TransactionPointer {
+    pub block_height: BlockHeight,
+    pub tx_idx: TransactionIndex,
+}
+
+impl TransactionPointer {
+    pub fn new(block_height: BlockHeight, tx_idx: TransactionIndex) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+        }
+    }
+}
+
+impl From<TxPointer> for TransactionPointer {
+    fn from(tx_pointer: TxPointer) -> Self {
+        Self::new(tx_pointer.block_height(), tx_pointer.tx_index())
+    }
+}
+
+impl From<TransactionPointer> for TxPointer {
+    fn from(pointer: TransactionPointer) -> Self {
+        TxPointer::new(pointer.block_height, pointer.tx_idx)
+    }
+}
+
+const POINTER_SIZE: usize = BLOCK_HEIGHT + TX_INDEX_SIZE;
+
+impl Encode<TransactionPointer> for Manual<TransactionPointer> {
+    type Encoder<'a> = [u8; POINTER_SIZE];
+
+    fn encode(t: &TransactionPointer) -> Self::Encoder<'_> {
+        // block_height + tx_idx
+        let mut bytes = [0u8; POINTER_SIZE];
+        bytes[..BLOCK_HEIGHT].copy_from_slice(t.block_height.to_bytes().as_ref());
+        bytes[BLOCK_HEIGHT..].copy_from_slice(t.tx_idx.to_be_bytes().as_ref());
+        bytes
+    }
+}
+
+impl Decode<TransactionPointer> for Manual<TransactionPointer> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<TransactionPointer> {
+        let bytes: [u8; POINTER_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (height, tx_idx) = bytes.split_at(BLOCK_HEIGHT);
+        Ok(TransactionPointer {
+            block_height: u32::from_be_bytes(height.try_into()?).into(),
+            tx_idx: u16::from_be_bytes(tx_idx.try_into()?),
+        })
+    }
+}
+
+/// The transaction and the contracts it uses or creates.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TransactionContractsInfo {
+    pub tx_id: Bytes32,
+    /// The deduplicated and sorted contracts.
+    pub contracts: Vec<ContractId>,
+}
+
 #[derive(Clone, Debug, PartialOrd, Eq, PartialEq)]
 pub struct OwnedTransactionIndexCursor {
     pub block_height: BlockHeight,
@@ -217,9 +402,160 @@ impl From<OwnedTransactionIndexCursor> for Vec<u8> {
     }
 }
 
+/// The number of blocks processed by one batch of the [`ContractTransactionsMigration`].
+const MIGRATION_BATCH_SIZE: usize = 1_000;
+
+/// The migration of the off-chain database to the version `3` builds the
+/// [`TransactionContracts`] from the blocks and transactions of the on-chain database.
+/// Transactions removed by the pruning are skipped.
+pub struct ContractTransactionsMigration {
+    on_chain: Database<OnChain>,
+}
+
+impl ContractTransactionsMigration {
+    pub fn new(on_chain: Database<OnChain>) -> Self {
+        Self { on_chain }
+    }
+}
+
+impl Migration<OffChain> for ContractTransactionsMigration {
+    fn name(&self) -> &'static str {
+        "contract_transactions"
+    }
+
+    fn migrate_batch(
+        &self,
+        database: &Database<OffChain>,
+        cursor: Option<Vec<u8>>,
+    ) -> StorageResult<MigrationBatch> {
+        let start = cursor
+            .as_deref()
+            .map(<Primitive<4> as Decode<BlockHeight>>::decode)
+            .transpose()
+            .map_err(StorageError::Codec)?;
+
+        let mut transaction = StorageTransaction::transaction(
+            database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        let mut blocks = self.on_chain.iter_all_by_start::<FuelBlocks>(
+            start.as_ref(),
+            Some(IterDirection::Forward),
+        );
+        for block in blocks.by_ref().take(MIGRATION_BATCH_SIZE) {
+            let (height, block) = block?;
+            for (tx_idx, tx_id) in (0u16..).zip(block.transactions()) {
+                let Some(tx) = self.on_chain.storage::<Transactions>().get(tx_id)? else {
+                    continue
+                };
+                let contracts = transaction_contracts(&tx);
+                if contracts.is_empty() {
+                    continue
+                }
+                transaction
+                    .storage_as_mut::<TransactionContracts>()
+                    .insert(
+                        &TransactionPointer::new(height, tx_idx),
+                        &TransactionContractsInfo {
+                            tx_id: *tx_id,
+                            contracts,
+                        },
+                    )?;
+            }
+        }
+        let cursor = blocks
+            .next()
+            .transpose()?
+            .map(|(next, _)| next.to_bytes().to_vec());
+
+        Ok(MigrationBatch {
+            changes: transaction.into_changes(),
+            cursor,
+        })
+    }
+}
+
+/// The number of index entries processed by one batch of the
+/// [`TransactionContractsMigration`].
+const INDEX_MIGRATION_BATCH_SIZE: usize = 10_000;
+
+/// The migration of the off-chain database to the version `6` moves the transaction ids
+/// from the entries of the [`ContractTransactions`] index, written by the older versions
+/// with the transaction id as the value, into the [`TransactionContracts`].
+#[derive(Default)]
+pub struct TransactionContractsMigration;
+
+impl Migration<OffChain> for TransactionContractsMigration {
+    fn name(&self) -> &'static str {
+        "transaction_contracts"
+    }
+
+    fn migrate_batch(
+        &self,
+        database: &Database<OffChain>,
+        cursor: Option<Vec<u8>>,
+    ) -> StorageResult<MigrationBatch> {
+        let mut transaction = StorageTransaction::transaction(
+            database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        let mut entries = database.iter_store(
+            super::Column::TransactionsByContractBlockIdx,
+            None,
+            cursor.as_deref(),
+            IterDirection::Forward,
+        );
+        for entry in entries.by_ref().take(INDEX_MIGRATION_BATCH_SIZE) {
+            let (key, value) = entry?;
+            // The entries of the index have empty values since the version `6`.
+            if value.is_empty() {
+                continue
+            }
+            let (contract_id, pointer) = key.split_at(ContractId::LEN);
+            let contract_id = <Raw as Decode<ContractId>>::decode(contract_id)
+                .map_err(StorageError::Codec)?;
+            let pointer = Manual::<TransactionPointer>::decode(pointer)
+                .map_err(StorageError::Codec)?;
+            let tx_id = <Raw as Decode<Bytes32>>::decode(value.as_slice())
+                .map_err(StorageError::Codec)?;
+
+            let mut info = transaction
+                .storage::<TransactionContracts>()
+                .get(&pointer)?
+                .map(|info| info.into_owned())
+                .unwrap_or(TransactionContractsInfo {
+                    tx_id,
+                    contracts: vec![],
+                });
+            if let Err(idx) = info.contracts.binary_search(&contract_id) {
+                info.contracts.insert(idx, contract_id);
+            }
+            transaction
+                .storage_as_mut::<TransactionContracts>()
+                .insert(&pointer, &info)?;
+        }
+        let cursor = entries.next().transpose()?.map(|(next, _)| next);
+
+        Ok(MigrationBatch {
+            changes: transaction.into_changes(),
+            cursor,
+        })
+    }
+}
+
+#[allow(non_snake_case)]
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::fuel_core_graphql_api::storage::Column;
+    use fuel_core_storage::{
+        blueprint::indexed::IteratorOverIndex,
+        kv_store::KeyValueMutate,
+        transactional::WriteTransaction,
+    };
+    use std::sync::Arc;
 
     fn generate_key(rng: &mut impl rand::Rng) -> <OwnedTransactions as Mappable>::Key {
         let mut bytes = [0u8; INDEX_SIZE];
@@ -227,6 +563,41 @@ mod test {
         bytes.into()
     }
 
+    fn generate_pointer(
+        rng: &mut impl rand::Rng,
+    ) -> <TransactionContracts as Mappable>::Key {
+        TransactionPointer::new(rng.gen::<u32>().into(), rng.gen())
+    }
+
+    fn transaction_contracts_info() -> TransactionContractsInfo {
+        TransactionContractsInfo {
+            tx_id: Bytes32::new([1; 32]),
+            contracts: vec![ContractId::new([2; 32])],
+        }
+    }
+
+    fn contract_transactions(
+        database: &Database<OffChain>,
+        contract_id: &ContractId,
+    ) -> Vec<(TransactionPointer, Bytes32)> {
+        database
+            .iter_by_index::<TransactionContracts, ContractTransactions>(
+                contract_id,
+                None,
+                None,
+            )
+            .map(|pointer| {
+                let pointer = pointer.unwrap();
+                let info = database
+                    .storage::<TransactionContracts>()
+                    .get(&pointer)
+                    .unwrap()
+                    .unwrap();
+                (pointer, info.tx_id)
+            })
+            .collect()
+    }
+
     fuel_core_storage::basic_storage_tests!(
         OwnedTransactions,
         [1u8; INDEX_SIZE].into(),
@@ -242,4 +613,173 @@ mod test {
             time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        PrunedTransactions,
+        <PrunedTransactions as Mappable>::Key::default(),
+        BlockHeight::new(1)
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        PrunedBlockTransactions,
+        <PrunedBlockTransactions as Mappable>::Key::default(),
+        vec![Bytes32::zeroed()]
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        TransactionContracts,
+        TransactionPointer::new(1.into(), 1),
+        transaction_contracts_info(),
+        transaction_contracts_info(),
+        generate_pointer
+    );
+
+    #[test]
+    fn contract_transactions_migration__indexes_transactions_by_contracts() {
+        use fuel_core_types::{
+            blockchain::block::CompressedBlock,
+            fuel_tx::{
+                Input,
+                Output,
+                TransactionBuilder,
+                UniqueIdentifier,
+            },
+            fuel_types::ChainId,
+        };
+
+        // Given
+        let contract_id = ContractId::new([1; 32]);
+        let created_contract_id = ContractId::new([2; 32]);
+        let script = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id,
+            ))
+            .add_output(Output::contract(0, Default::default(), Default::default()))
+            .finalize_as_transaction();
+        let create =
+            TransactionBuilder::create(Default::default(), Default::default(), vec![])
+                .add_output(Output::contract_created(
+                    created_contract_id,
+                    Default::default(),
+                ))
+                .finalize_as_transaction();
+        let unrelated =
+            TransactionBuilder::script(vec![1], vec![]).finalize_as_transaction();
+        let chain_id = ChainId::default();
+        let txs = [unrelated, script, create];
+        let tx_ids = txs.iter().map(|tx| tx.id(&chain_id)).collect::<Vec<_>>();
+
+        let mut on_chain = Database::<OnChain>::default();
+        let mut transaction = on_chain.write_transaction();
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(5.into());
+        *block.transactions_mut() = tx_ids.clone();
+        transaction
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&5.into(), &block)
+            .unwrap();
+        for (tx_id, tx) in tx_ids.iter().zip(txs.iter()) {
+            transaction
+                .storage_as_mut::<Transactions>()
+                .insert(tx_id, tx)
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+        let off_chain = Database::<OffChain>::default();
+        let migration = ContractTransactionsMigration::new(on_chain);
+
+        // When
+        let mut cursor = None;
+        loop {
+            let batch = migration.migrate_batch(&off_chain, cursor).unwrap();
+            off_chain
+                .commit_changes_without_height_update(batch.changes)
+                .unwrap();
+            cursor = batch.cursor;
+            if cursor.is_none() {
+                break
+            }
+        }
+
+        // Then
+        assert_eq!(
+            contract_transactions(&off_chain, &contract_id),
+            vec![(TransactionPointer::new(5.into(), 1), tx_ids[1])]
+        );
+        assert_eq!(
+            contract_transactions(&off_chain, &created_contract_id),
+            vec![(TransactionPointer::new(5.into(), 2), tx_ids[2])]
+        );
+    }
+
+    #[test]
+    fn transaction_contracts_migration__converts_legacy_index_entries() {
+        // Given
+        let mut off_chain = Database::<OffChain>::default();
+        let first_contract = ContractId::new([1; 32]);
+        let second_contract = ContractId::new([2; 32]);
+        let tx_id = Bytes32::new([3; 32]);
+        let pointer = TransactionPointer::new(5.into(), 1);
+        let mut transaction = off_chain.write_transaction();
+        for contract_id in [second_contract, first_contract] {
+            let mut key = contract_id.to_vec();
+            key.extend_from_slice(&Manual::<TransactionPointer>::encode(&pointer));
+            transaction
+                .put(
+                    &key,
+                    Column::TransactionsByContractBlockIdx,
+                    Arc::new(tx_id.to_vec()),
+                )
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+
+        // When
+        let mut cursor = None;
+        loop {
+            let batch = TransactionContractsMigration
+                .migrate_batch(&off_chain, cursor)
+                .unwrap();
+            off_chain
+                .commit_changes_without_height_update(batch.changes)
+                .unwrap();
+            cursor = batch.cursor;
+            if cursor.is_none() {
+                break
+            }
+        }
+
+        // Then
+        let info = off_chain
+            .storage::<TransactionContracts>()
+            .get(&pointer)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(
+            info,
+            TransactionContractsInfo {
+                tx_id,
+                contracts: vec![first_contract, second_contract],
+            }
+        );
+        assert_eq!(
+            contract_transactions(&off_chain, &second_contract),
+            vec![(pointer, tx_id)]
+        );
+        let legacy_values = off_chain
+            .iter_store(
+                Column::TransactionsByContractBlockIdx,
+                None,
+                None,
+                IterDirection::Forward,
+            )
+            .filter(|entry| !entry.as_ref().unwrap().1.is_empty())
+            .count();
+        assert_eq!(legacy_values, 0);
+    }
 }
@@ -7,6 +7,7 @@ use async_graphql::{
         NextPrepareRequest,
     },
     Request,
+    ServerError,
     ServerResult,
 };
 use std::sync::Arc;
@@ -14,8 +15,14 @@ use std::sync::Arc;
 /// The extension that adds the `ReadView` to the request context.
 /// It guarantees that the request works with the one view of the database,
 /// and external database modification cannot affect the result.
+///
+/// Requests marked with [`LiveView`] get the view of the latest data instead.
 pub(crate) struct ViewExtension;
 
+/// The marker of the request that should see the data committed after its start,
+/// like subscriptions following new blocks.
+pub(crate) struct LiveView;
+
 impl ViewExtension {
     pub fn new() -> Self {
         Self
@@ -37,7 +44,14 @@ impl Extension for ViewExtension {
         next: NextPrepareRequest<'_>,
     ) -> ServerResult<Request> {
         let database: &ReadDatabase = ctx.data_unchecked();
-        let view = database.view();
+        // The data of the request is moved into the query data before the extensions run.
+        let view = if ctx.data_opt::<LiveView>().is_some() {
+            database.live_view()
+        } else {
+            database
+                .view()
+                .map_err(|e| ServerError::new(e.to_string(), None))?
+        };
         let request = request.data(view);
         next.run(ctx, request).await
     }
@@ -2,6 +2,15 @@ use crate::fuel_core_graphql_api::{
     ports,
     ports::worker::OffChainDatabase,
     storage::{
+        assets::{
+            increase_base_asset_supply,
+            process_contract_asset_receipts,
+            process_withdrawal_receipts,
+        },
+        balances::{
+            decrease_balance,
+            increase_balance,
+        },
         blocks::FuelBlockIdsToHeights,
         coins::{
             owner_coin_id_key,
@@ -12,12 +21,12 @@ use crate::fuel_core_graphql_api::{
             OwnedMessageIds,
             OwnedMessageKey,
         },
+        receipts::index_contract_receipts,
     },
 };
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -45,9 +54,11 @@ use fuel_core_types::{
         UniqueIdentifier,
     },
     fuel_types::{
+        Address,
+        AssetId,
         BlockHeight,
-        Bytes32,
         ChainId,
+        ContractId,
     },
     services::{
         block_importer::{
@@ -56,6 +67,7 @@ use fuel_core_types::{
         },
         executor::{
             Event,
+            TransactionExecutionResult,
             TransactionExecutionStatus,
         },
         txpool::from_executor_to_status,
@@ -69,6 +81,7 @@ use std::{
     borrow::Cow,
     ops::Deref,
 };
+use tokio::sync::watch;
 
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
@@ -77,6 +90,8 @@ pub struct Task<TxPool, D> {
     block_importer: BoxStream<SharedImportResult>,
     database: D,
     chain_id: ChainId,
+    base_asset_id: AssetId,
+    processed_height: watch::Sender<Option<BlockHeight>>,
 }
 
 impl<TxPool, D> Task<TxPool, D>
@@ -90,8 +105,14 @@ where
         // save the status for every transaction using the finalized block id
         persist_transaction_status(&result, &mut transaction)?;
 
-        // save the associated owner for each transaction in the block
-        index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
+        // index the receipts of successful transactions by the emitting contracts
+        index_receipts_for_block(&result, &mut transaction)?;
+
+        // update the supply of assets minted, burned or withdrawn by successful transactions
+        process_asset_receipts_for_block(&result, &self.base_asset_id, &mut transaction)?;
+
+        // save the associated owners and contracts for each transaction in the block
+        index_tx_owners_and_contracts_for_block(block, &mut transaction, &self.chain_id)?;
 
         // save the transaction related information
         process_transactions(block.transactions().iter(), &mut transaction)?;
@@ -108,10 +129,12 @@ where
 
         process_executor_events(
             result.events.iter().map(Cow::Borrowed),
+            &self.base_asset_id,
             &mut transaction,
         )?;
 
         transaction.commit()?;
+        self.processed_height.send_replace(Some(*height));
 
         for status in result.tx_status.iter() {
             let tx_id = status.id;
@@ -129,6 +152,7 @@ where
 /// Process the executor events and update the indexes for the messages and coins.
 pub fn process_executor_events<'a, Iter, T>(
     events: Iter,
+    base_asset_id: &AssetId,
     block_st_transaction: &mut T,
 ) -> anyhow::Result<()>
 where
@@ -144,6 +168,21 @@ where
                         &OwnedMessageKey::new(message.recipient(), message.nonce()),
                         &(),
                     )?;
+                // Relayed messages bring the base asset to the chain.
+                increase_base_asset_supply(
+                    block_st_transaction,
+                    base_asset_id,
+                    message.amount(),
+                )?;
+                // Messages with data are not spendable, so they don't affect the balance.
+                if message.data().is_empty() {
+                    increase_balance(
+                        block_st_transaction,
+                        message.recipient(),
+                        base_asset_id,
+                        message.amount(),
+                    )?;
+                }
             }
             Event::MessageConsumed(message) => {
                 block_st_transaction
@@ -152,18 +191,38 @@ where
                         message.recipient(),
                         message.nonce(),
                     ))?;
+                if message.data().is_empty() {
+                    decrease_balance(
+                        block_st_transaction,
+                        message.recipient(),
+                        base_asset_id,
+                        message.amount(),
+                    )?;
+                }
             }
             Event::CoinCreated(coin) => {
                 let coin_by_owner = owner_coin_id_key(&coin.owner, &coin.utxo_id);
                 block_st_transaction
                     .storage_as_mut::<OwnedCoins>()
                     .insert(&coin_by_owner, &())?;
+                increase_balance(
+                    block_st_transaction,
+                    &coin.owner,
+                    &coin.asset_id,
+                    coin.amount,
+                )?;
             }
             Event::CoinConsumed(coin) => {
                 let key = owner_coin_id_key(&coin.owner, &coin.utxo_id);
                 block_st_transaction
                     .storage_as_mut::<OwnedCoins>()
                     .remove(&key)?;
+                decrease_balance(
+                    block_st_transaction,
+                    &coin.owner,
+                    &coin.asset_id,
+                    coin.amount,
+                )?;
             }
         }
     }
@@ -171,7 +230,8 @@ where
 }
 
 /// Associate all transactions within a block to their respective UTXO owners
-fn index_tx_owners_for_block<T>(
+/// and to the contracts they use or create
+fn index_tx_owners_and_contracts_for_block<T>(
     block: &Block,
     block_st_transaction: &mut T,
     chain_id: &ChainId,
@@ -181,53 +241,75 @@ where
 {
     for (tx_idx, tx) in block.transactions().iter().enumerate() {
         let block_height = *block.header().height();
-        let inputs;
-        let outputs;
         let tx_idx = u16::try_from(tx_idx).map_err(|e| {
             anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
         })?;
         let tx_id = tx.id(chain_id);
-        match tx {
-            Transaction::Script(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Create(tx) => {
-                inputs = tx.inputs().as_slice();
-                outputs = tx.outputs().as_slice();
-            }
-            Transaction::Mint(_) => continue,
+
+        // Index the tx id by owner for all of the inputs and outputs
+        for owner in transaction_owners(tx) {
+            block_st_transaction.record_tx_id_owner(
+                &owner,
+                block_height,
+                tx_idx,
+                &tx_id,
+            )?;
+        }
+
+        let contracts = transaction_contracts(tx);
+        if !contracts.is_empty() {
+            block_st_transaction.record_tx_contracts(
+                block_height,
+                tx_idx,
+                &tx_id,
+                contracts,
+            )?;
         }
-        persist_owners_index(
-            block_height,
-            inputs,
-            outputs,
-            &tx_id,
-            tx_idx,
-            block_st_transaction,
-        )?;
     }
     Ok(())
 }
 
-/// Index the tx id by owner for all of the inputs and outputs
-fn persist_owners_index<T>(
-    block_height: BlockHeight,
-    inputs: &[Input],
-    outputs: &[Output],
-    tx_id: &Bytes32,
-    tx_idx: u16,
-    db: &mut T,
-) -> StorageResult<()>
-where
-    T: OffChainDatabase,
-{
+/// Returns the inputs and outputs of the transaction. The `Mint` transaction has none.
+fn inputs_and_outputs(tx: &Transaction) -> (&[Input], &[Output]) {
+    match tx {
+        Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Mint(_) => (&[], &[]),
+    }
+}
+
+/// Returns the deduplicated contracts used as inputs or created by the transaction.
+pub(crate) fn transaction_contracts(tx: &Transaction) -> Vec<ContractId> {
+    let (inputs, outputs) = inputs_and_outputs(tx);
+
+    let mut contracts = vec![];
+    for input in inputs {
+        if let Input::Contract(contract) = input {
+            contracts.push(contract.contract_id);
+        }
+    }
+
+    for output in outputs {
+        if let Output::ContractCreated { contract_id, .. } = output {
+            contracts.push(*contract_id);
+        }
+    }
+
+    contracts.sort();
+    contracts.dedup();
+    contracts
+}
+
+/// Returns the deduplicated owners of the coins from the inputs and outputs of the transaction.
+pub(crate) fn transaction_owners(tx: &Transaction) -> Vec<Address> {
+    let (inputs, outputs) = inputs_and_outputs(tx);
+
     let mut owners = vec![];
     for input in inputs {
         if let Input::CoinSigned(CoinSigned { owner, .. })
         | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
         {
-            owners.push(owner);
+            owners.push(*owner);
         }
     }
 
@@ -236,7 +318,7 @@ where
             Output::Coin { to, .. }
             | Output::Change { to, .. }
             | Output::Variable { to, .. } => {
-                owners.push(to);
+                owners.push(*to);
             }
             Output::Contract(_) | Output::ContractCreated { .. } => {}
         }
@@ -245,12 +327,7 @@ where
     // dedupe owners from inputs and outputs prior to indexing
     owners.sort();
     owners.dedup();
-
-    for owner in owners {
-        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
-    }
-
-    Ok(())
+    owners
 }
 
 fn persist_transaction_status<T>(
@@ -275,6 +352,45 @@ where
     Ok(())
 }
 
+fn index_receipts_for_block<T>(
+    import_result: &ImportResult,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabase,
+{
+    let block_height = *import_result.sealed_block.entity.header().height();
+    for (tx_idx, TransactionExecutionStatus { id, result }) in
+        import_result.tx_status.iter().enumerate()
+    {
+        if let TransactionExecutionResult::Success { receipts, .. } = result {
+            let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+            })?;
+            index_contract_receipts(db, block_height, tx_idx, id, receipts)?;
+        }
+    }
+    Ok(())
+}
+
+fn process_asset_receipts_for_block<T>(
+    import_result: &ImportResult,
+    base_asset_id: &AssetId,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabase,
+{
+    let block_height = *import_result.sealed_block.entity.header().height();
+    for TransactionExecutionStatus { result, .. } in import_result.tx_status.iter() {
+        if let TransactionExecutionResult::Success { receipts, .. } = result {
+            process_contract_asset_receipts(db, block_height, receipts)?;
+            process_withdrawal_receipts(db, base_asset_id, receipts)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn process_transactions<'a, I, T>(transactions: I, db: &mut T) -> StorageResult<()>
 where
     I: Iterator<Item = &'a Transaction>,
@@ -310,12 +426,12 @@ where
     D: ports::worker::Transactional,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
+    type SharedData = watch::Receiver<Option<BlockHeight>>;
     type Task = Self;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        self.processed_height.subscribe()
     }
 
     async fn into_task(
@@ -387,6 +503,7 @@ pub fn new_service<TxPool, I, D>(
     block_importer: I,
     database: D,
     chain_id: ChainId,
+    base_asset_id: AssetId,
 ) -> ServiceRunner<Task<TxPool, D>>
 where
     TxPool: ports::worker::TxPool,
@@ -394,10 +511,13 @@ where
     D: ports::worker::Transactional,
 {
     let block_importer = block_importer.block_events();
+    let (processed_height, _) = watch::channel(database.latest_height());
     ServiceRunner::new(Task {
         tx_pool,
         block_importer,
         database,
         chain_id,
+        base_asset_id,
+        processed_height,
     })
 }
@@ -1,18 +1,22 @@
+mod asset;
 mod balance;
 mod block;
 mod chain;
 mod coin;
 mod contract;
 mod message;
+mod receipt;
 mod subscriptions;
 mod tx;
 
 // TODO: Remove reexporting of everything
+pub use asset::*;
 pub use balance::*;
 pub use block::*;
 pub use chain::*;
 pub use coin::*;
 pub use contract::*;
 pub use message::*;
+pub use receipt::*;
 pub(crate) use subscriptions::*;
 pub use tx::*;
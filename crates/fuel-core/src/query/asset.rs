@@ -0,0 +1,50 @@
+use crate::fuel_core_graphql_api::{
+    ports::OffChainDatabase,
+    storage::assets::AssetDetails,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+    },
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_types::{
+    AssetId,
+    ContractId,
+};
+
+pub trait AssetQueryData: Send + Sync {
+    fn asset(&self, asset_id: &AssetId) -> StorageResult<AssetDetails>;
+
+    /// Returns the assets minted by the `contract_id` sorted by their ids,
+    /// starting from the `start_asset`.
+    fn contract_assets(
+        &self,
+        contract_id: &ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(AssetId, AssetDetails)>>;
+}
+
+impl<D: OffChainDatabase + ?Sized> AssetQueryData for D {
+    fn asset(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        self.asset_details(asset_id)
+    }
+
+    fn contract_assets(
+        &self,
+        contract_id: &ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(AssetId, AssetDetails)>> {
+        self.contract_asset_ids(contract_id, start_asset, direction)
+            .map(move |asset_id| {
+                let asset_id = asset_id?;
+                let details = self.asset_details(&asset_id)?;
+                Ok((asset_id, details))
+            })
+            .into_boxed()
+    }
+}
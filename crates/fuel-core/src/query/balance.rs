@@ -1,8 +1,6 @@
-use crate::fuel_core_graphql_api::database::ReadView;
-use asset_query::{
-    AssetQuery,
-    AssetSpendTarget,
-    AssetsQuery,
+use crate::fuel_core_graphql_api::{
+    database::ReadView,
+    ports::OffChainDatabase,
 };
 use fuel_core_storage::{
     iter::{
@@ -19,27 +17,17 @@ use fuel_core_types::{
     },
     services::graphql_api::AddressBalance,
 };
-use itertools::Itertools;
-use std::{
-    cmp::Ordering,
-    collections::HashMap,
-};
 
 pub mod asset_query;
 
 pub trait BalanceQueryData: Send + Sync {
-    fn balance(
-        &self,
-        owner: Address,
-        asset_id: AssetId,
-        base_asset_id: AssetId,
-    ) -> StorageResult<AddressBalance>;
+    fn balance(&self, owner: Address, asset_id: AssetId)
+        -> StorageResult<AddressBalance>;
 
     fn balances(
         &self,
         owner: Address,
         direction: IterDirection,
-        base_asset_id: AssetId,
     ) -> BoxedIter<StorageResult<AddressBalance>>;
 }
 
@@ -48,29 +36,12 @@ impl BalanceQueryData for ReadView {
         &self,
         owner: Address,
         asset_id: AssetId,
-        base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
-        let amount = AssetQuery::new(
-            &owner,
-            &AssetSpendTarget::new(asset_id, u64::MAX, usize::MAX),
-            &base_asset_id,
-            None,
-            self,
-        )
-        .coins()
-        .map(|res| res.map(|coins| coins.amount()))
-        .try_fold(0u64, |mut balance, res| -> StorageResult<_> {
-            let amount = res?;
-
-            // Increase the balance
-            balance = balance.saturating_add(amount);
-
-            Ok(balance)
-        })?;
+        let amount = OffChainDatabase::balance(self, &owner, &asset_id)?;
 
         Ok(AddressBalance {
             owner,
-            amount,
+            amount: saturate_amount(amount),
             asset_id,
         })
     }
@@ -79,50 +50,20 @@ impl BalanceQueryData for ReadView {
         &self,
         owner: Address,
         direction: IterDirection,
-        base_asset_id: AssetId,
     ) -> BoxedIter<StorageResult<AddressBalance>> {
-        let mut amounts_per_asset = HashMap::new();
-        let mut errors = vec![];
-
-        for coin in AssetsQuery::new(&owner, None, None, self, &base_asset_id).coins() {
-            match coin {
-                Ok(coin) => {
-                    let amount: &mut u64 = amounts_per_asset
-                        .entry(*coin.asset_id(&base_asset_id))
-                        .or_default();
-                    *amount = amount.saturating_add(coin.amount());
-                }
-                Err(err) => {
-                    errors.push(err);
-                }
-            }
-        }
-
-        let mut balances = amounts_per_asset
-            .into_iter()
-            .map(|(asset_id, amount)| AddressBalance {
-                owner,
-                amount,
-                asset_id,
+        OffChainDatabase::balances(self, &owner, direction)
+            .map(move |result| {
+                result.map(|(asset_id, amount)| AddressBalance {
+                    owner,
+                    amount: saturate_amount(amount),
+                    asset_id,
+                })
             })
-            .collect_vec();
-
-        balances.sort_by(|l, r| {
-            if l.asset_id < r.asset_id {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
-        });
-
-        if direction == IterDirection::Reverse {
-            balances.reverse();
-        }
-
-        balances
-            .into_iter()
-            .map(Ok)
-            .chain(errors.into_iter().map(Err))
             .into_boxed()
     }
 }
+
+/// The GraphQL API exposes balances as `u64`, so larger totals are saturated.
+fn saturate_amount(amount: u128) -> u64 {
+    u64::try_from(amount).unwrap_or(u64::MAX)
+}
@@ -1,4 +1,10 @@
-use crate::fuel_core_graphql_api::ports::OnChainDatabase;
+use crate::{
+    database::{
+        DatabaseHeight,
+        Error as DatabaseError,
+    },
+    fuel_core_graphql_api::ports::OnChainDatabase,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -19,6 +25,11 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
 };
+use futures::{
+    stream::BoxStream,
+    Stream,
+    StreamExt,
+};
 
 pub trait SimpleBlockData: Send + Sync {
     fn block(&self, id: &BlockHeight) -> StorageResult<CompressedBlock>;
@@ -26,6 +37,7 @@ pub trait SimpleBlockData: Send + Sync {
 
 impl<D: OnChainDatabase + ?Sized> SimpleBlockData for D {
     fn block(&self, id: &BlockHeight) -> StorageResult<CompressedBlock> {
+        ensure_height_is_not_pruned(self, id)?;
         let block = self
             .storage::<FuelBlocks>()
             .get(id)?
@@ -36,6 +48,23 @@ impl<D: OnChainDatabase + ?Sized> SimpleBlockData for D {
     }
 }
 
+/// Returns an error if the data at the `height` was removed by the state pruning.
+pub(crate) fn ensure_height_is_not_pruned<D: OnChainDatabase + ?Sized>(
+    database: &D,
+    height: &BlockHeight,
+) -> StorageResult<()> {
+    match database.lowest_available_height()? {
+        Some(lowest_available_height) if *height < lowest_available_height => {
+            Err(DatabaseError::HeightIsPruned {
+                requested_height: height.as_u64(),
+                lowest_available_height: lowest_available_height.as_u64(),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
 pub trait BlockQueryData: Send + Sync + SimpleBlockData {
     fn latest_block_height(&self) -> StorageResult<BlockHeight>;
 
@@ -68,9 +97,166 @@ impl<D: OnChainDatabase + ?Sized> BlockQueryData for D {
     }
 
     fn consensus(&self, id: &BlockHeight) -> StorageResult<Consensus> {
+        ensure_height_is_not_pruned(self, id)?;
         self.storage::<SealedBlockConsensus>()
             .get(id)
             .map(|c| c.map(|c| c.into_owned()))?
             .ok_or(not_found!(SealedBlockConsensus))
     }
 }
+
+/// Returns a stream of blocks in the ascending order of heights starting from
+/// the `from_height`, or from the block after the first item of `heights` if it is not set.
+///
+/// The first item of `heights` is the latest available height at the moment
+/// of the subscription, the next items notify about new available heights.
+/// All blocks up to the notified height are fetched with the `block`, so
+/// the stream doesn't skip blocks even if some notifications are lost.
+/// The `block` may return any data of the block instead of the block itself.
+/// The stream ends after the first error.
+pub fn block_stream<'a, F, T>(
+    block: F,
+    from_height: Option<BlockHeight>,
+    heights: BoxStream<'a, BlockHeight>,
+) -> impl Stream<Item = StorageResult<T>> + 'a
+where
+    F: Fn(&BlockHeight) -> StorageResult<T> + Send + 'a,
+    T: 'a,
+{
+    let state = BlockStreamState {
+        block,
+        heights,
+        next: from_height,
+        available: None,
+    };
+    futures::stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+        loop {
+            match (state.next, state.available) {
+                (Some(next), Some(available)) if next <= available => {
+                    let result = (state.block)(&next);
+                    let next = next.succ();
+                    let state = match (&result, next) {
+                        (Ok(_), Some(next)) => Some(BlockStreamState {
+                            next: Some(next),
+                            ..state
+                        }),
+                        _ => None,
+                    };
+                    return Some((result, state))
+                }
+                _ => {
+                    let height = state.heights.next().await?;
+                    if state.next.is_none() {
+                        state.next = Some(height.succ()?);
+                    }
+                    state.available = Some(
+                        state
+                            .available
+                            .map_or(height, |available| available.max(height)),
+                    );
+                }
+            }
+        }
+    })
+}
+
+struct BlockStreamState<'a, F> {
+    block: F,
+    heights: BoxStream<'a, BlockHeight>,
+    next: Option<BlockHeight>,
+    available: Option<BlockHeight>,
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::Error as StorageError;
+
+    fn block(height: &BlockHeight) -> StorageResult<CompressedBlock> {
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(*height);
+        Ok(block)
+    }
+
+    async fn heights_of<S>(stream: S) -> Vec<StorageResult<u32>>
+    where
+        S: Stream<Item = StorageResult<CompressedBlock>>,
+    {
+        stream
+            .map(|result| result.map(|block| u32::from(*block.header().height())))
+            .collect()
+            .await
+    }
+
+    fn notifications(heights: Vec<u32>) -> BoxStream<'static, BlockHeight> {
+        futures::stream::iter(heights.into_iter().map(BlockHeight::from)).boxed()
+    }
+
+    #[tokio::test]
+    async fn block_stream__starts_after_the_latest_height_by_default() {
+        // Given
+        let heights = notifications(vec![3, 4, 5]);
+
+        // When
+        let blocks = heights_of(block_stream(block, None, heights)).await;
+
+        // Then
+        assert_eq!(
+            blocks.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            vec![4, 5]
+        );
+    }
+
+    #[tokio::test]
+    async fn block_stream__backfills_from_the_height_and_fills_gaps() {
+        // Given
+        let heights = notifications(vec![3, 4, 7]);
+
+        // When
+        let blocks = heights_of(block_stream(block, Some(1.into()), heights)).await;
+
+        // Then
+        assert_eq!(
+            blocks.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[tokio::test]
+    async fn block_stream__waits_for_the_from_height() {
+        // Given
+        let heights = notifications(vec![3, 4, 5]);
+
+        // When
+        let blocks = heights_of(block_stream(block, Some(5.into()), heights)).await;
+
+        // Then
+        assert_eq!(
+            blocks.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            vec![5]
+        );
+    }
+
+    #[tokio::test]
+    async fn block_stream__ends_after_error() {
+        // Given
+        let heights = notifications(vec![5]);
+        let block = |height: &BlockHeight| {
+            if *height == 2.into() {
+                Err(StorageError::Other(anyhow::anyhow!("Pruned")))
+            } else {
+                block(height)
+            }
+        };
+
+        // When
+        let blocks = heights_of(block_stream(block, Some(1.into()), heights)).await;
+
+        // Then
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(*blocks[0].as_ref().unwrap(), 1);
+        assert!(blocks[1].is_err());
+    }
+}
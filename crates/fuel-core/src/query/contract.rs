@@ -11,17 +11,27 @@ use fuel_core_storage::{
     tables::{
         ContractsAssets,
         ContractsRawCode,
+        ContractsState,
     },
+    ContractsAssetKey,
+    ContractsStateKey,
+    MerkleProofStorage,
+    MerkleRootStorage,
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::{
     fuel_types::{
         AssetId,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractBalanceProof,
+        ContractStorageProof,
+    },
 };
 
 pub trait ContractQueryData: Send + Sync {
@@ -43,6 +53,18 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_proof(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<ContractStorageProof>;
+
+    fn contract_balance_proof(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> StorageResult<ContractBalanceProof>;
 }
 
 impl<D: OnChainDatabase + OffChainDatabase + ?Sized> ContractQueryData for D {
@@ -95,4 +117,52 @@ impl<D: OnChainDatabase + OffChainDatabase + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn contract_storage_proof(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<ContractStorageProof> {
+        self.contract_id(contract_id)?;
+        let storage_key = ContractsStateKey::new(&contract_id, &key);
+        let value = self
+            .storage::<ContractsState>()
+            .get(&storage_key)?
+            .map(|value| value.into_owned().0);
+        let root =
+            MerkleRootStorage::<ContractId, ContractsState>::root(self, &contract_id)?;
+        let proof = MerkleProofStorage::<ContractsState>::proof(self, &storage_key)?;
+
+        Ok(ContractStorageProof {
+            contract_id,
+            key,
+            value,
+            root: root.into(),
+            proof,
+        })
+    }
+
+    fn contract_balance_proof(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> StorageResult<ContractBalanceProof> {
+        self.contract_id(contract_id)?;
+        let storage_key = ContractsAssetKey::new(&contract_id, &asset_id);
+        let value = self
+            .storage::<ContractsAssets>()
+            .get(&storage_key)?
+            .map(|value| value.into_owned());
+        let root =
+            MerkleRootStorage::<ContractId, ContractsAssets>::root(self, &contract_id)?;
+        let proof = MerkleProofStorage::<ContractsAssets>::proof(self, &storage_key)?;
+
+        Ok(ContractBalanceProof {
+            contract_id,
+            key: asset_id,
+            value,
+            root: root.into(),
+            proof,
+        })
+    }
 }
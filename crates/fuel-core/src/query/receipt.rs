@@ -0,0 +1,325 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+        storage::receipts::{
+            ContractReceipt,
+            IndexedReceiptType,
+            ReceiptPointer,
+        },
+    },
+    query::ensure_height_is_not_pruned,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+    },
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+};
+use itertools::Itertools;
+
+/// The filter of the receipts emitted by the contract.
+#[derive(Clone, Debug)]
+pub struct ReceiptFilter {
+    /// The contract that emitted the receipts.
+    pub contract_id: ContractId,
+    /// The type of the receipts. Receipts of all indexed types if `None`.
+    pub receipt_type: Option<IndexedReceiptType>,
+    /// The `rb` of `Log` and `LogData` receipts. Other receipts are skipped if it is set.
+    pub log_id: Option<Word>,
+    /// The lowest height of the receipts, inclusive.
+    pub from_height: Option<BlockHeight>,
+    /// The highest height of the receipts, inclusive.
+    pub to_height: Option<BlockHeight>,
+}
+
+impl ReceiptFilter {
+    fn contains_height(&self, height: &BlockHeight) -> bool {
+        let from = self.from_height.unwrap_or_default();
+        let to = self.to_height.unwrap_or_else(|| u32::MAX.into());
+        (from..=to).contains(height)
+    }
+}
+
+pub trait ReceiptQueryData: Send + Sync {
+    /// Returns the receipts matching the `filter` in the order of their appearance,
+    /// starting from the `start` pointer.
+    fn filtered_receipts(
+        &self,
+        filter: ReceiptFilter,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ReceiptPointer, ContractReceipt)>>;
+}
+
+impl ReceiptQueryData for ReadView {
+    fn filtered_receipts(
+        &self,
+        filter: ReceiptFilter,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ReceiptPointer, ContractReceipt)>> {
+        // The receipts below the lowest available height are removed by the state pruning,
+        // so the queries of them fail instead of returning incomplete results.
+        let requested_heights = [
+            filter.from_height,
+            filter.to_height,
+            start.map(|start| start.block_height),
+        ];
+        for height in requested_heights.iter().flatten() {
+            if let Err(error) = ensure_height_is_not_pruned(self, height) {
+                return core::iter::once(Err(error)).into_boxed()
+            }
+        }
+
+        // Skips receipts outside of the range of heights without iteration over them.
+        let range_start = match direction {
+            IterDirection::Forward => filter.from_height.map(ReceiptPointer::first_at),
+            IterDirection::Reverse => filter.to_height.map(ReceiptPointer::last_at),
+        };
+        let start = match (start, range_start) {
+            (Some(start), Some(range_start)) => match direction {
+                IterDirection::Forward => Some(start.max(range_start)),
+                IterDirection::Reverse => Some(start.min(range_start)),
+            },
+            (start, range_start) => start.or(range_start),
+        };
+
+        let receipt_types = match filter.receipt_type {
+            Some(receipt_type) => vec![receipt_type],
+            None => enum_iterator::all::<IndexedReceiptType>().collect(),
+        };
+        let receipts = receipt_types
+            .into_iter()
+            .filter_map(|receipt_type| match filter.log_id {
+                None => Some(OffChainDatabase::contract_receipts(
+                    self,
+                    &filter.contract_id,
+                    receipt_type,
+                    start,
+                    direction,
+                )),
+                // Only logs have ids, they are indexed separately by them.
+                Some(log_id) if receipt_type.is_log() => {
+                    Some(OffChainDatabase::contract_logs(
+                        self,
+                        &filter.contract_id,
+                        receipt_type,
+                        log_id,
+                        start,
+                        direction,
+                    ))
+                }
+                Some(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        // The receipts of each type are sorted, so merging keeps the order.
+        receipts
+            .into_iter()
+            .kmerge_by(move |a, b| match (a, b) {
+                (Ok((a, _)), Ok((b, _))) => match direction {
+                    IterDirection::Forward => a < b,
+                    IterDirection::Reverse => a > b,
+                },
+                (Err(_), _) => true,
+                (_, Err(_)) => false,
+            })
+            .take_while(move |result| match result {
+                Ok((pointer, _)) => filter.contains_height(&pointer.block_height),
+                Err(_) => true,
+            })
+            .into_boxed()
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::{
+            database_description::{
+                off_chain::OffChain,
+                on_chain::OnChain,
+            },
+            state_pruning::LowestAvailableHeight,
+            Database,
+        },
+        fuel_core_graphql_api::{
+            database::ReadDatabase,
+            storage::receipts::index_contract_receipts,
+        },
+    };
+    use fuel_core_storage::{
+        transactional::WriteTransaction,
+        Error as StorageError,
+        StorageAsMut,
+    };
+    use fuel_core_types::fuel_tx::{
+        Bytes32,
+        Receipt,
+        TxId,
+    };
+
+    const CONTRACT_ID: ContractId = ContractId::new([1; 32]);
+
+    fn filter() -> ReceiptFilter {
+        ReceiptFilter {
+            contract_id: CONTRACT_ID,
+            receipt_type: None,
+            log_id: None,
+            from_height: None,
+            to_height: None,
+        }
+    }
+
+    fn view_with_receipts() -> ReadView {
+        let mut off_chain = Database::<OffChain>::default();
+        let mut transaction = off_chain.write_transaction();
+        for height in 1..=3u32 {
+            let receipts = vec![
+                Receipt::log(CONTRACT_ID, 0, height.into(), 0, 0, 0, 0),
+                Receipt::mint(Bytes32::zeroed(), CONTRACT_ID, 10, 0, 0),
+                Receipt::log(CONTRACT_ID, 0, 0, 0, 0, 0, 0),
+            ];
+            index_contract_receipts(
+                &mut transaction,
+                height.into(),
+                0,
+                &TxId::zeroed(),
+                &receipts,
+            )
+            .unwrap();
+        }
+        transaction.commit().unwrap();
+
+        ReadDatabase::new(Database::<OnChain>::default(), off_chain)
+            .view()
+            .unwrap()
+    }
+
+    fn pointers(
+        view: &ReadView,
+        filter: ReceiptFilter,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> Vec<(u32, u32)> {
+        view.filtered_receipts(filter, start, direction)
+            .map(|result| {
+                let (pointer, _) = result.unwrap();
+                (u32::from(pointer.block_height), pointer.receipt_idx)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn filtered_receipts__merges_receipts_of_all_types_in_order() {
+        // Given
+        let view = view_with_receipts();
+
+        // When
+        let forward = pointers(&view, filter(), None, IterDirection::Forward);
+        let reverse = pointers(&view, filter(), None, IterDirection::Reverse);
+
+        // Then
+        let expected = (1..=3)
+            .flat_map(|height| [(height, 0), (height, 1), (height, 2)])
+            .collect::<Vec<_>>();
+        assert_eq!(forward, expected);
+        assert_eq!(reverse, expected.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn filtered_receipts__returns_only_receipts_within_heights() {
+        // Given
+        let view = view_with_receipts();
+        let filter = ReceiptFilter {
+            receipt_type: Some(IndexedReceiptType::Mint),
+            from_height: Some(2.into()),
+            to_height: Some(2.into()),
+            ..filter()
+        };
+
+        // When
+        let forward = pointers(&view, filter.clone(), None, IterDirection::Forward);
+        let reverse = pointers(&view, filter, None, IterDirection::Reverse);
+
+        // Then
+        assert_eq!(forward, vec![(2, 1)]);
+        assert_eq!(reverse, vec![(2, 1)]);
+    }
+
+    #[test]
+    fn filtered_receipts__returns_only_logs_with_log_id() {
+        // Given
+        let view = view_with_receipts();
+        let filter = ReceiptFilter {
+            log_id: Some(2),
+            ..filter()
+        };
+
+        // When
+        let receipts = pointers(&view, filter, None, IterDirection::Forward);
+
+        // Then
+        assert_eq!(receipts, vec![(2, 0)]);
+    }
+
+    #[test]
+    fn filtered_receipts__starts_from_the_start_pointer() {
+        // Given
+        let view = view_with_receipts();
+        let start = ReceiptPointer::new(2.into(), 0, 2);
+
+        // When
+        let receipts = pointers(&view, filter(), Some(start), IterDirection::Forward);
+
+        // Then
+        assert_eq!(receipts, vec![(2, 2), (3, 0), (3, 1), (3, 2)]);
+    }
+
+    #[test]
+    fn filtered_receipts__fails_below_lowest_available_height() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut transaction = on_chain.write_transaction();
+        transaction
+            .storage_as_mut::<LowestAvailableHeight>()
+            .insert(&(), &2.into())
+            .unwrap();
+        transaction.commit().unwrap();
+        let view = ReadDatabase::new(on_chain, Database::<OffChain>::default())
+            .view()
+            .unwrap();
+        let pruned_filter = ReceiptFilter {
+            from_height: Some(1.into()),
+            ..filter()
+        };
+        let available_filter = ReceiptFilter {
+            from_height: Some(2.into()),
+            ..filter()
+        };
+
+        // When
+        let pruned = view
+            .filtered_receipts(pruned_filter, None, IterDirection::Forward)
+            .next();
+        let available = view
+            .filtered_receipts(available_filter, None, IterDirection::Forward)
+            .next();
+
+        // Then
+        assert!(matches!(pruned, Some(Err(StorageError::DatabaseError(_)))));
+        assert!(available.is_none());
+    }
+}
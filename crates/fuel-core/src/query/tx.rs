@@ -1,6 +1,9 @@
-use crate::fuel_core_graphql_api::ports::{
-    OffChainDatabase,
-    OnChainDatabase,
+use crate::{
+    fuel_core_graphql_api::ports::{
+        OffChainDatabase,
+        OnChainDatabase,
+    },
+    query::ensure_height_is_not_pruned,
 };
 use fuel_core_storage::{
     iter::{
@@ -10,6 +13,7 @@ use fuel_core_storage::{
     },
     not_found,
     tables::Transactions,
+    IsNotFound,
     Result as StorageResult,
     StorageAsRef,
 };
@@ -20,7 +24,10 @@ use fuel_core_types::{
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        ContractId,
+    },
     services::txpool::TransactionStatus,
 };
 
@@ -37,9 +44,13 @@ where
     D: OnChainDatabase + OffChainDatabase + ?Sized,
 {
     fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
-        self.storage::<Transactions>()
-            .get(tx_id)
-            .and_then(|v| v.ok_or(not_found!(Transactions)).map(|tx| tx.into_owned()))
+        match self.storage::<Transactions>().get(tx_id)? {
+            Some(tx) => Ok(tx.into_owned()),
+            None => {
+                ensure_transaction_is_not_pruned(self, tx_id)?;
+                Err(not_found!(Transactions))
+            }
+        }
     }
 
     fn receipts(&self, tx_id: &TxId) -> StorageResult<Vec<Receipt>> {
@@ -63,6 +74,13 @@ pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
 }
 
 impl<D> TransactionQueryData for D
@@ -70,7 +88,12 @@ where
     D: OnChainDatabase + OffChainDatabase + ?Sized,
 {
     fn status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
-        self.tx_status(tx_id)
+        self.tx_status(tx_id).or_else(|error| {
+            if error.is_not_found() {
+                ensure_transaction_is_not_pruned(self, tx_id)?;
+            }
+            Err(error)
+        })
     }
 
     fn owned_transactions(
@@ -79,6 +102,9 @@ where
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        if let Err(error) = ensure_start_is_not_pruned(self, start) {
+            return core::iter::once(Err(error)).into_boxed()
+        }
         self.owned_transactions_ids(owner, start, direction)
             .map(|result| {
                 result.and_then(|(tx_pointer, tx_id)| {
@@ -89,4 +115,49 @@ where
             })
             .into_boxed()
     }
+
+    fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        if let Err(error) = ensure_start_is_not_pruned(self, start) {
+            return core::iter::once(Err(error)).into_boxed()
+        }
+        self.contract_transactions_ids(contract_id, start, direction)
+            .map(|result| {
+                result.and_then(|(tx_pointer, tx_id)| {
+                    let tx = self.transaction(&tx_id)?;
+
+                    Ok((tx_pointer, tx))
+                })
+            })
+            .into_boxed()
+    }
+}
+
+/// Returns an error if the transaction was removed by the state pruning.
+fn ensure_transaction_is_not_pruned<D>(database: &D, tx_id: &TxId) -> StorageResult<()>
+where
+    D: OnChainDatabase + OffChainDatabase + ?Sized,
+{
+    match database.pruned_transaction_height(tx_id)? {
+        Some(height) => ensure_height_is_not_pruned(database, &height),
+        None => Ok(()),
+    }
+}
+
+/// Returns an error if the pagination starts from the transaction removed by the state pruning.
+fn ensure_start_is_not_pruned<D>(
+    database: &D,
+    start: Option<TxPointer>,
+) -> StorageResult<()>
+where
+    D: OnChainDatabase + ?Sized,
+{
+    match start {
+        Some(start) => ensure_height_is_not_pruned(database, &start.block_height()),
+        None => Ok(()),
+    }
 }
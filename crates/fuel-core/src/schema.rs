@@ -19,15 +19,18 @@ use fuel_core_storage::{
 };
 use itertools::Itertools;
 
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod dap;
+pub mod database;
 pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod receipt;
 
 pub mod gas_price;
 pub mod scalars;
@@ -48,13 +51,25 @@ pub struct Query(
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
     message::MessageQuery,
+    receipt::ReceiptQuery,
+    asset::AssetQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    database::DatabaseMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    block::BlockSubscription,
+    receipt::ReceiptSubscription,
+    database::DatabaseSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -0,0 +1,113 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        storage::assets::AssetDetails,
+        IntoApiResult,
+    },
+    query::AssetQueryData,
+    schema::scalars::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        U128,
+        U32,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Object,
+};
+use fuel_core_types::fuel_types;
+
+pub struct Asset {
+    id: fuel_types::AssetId,
+    details: AssetDetails,
+}
+
+impl From<(fuel_types::AssetId, AssetDetails)> for Asset {
+    fn from((id, details): (fuel_types::AssetId, AssetDetails)) -> Self {
+        Self { id, details }
+    }
+}
+
+#[Object]
+impl Asset {
+    async fn id(&self) -> AssetId {
+        self.id.into()
+    }
+
+    /// The contract that mints the asset. `null` for the base asset.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.details.contract_id.map(Into::into)
+    }
+
+    /// The sub id of the asset in the contract. `null` for the base asset.
+    async fn sub_id(&self) -> Option<Bytes32> {
+        self.details.sub_id.map(Into::into)
+    }
+
+    /// The height of the block with the first mint of the asset.
+    /// The genesis height for the base asset.
+    async fn first_mint_height(&self) -> U32 {
+        self.details.first_mint_height.into()
+    }
+
+    /// The amount of the asset minted and not burned yet. The supply of the base asset
+    /// includes the genesis coins and relayed messages without withdrawals.
+    async fn total_supply(&self) -> U128 {
+        self.details.total_supply.into()
+    }
+}
+
+#[derive(Default)]
+pub struct AssetQuery;
+
+#[Object]
+impl AssetQuery {
+    /// Returns the asset minted by a contract or the base asset.
+    /// The base asset is registered only at the genesis of the node, because its supply
+    /// can't be restored later. So the base asset is `null` on nodes that migrated
+    /// the database of an older version instead of starting from the genesis.
+    async fn asset(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] id: AssetId,
+    ) -> async_graphql::Result<Option<Asset>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let id = id.into();
+        query
+            .asset(&id)
+            .map(|details| (id, details))
+            .into_api_result()
+    }
+
+    /// Returns the assets minted by the contract sorted by their ids.
+    async fn assets(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<AssetId, Asset, EmptyFields, EmptyFields>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let contract_id = contract.into();
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let assets = query
+                .contract_assets(&contract_id, (*start).map(Into::into), direction)
+                .map(|result| {
+                    let (asset_id, details) = result?;
+                    Ok((asset_id.into(), (asset_id, details).into()))
+                });
+
+            Ok(assets)
+        })
+        .await
+    }
+}
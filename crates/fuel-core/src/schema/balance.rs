@@ -1,8 +1,5 @@
 use crate::{
-    fuel_core_graphql_api::{
-        database::ReadView,
-        Config,
-    },
+    fuel_core_graphql_api::database::ReadView,
     query::BalanceQueryData,
     schema::scalars::{
         Address,
@@ -57,11 +54,7 @@ impl BalanceQuery {
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
     ) -> async_graphql::Result<Balance> {
         let query: &ReadView = ctx.data_unchecked();
-        let base_asset_id = *ctx
-            .data_unchecked::<Config>()
-            .consensus_parameters
-            .base_asset_id();
-        let balance = query.balance(owner.0, asset_id.0, base_asset_id)?.into();
+        let balance = query.balance(owner.0, asset_id.0)?.into();
         Ok(balance)
     }
 
@@ -85,15 +78,9 @@ impl BalanceQuery {
         let query: &ReadView = ctx.data_unchecked();
         crate::schema::query_pagination(after, before, first, last, |_, direction| {
             let owner = filter.owner.into();
-            let base_asset_id = *ctx
-                .data_unchecked::<Config>()
-                .consensus_parameters
-                .base_asset_id();
-            Ok(query
-                .balances(owner, direction, base_asset_id)
-                .map(|result| {
-                    result.map(|balance| (balance.asset_id.into(), balance.into()))
-                }))
+            Ok(query.balances(owner, direction).map(|result| {
+                result.map(|balance| (balance.asset_id.into(), balance.into()))
+            }))
         })
         .await
     }
@@ -4,13 +4,20 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
-        database::ReadView,
+        api_service::{
+            BlockImporter,
+            ConsensusModule,
+        },
+        database::{
+            ReadDatabase,
+            ReadView,
+        },
         ports::OffChainDatabase,
         Config as GraphQLConfig,
         IntoApiResult,
     },
     query::{
+        block_stream,
         BlockQueryData,
         SimpleBlockData,
         SimpleTransactionData,
@@ -34,6 +41,7 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -52,6 +60,11 @@ use fuel_core_types::{
     fuel_types,
     fuel_types::BlockHeight,
 };
+use futures::{
+    stream::BoxStream,
+    Stream,
+    StreamExt,
+};
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -315,7 +328,8 @@ impl BlockMutation {
         start_timestamp: Option<Tai64Timestamp>,
         blocks_to_produce: U32,
     ) -> async_graphql::Result<U32> {
-        let query: &ReadView = ctx.data_unchecked();
+        // The view of the request doesn't see the blocks produced by it.
+        let query = ctx.data_unchecked::<ReadDatabase>().live_view();
         let consensus_module = ctx.data_unchecked::<ConsensusModule>();
         let config = ctx.data_unchecked::<GraphQLConfig>().clone();
 
@@ -336,6 +350,77 @@ impl BlockMutation {
     }
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks committed by the node in the ascending order of heights.
+    /// If the `from_height` is set, the stream starts with the already committed blocks
+    /// from this height. Otherwise, it starts with the next committed block.
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Block>> + 'a>
+    {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let query: &ReadView = ctx.data_unchecked();
+        // Subscribe before reading the latest height to not miss blocks committed in between.
+        let heights = block_importer.imported_heights();
+        let latest_height = query.latest_block_height().ok();
+        let heights = futures::stream::iter(latest_height).chain(heights).boxed();
+
+        Ok(blocks_subscription(query, from_height, heights))
+    }
+
+    /// Returns a stream of headers of blocks committed by the node.
+    /// It follows the same rules as `newBlocks`.
+    async fn new_headers<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The height of the first header in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Header>> + 'a>
+    {
+        let blocks = self.new_blocks(ctx, from_height).await?;
+        Ok(blocks.map(|result| result.map(|block| block.0.header().clone().into())))
+    }
+
+    /// Returns a stream of final blocks in the ascending order of heights. The block
+    /// is sent after the node finishes its processing, including the indexes for the API,
+    /// so the following queries about the block's transactions return consistent results.
+    /// The `PoA` blocks are final after the commit.
+    ///
+    /// If the `from_height` is set, the stream starts with the already processed blocks
+    /// from this height. Otherwise, it starts with the next processed block.
+    async fn finalized_blocks<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Block>> + 'a>
+    {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let query: &ReadView = ctx.data_unchecked();
+        // The stream of processed heights starts from the latest processed height.
+        let heights = block_importer.processed_heights();
+
+        Ok(blocks_subscription(query, from_height, heights))
+    }
+}
+
+fn blocks_subscription<'a>(
+    query: &'a ReadView,
+    from_height: Option<U32>,
+    heights: BoxStream<'a, BlockHeight>,
+) -> impl Stream<Item = async_graphql::Result<Block>> + 'a {
+    let from_height = from_height.map(|height| u32::from(height).into());
+    block_stream(|height| query.block(height), from_height, heights)
+        .map(|result| result.map(Into::into).map_err(Into::into))
+}
+
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
         Block(block)
@@ -6,6 +6,7 @@ use crate::{
     query::ContractQueryData,
     schema::scalars::{
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         Salt,
@@ -22,6 +23,10 @@ use async_graphql::{
     Object,
 };
 use fuel_core_types::{
+    fuel_merkle::sparse::proof::{
+        ExclusionLeaf,
+        Proof,
+    },
     fuel_types,
     services::graphql_api,
 };
@@ -70,6 +75,124 @@ impl ContractQuery {
         let query: &ReadView = ctx.data_unchecked();
         query.contract_id(id.0).into_api_result()
     }
+
+    /// Returns the sparse Merkle proof of the storage slot of the contract.
+    async fn contract_storage_proof(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "The key of the storage slot")] key: Bytes32,
+    ) -> async_graphql::Result<ContractStorageProof> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_storage_proof(contract.0, key.0)
+            .map(ContractStorageProof)
+            .map_err(Into::into)
+    }
+}
+
+pub struct SparseMerkleProof(Proof);
+
+#[Object]
+impl SparseMerkleProof {
+    /// `true` if the proof proves the inclusion of the key, `false` for the exclusion.
+    async fn inclusion(&self) -> bool {
+        self.0.is_inclusion()
+    }
+
+    async fn proof_set(&self) -> Vec<Bytes32> {
+        self.0
+            .proof_set()
+            .iter()
+            .cloned()
+            .map(|array| Bytes32::from(fuel_types::Bytes32::from(array)))
+            .collect()
+    }
+
+    /// The leaf closest to the excluded key. It is `null` for the inclusion proof
+    /// and for the exclusion proof that ends with the empty subtree.
+    async fn exclusion_leaf(&self) -> Option<SparseMerkleLeaf> {
+        match &self.0 {
+            Proof::Exclusion(proof) => match &proof.leaf {
+                ExclusionLeaf::Leaf(leaf) => Some(SparseMerkleLeaf {
+                    key: leaf.leaf_key.into(),
+                    value_hash: leaf.leaf_value.into(),
+                }),
+                ExclusionLeaf::Placeholder => None,
+            },
+            Proof::Inclusion(_) => None,
+        }
+    }
+}
+
+pub struct SparseMerkleLeaf {
+    key: fuel_types::Bytes32,
+    value_hash: fuel_types::Bytes32,
+}
+
+#[Object]
+impl SparseMerkleLeaf {
+    async fn key(&self) -> Bytes32 {
+        self.key.into()
+    }
+
+    async fn value_hash(&self) -> Bytes32 {
+        self.value_hash.into()
+    }
+}
+
+pub struct ContractStorageProof(graphql_api::ContractStorageProof);
+
+#[Object]
+impl ContractStorageProof {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// The value of the storage slot, `null` if the slot is empty.
+    async fn value(&self) -> Option<HexString> {
+        self.0.value.clone().map(HexString)
+    }
+
+    /// The root of the contract's state tree.
+    async fn root(&self) -> Bytes32 {
+        self.0.root.into()
+    }
+
+    async fn proof(&self) -> SparseMerkleProof {
+        SparseMerkleProof(self.0.proof.clone())
+    }
+}
+
+pub struct ContractBalanceProof(graphql_api::ContractBalanceProof);
+
+#[Object]
+impl ContractBalanceProof {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.key.into()
+    }
+
+    /// The balance of the asset, `null` if the contract doesn't have the balance.
+    async fn amount(&self) -> Option<U64> {
+        self.0.value.map(Into::into)
+    }
+
+    /// The root of the contract's balances tree.
+    async fn root(&self) -> Bytes32 {
+        self.0.root.into()
+    }
+
+    async fn proof(&self) -> SparseMerkleProof {
+        SparseMerkleProof(self.0.proof.clone())
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
@@ -124,6 +247,20 @@ impl ContractBalanceQuery {
             })
     }
 
+    /// Returns the sparse Merkle proof of the asset balance of the contract.
+    async fn contract_balance_proof(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        asset: AssetId,
+    ) -> async_graphql::Result<ContractBalanceProof> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_balance_proof(contract.into(), asset.into())
+            .map(ContractBalanceProof)
+            .map_err(Into::into)
+    }
+
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
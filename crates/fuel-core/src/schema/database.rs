@@ -0,0 +1,326 @@
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        change_journal::{
+            JournalEntry,
+            JournalRecord,
+            JournalRecordKind,
+        },
+        database_description::on_chain::OnChain,
+        Database,
+    },
+    fuel_core_graphql_api::{
+        api_service::BlockImporter,
+        Config as GraphQLConfig,
+    },
+    schema::scalars::{
+        HexString,
+        U32,
+        U64,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+    Subscription,
+};
+use fuel_core_storage::{
+    column::Column,
+    kv_store::StorageColumn,
+};
+use futures::{
+    stream::BoxStream,
+    Stream,
+    StreamExt,
+};
+use std::path::{
+    Component,
+    Path,
+    PathBuf,
+};
+
+#[derive(Default)]
+pub struct DatabaseMutation;
+
+#[Object]
+impl DatabaseMutation {
+    /// Creates the backup of the node's databases in the `name` subdirectory of
+    /// the backup directory configured on the node. The on-chain and off-chain
+    /// databases in the backup are at the same height.
+    /// Returns the height of the latest block in the backup.
+    async fn backup_database(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The name of the backup subdirectory")] name: String,
+    ) -> async_graphql::Result<Option<U32>> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        let Some(backup_directory) = &config.backup_directory else {
+            return Err(
+                anyhow!("The backup directory is not configured on the node").into(),
+            )
+        };
+
+        let path = backup_path(backup_directory, &name)?;
+        let database = ctx.data::<CombinedDatabase>()?.clone();
+        let height =
+            tokio::task::spawn_blocking(move || database.backup(&path)).await??;
+
+        Ok(height.map(Into::into))
+    }
+}
+
+/// Returns the path of the backup with the `name` inside the `backup_directory`.
+/// The `name` must be a single path component, so the backup can't escape
+/// the directory.
+fn backup_path(backup_directory: &Path, name: &str) -> anyhow::Result<PathBuf> {
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(component)), None) => {
+            Ok(backup_directory.join(component))
+        }
+        _ => Err(anyhow!(
+            "The backup name `{name}` must be a single component of the path"
+        )),
+    }
+}
+
+#[derive(Default)]
+pub struct DatabaseSubscription;
+
+#[Subscription]
+impl DatabaseSubscription {
+    /// Returns a stream of records of the journal of changes of the on-chain state,
+    /// starting from the record with the `from_sequence`. Each commit of the block,
+    /// revert of the block by the rollback, and maintenance commit, like the pruning
+    /// or the migration, appends one record with the next sequence number.
+    /// Applying the records in order mirrors the state of the node.
+    ///
+    /// The stream is woken up by the imported blocks, so records of the maintenance
+    /// commits are sent together with the next block. The stream waits for new blocks
+    /// forever.
+    ///
+    /// The node must be started with the journal of changes enabled. The stream ends
+    /// with an error if the journal doesn't contain the next record because it was pruned.
+    async fn state_changes<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The sequence number of the first record in the stream")]
+        from_sequence: U64,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<StateChanges>> + 'a>
+    {
+        let database = ctx.data::<CombinedDatabase>()?.on_chain().clone();
+
+        if !database.change_journal_policy().records_changes() {
+            return Err(anyhow!(
+                "The journal of changes is disabled, use `--change-journal-window` to enable it"
+            )
+            .into())
+        }
+
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        // Subscribe before reading the journal to not miss records committed in between.
+        // The first notification reads the records committed before the subscription.
+        let notifications = futures::stream::once(async {})
+            .chain(block_importer.imported_heights().map(|_| ()))
+            .boxed();
+
+        let state = StateChangesState {
+            database,
+            notifications,
+            next: u64::from(from_sequence),
+        };
+        let stream = futures::stream::unfold(Some(state), |state| async move {
+            let mut state = state?;
+            loop {
+                match state_changes_at(&state.database, state.next) {
+                    Ok(Some(changes)) => {
+                        let state = state
+                            .next
+                            .checked_add(1)
+                            .map(|next| StateChangesState { next, ..state });
+                        return Some((Ok(changes), state))
+                    }
+                    Ok(None) => state.notifications.next().await?,
+                    Err(err) => return Some((Err(err.into()), None)),
+                }
+            }
+        });
+
+        Ok(stream)
+    }
+}
+
+struct StateChangesState<'a> {
+    database: Database<OnChain>,
+    notifications: BoxStream<'a, ()>,
+    next: u64,
+}
+
+/// Returns the record of the journal with the `sequence`,
+/// or `None` if the record is not committed yet.
+fn state_changes_at(
+    database: &Database<OnChain>,
+    sequence: u64,
+) -> anyhow::Result<Option<StateChanges>> {
+    // The journal is read in one pass, so if it contains a record after the `sequence`,
+    // the record with the `sequence` is missing and not just being committed.
+    let next_record = database.change_journal(sequence).next().transpose()?;
+
+    match next_record {
+        Some((next_sequence, record)) if next_sequence == sequence => {
+            Ok(Some(StateChanges { sequence, record }))
+        }
+        Some(_) => Err(anyhow!(
+            "The journal of changes doesn't contain the record {sequence}"
+        )),
+        None => Ok(None),
+    }
+}
+
+/// The kind of the commit that made the changes.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum StateChangesKind {
+    /// The commit of the block.
+    Commit,
+    /// The revert of the block by the rollback.
+    Revert,
+    /// The commit that doesn't change the height, like the pruning or the migration.
+    Maintenance,
+}
+
+impl From<JournalRecordKind> for StateChangesKind {
+    fn from(kind: JournalRecordKind) -> Self {
+        match kind {
+            JournalRecordKind::Commit => Self::Commit,
+            JournalRecordKind::Revert => Self::Revert,
+            JournalRecordKind::Maintenance => Self::Maintenance,
+        }
+    }
+}
+
+pub struct StateChanges {
+    sequence: u64,
+    record: JournalRecord,
+}
+
+#[Object]
+impl StateChanges {
+    /// The sequence number of the record in the journal.
+    async fn sequence(&self) -> U64 {
+        self.sequence.into()
+    }
+
+    /// The kind of the commit that made the changes.
+    async fn kind(&self) -> StateChangesKind {
+        self.record.kind.into()
+    }
+
+    /// The height of the committed or reverted block. For the maintenance commits,
+    /// the height of the database at the moment of the commit, `null` before
+    /// the first block.
+    async fn height(&self) -> async_graphql::Result<Option<U32>> {
+        let height = self.record.height.map(u32::try_from).transpose()?;
+        Ok(height.map(Into::into))
+    }
+
+    /// The changes sorted by the column and the key.
+    async fn changes(&self) -> Vec<StateChange> {
+        self.record
+            .entries
+            .iter()
+            .cloned()
+            .map(StateChange)
+            .collect()
+    }
+}
+
+pub struct StateChange(JournalEntry);
+
+#[Object]
+impl StateChange {
+    /// The name of the column of the on-chain database.
+    async fn column(&self) -> String {
+        enum_iterator::all::<Column>()
+            .find(|column| column.id() == self.0.column)
+            .map(|column| column.name().to_string())
+            .unwrap_or_else(|| self.0.column.to_string())
+    }
+
+    async fn key(&self) -> HexString {
+        HexString(self.0.key.clone())
+    }
+
+    /// The value before the change, `null` if the key didn't exist.
+    async fn old_value(&self) -> Option<HexString> {
+        self.0.old_value.clone().map(HexString)
+    }
+
+    /// The value after the change, `null` if the key was removed.
+    async fn new_value(&self) -> Option<HexString> {
+        self.0.new_value.clone().map(HexString)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::change_journal::ChangeJournalPolicy;
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        StorageAsMut,
+    };
+    use fuel_core_types::blockchain::block::CompressedBlock;
+
+    #[test]
+    fn backup_path_joins_the_name_to_the_backup_directory() {
+        let path = backup_path(Path::new("/backups"), "daily").unwrap();
+        assert_eq!(path, PathBuf::from("/backups/daily"));
+    }
+
+    #[test]
+    fn backup_path_rejects_names_outside_the_backup_directory() {
+        for name in ["", ".", "..", "../daily", "daily/..", "a/b", "/daily"] {
+            assert!(
+                backup_path(Path::new("/backups"), name).is_err(),
+                "The name `{name}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn state_changes_at_fails_if_the_record_was_pruned() {
+        // Given
+        let mut database = Database::<OnChain>::default().with_change_journal_policy(
+            ChangeJournalPolicy::Range {
+                size: 1.try_into().unwrap(),
+            },
+        );
+        for height in 1..=3u32 {
+            database
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height.into(), &CompressedBlock::default())
+                .unwrap();
+        }
+
+        // When
+        let pruned = state_changes_at(&database, 0);
+        let latest = state_changes_at(&database, 2);
+        let next = state_changes_at(&database, 3);
+
+        // Then
+        assert!(pruned.is_err());
+        let latest = latest
+            .unwrap()
+            .expect("The latest record should be available");
+        assert_eq!(latest.record.kind, JournalRecordKind::Commit);
+        assert_eq!(latest.record.height, Some(3));
+        assert!(next.unwrap().is_none());
+    }
+}
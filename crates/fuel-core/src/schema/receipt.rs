@@ -0,0 +1,260 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::BlockImporter,
+        database::ReadView,
+        storage::receipts::{
+            self,
+            IndexedReceiptType,
+            ReceiptPointer,
+        },
+    },
+    query::{
+        block_stream,
+        ReceiptQueryData,
+    },
+    schema::{
+        scalars::{
+            ContractId,
+            TransactionId,
+            U16,
+            U32,
+            U64,
+        },
+        tx::receipt::{
+            Receipt,
+            ReceiptType,
+        },
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    connection::{
+        Connection,
+        CursorType,
+        EmptyFields,
+    },
+    Context,
+    InputObject,
+    Object,
+    Subscription,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::fuel_types::BlockHeight;
+use futures::{
+    Stream,
+    StreamExt,
+};
+use std::str::FromStr;
+
+pub struct ContractReceipt {
+    pointer: ReceiptPointer,
+    receipt: receipts::ContractReceipt,
+}
+
+#[Object]
+impl ContractReceipt {
+    /// The height of the block with the transaction that emitted the receipt.
+    async fn block_height(&self) -> U32 {
+        self.pointer.block_height.into()
+    }
+
+    /// The index of the transaction in the block.
+    async fn transaction_index(&self) -> U16 {
+        self.pointer.tx_idx.into()
+    }
+
+    /// The index of the receipt among receipts of the transaction.
+    async fn receipt_index(&self) -> U32 {
+        self.pointer.receipt_idx.into()
+    }
+
+    async fn transaction_id(&self) -> TransactionId {
+        self.receipt.tx_id.into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        self.receipt.receipt.clone().into()
+    }
+}
+
+#[derive(InputObject)]
+struct ReceiptFilterInput {
+    /// Filter receipts emitted by the contract
+    contract_id: ContractId,
+    /// Filter receipts by the type. Only `LOG`, `LOG_DATA`, `TRANSFER`, `MINT`
+    /// and `BURN` receipts are indexed, all of them are returned if it is not set.
+    receipt_type: Option<ReceiptType>,
+    /// Filter `LOG` and `LOG_DATA` receipts by the `rb` register used as the id of the log
+    log_id: Option<U64>,
+    /// The lowest height of the block with receipts, inclusive
+    from_height: Option<U32>,
+    /// The highest height of the block with receipts, inclusive
+    to_height: Option<U32>,
+}
+
+impl TryFrom<ReceiptFilterInput> for crate::query::ReceiptFilter {
+    type Error = anyhow::Error;
+
+    fn try_from(filter: ReceiptFilterInput) -> Result<Self, Self::Error> {
+        let receipt_type = filter
+            .receipt_type
+            .map(|receipt_type| match receipt_type {
+                ReceiptType::Log => Ok(IndexedReceiptType::Log),
+                ReceiptType::LogData => Ok(IndexedReceiptType::LogData),
+                ReceiptType::Transfer => Ok(IndexedReceiptType::Transfer),
+                ReceiptType::Mint => Ok(IndexedReceiptType::Mint),
+                ReceiptType::Burn => Ok(IndexedReceiptType::Burn),
+                receipt_type => Err(anyhow!(
+                    "Receipts of the type {receipt_type} are not indexed"
+                )),
+            })
+            .transpose()?;
+
+        Ok(Self {
+            contract_id: filter.contract_id.into(),
+            receipt_type,
+            log_id: filter.log_id.map(Into::into),
+            from_height: filter.from_height.map(|height| u32::from(height).into()),
+            to_height: filter.to_height.map(|height| u32::from(height).into()),
+        })
+    }
+}
+
+/// The cursor of the receipt in the format `block_height#tx_idx#receipt_idx`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptCursor(ReceiptPointer);
+
+impl CursorType for ReceiptCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let mut parts = s.split('#');
+        let (Some(block_height), Some(tx_idx), Some(receipt_idx), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Incorrect format provided".to_string())
+        };
+
+        Ok(Self(ReceiptPointer::new(
+            u32::from_str(block_height)
+                .map_err(|_| "Failed to decode block_height")?
+                .into(),
+            u16::from_str(tx_idx).map_err(|_| "Failed to decode tx_idx")?,
+            u32::from_str(receipt_idx).map_err(|_| "Failed to decode receipt_idx")?,
+        )))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!(
+            "{}#{}#{}",
+            self.0.block_height, self.0.tx_idx, self.0.receipt_idx
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct ReceiptQuery;
+
+#[Object]
+impl ReceiptQuery {
+    /// Returns the receipts emitted by the contract in the order of their appearance.
+    /// Only receipts of successful transactions are indexed.
+    async fn receipts(
+        &self,
+        ctx: &Context<'_>,
+        filter: ReceiptFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ReceiptCursor, ContractReceipt, EmptyFields, EmptyFields>,
+    > {
+        let query: &ReadView = ctx.data_unchecked();
+        let filter = crate::query::ReceiptFilter::try_from(filter)?;
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<ReceiptCursor>, direction| {
+                let start = start.map(|cursor| cursor.0);
+                let receipts =
+                    query
+                        .filtered_receipts(filter, start, direction)
+                        .map(|result| {
+                            result.map(|(pointer, receipt)| {
+                                (
+                                    ReceiptCursor(pointer),
+                                    ContractReceipt { pointer, receipt },
+                                )
+                            })
+                        });
+                Ok(receipts)
+            },
+        )
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct ReceiptSubscription;
+
+#[Subscription]
+impl ReceiptSubscription {
+    /// Returns a stream of receipts emitted by the contract in the order of their
+    /// appearance. The receipts of the block are sent after the node finishes
+    /// its processing. If the `from_height` of the filter is set, the stream starts
+    /// with the already processed receipts from this height. Otherwise, it starts
+    /// with receipts of the next processed block. The stream ends after
+    /// the `to_height` if it is set.
+    async fn logs<'a>(
+        &self,
+        ctx: &Context<'a>,
+        filter: ReceiptFilterInput,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<ContractReceipt>> + 'a,
+    > {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let query: &ReadView = ctx.data_unchecked();
+        let filter = crate::query::ReceiptFilter::try_from(filter)?;
+        let from_height = filter.from_height;
+        let to_height = filter.to_height;
+        // The stream of processed heights starts from the latest processed height.
+        let heights = block_importer.processed_heights();
+
+        let receipts_at = move |height: &BlockHeight| {
+            let mut filter = filter.clone();
+            filter.from_height = Some(*height);
+            filter.to_height = Some(*height);
+            let receipts = query
+                .filtered_receipts(filter, None, IterDirection::Forward)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((*height, receipts))
+        };
+
+        let stream = block_stream(receipts_at, from_height, heights)
+            .take_while(move |result| {
+                let before_end = match (result, to_height) {
+                    (Ok((height, _)), Some(to_height)) => *height <= to_height,
+                    _ => true,
+                };
+                futures::future::ready(before_end)
+            })
+            .flat_map(|result| {
+                let receipts = match result {
+                    Ok((_, receipts)) => receipts
+                        .into_iter()
+                        .map(|(pointer, receipt)| {
+                            Ok(ContractReceipt { pointer, receipt })
+                        })
+                        .collect(),
+                    Err(err) => vec![Err(err.into())],
+                };
+                futures::stream::iter(receipts)
+            });
+
+        Ok(stream)
+    }
+}
@@ -18,6 +18,7 @@ use crate::{
     schema::{
         scalars::{
             Address,
+            ContractId,
             HexString,
             SortedTxCursor,
             TransactionId,
@@ -200,6 +201,43 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the transactions that use the contract as an input or create it,
+    /// sorted by block height and index within the block.
+    async fn transactions_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        let query: &ReadView = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let contract_id = fuel_types::ContractId::from(contract_id);
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .contract_transactions(contract_id, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&config.consensus_parameters.chain_id());
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
     /// Estimate the predicate gas for the provided transaction
     async fn estimate_predicates(
         &self,
@@ -32,6 +32,8 @@ pub mod config;
 pub mod genesis;
 pub mod metrics;
 mod query;
+pub mod read_replica;
+pub mod state_pruning;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -195,7 +197,16 @@ impl Task {
     pub fn new(database: CombinedDatabase, config: Config) -> anyhow::Result<Task> {
         // initialize state
         tracing::info!("Initializing database");
+        // The read replica can't modify the databases, so they are migrated by the primary.
+        if !config.is_read_replica() {
+            database.migrate()?;
+        }
         database.check_version()?;
+        // The replica sees the commits of the primary to the on-chain and off-chain
+        // databases at different moments, so it can't check their consistency.
+        if !config.is_read_replica() {
+            database.check_consistency()?;
+        }
 
         // initialize sub services
         tracing::info!("Initializing sub services");
@@ -226,15 +237,18 @@ impl RunnableService for Task {
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         // check if chain is initialized
-        if let Err(err) = self.shared.database.on_chain().get_genesis() {
-            if err.is_not_found() {
-                let result = genesis::execute_genesis_block(
-                    &self.shared.config,
-                    &self.shared.database,
-                )
-                .await?;
-
-                self.shared.block_importer.commit_result(result).await?;
+        // The genesis of the read replica is executed by the primary node.
+        if !self.shared.config.is_read_replica() {
+            if let Err(err) = self.shared.database.on_chain().get_genesis() {
+                if err.is_not_found() {
+                    let result = genesis::execute_genesis_block(
+                        &self.shared.config,
+                        &self.shared.database,
+                    )
+                    .await?;
+
+                    self.shared.block_importer.commit_result(result).await?;
+                }
             }
         }
 
@@ -361,4 +375,29 @@ mod tests {
             assert_eq!(service.borrow_and_update().clone(), State::Stopped);
         }
     }
+
+    #[tokio::test]
+    async fn read_replica_starts_only_graphql() {
+        // given
+        let mut config = Config::local_node();
+        config.combined_db_config.read_replica_of = Some("./primary".into());
+        let config = config.make_config_consistent();
+
+        // when
+        let task = Task::new(Default::default(), config).unwrap();
+        let mut task = task.into_task(&Default::default(), ()).await.unwrap();
+
+        // then
+        // current services: graphql, read replica
+        assert_eq!(task.sub_services().len(), 2);
+        for service in task.sub_services() {
+            assert_eq!(service.state(), State::Started);
+        }
+        // The genesis is executed only by the primary node.
+        assert_eq!(
+            task.shared.database.on_chain().latest_height().unwrap(),
+            None
+        );
+        task.shutdown().await.unwrap();
+    }
 }
@@ -60,6 +60,24 @@ impl TxPoolAdapter {
     }
 }
 
+/// The transaction pool of the read replica. The replica doesn't accept
+/// transactions, so the pool is always empty.
+#[derive(Clone, Default)]
+pub struct ReadReplicaTxPool;
+
+/// Notifies the GraphQL subscriptions of the read replica about the blocks
+/// of the primary node that became visible after the catch up.
+#[derive(Clone)]
+pub struct ReadReplicaBlockImporter {
+    heights: crate::service::read_replica::SharedState,
+}
+
+impl ReadReplicaBlockImporter {
+    pub fn new(heights: crate::service::read_replica::SharedState) -> Self {
+        Self { heights }
+    }
+}
+
 #[derive(Clone)]
 pub struct TransactionsSource {
     txpool: TxPoolSharedState<P2PAdapter, Database, StaticGasPrice>,
@@ -151,6 +169,26 @@ impl BlockImporterAdapter {
     }
 }
 
+/// Notifies the GraphQL subscriptions about the blocks imported by the node
+/// and processed by the off-chain worker.
+#[derive(Clone)]
+pub struct GraphQLBlockImporterAdapter {
+    block_importer: BlockImporterAdapter,
+    processed_height: tokio::sync::watch::Receiver<Option<BlockHeight>>,
+}
+
+impl GraphQLBlockImporterAdapter {
+    pub fn new(
+        block_importer: BlockImporterAdapter,
+        processed_height: tokio::sync::watch::Receiver<Option<BlockHeight>>,
+    ) -> Self {
+        Self {
+            block_importer,
+            processed_height,
+        }
+    }
+}
+
 #[cfg(feature = "p2p")]
 #[derive(Clone)]
 pub struct P2PAdapter {
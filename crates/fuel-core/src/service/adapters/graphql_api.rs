@@ -7,6 +7,7 @@ use crate::{
     database::Database,
     fuel_core_graphql_api::ports::{
         worker,
+        BlockImporterPort,
         BlockProducerPort,
         DatabaseMessageProof,
         GasPriceEstimate,
@@ -14,12 +15,18 @@ use crate::{
         TxPoolPort,
     },
     service::adapters::{
+        GraphQLBlockImporterAdapter,
         P2PAdapter,
+        ReadReplicaBlockImporter,
+        ReadReplicaTxPool,
         TxPoolAdapter,
     },
 };
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
+use fuel_core_services::stream::{
+    BoxStream,
+    IntoBoxStream,
+};
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
     service::TxStatusMessage,
@@ -43,10 +50,12 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use futures::StreamExt;
 use std::{
     ops::Deref,
     sync::Arc,
 };
+use tokio_stream::wrappers::WatchStream;
 
 mod off_chain;
 mod on_chain;
@@ -85,6 +94,36 @@ impl TxPoolPort for TxPoolAdapter {
     }
 }
 
+#[async_trait]
+impl TxPoolPort for ReadReplicaTxPool {
+    fn transaction(&self, _: TxId) -> Option<Transaction> {
+        None
+    }
+
+    fn submission_time(&self, _: TxId) -> Option<Tai64> {
+        None
+    }
+
+    async fn insert(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        txs.iter()
+            .map(|_| {
+                Err(anyhow::anyhow!(
+                    "The node is a read replica and doesn't accept transactions"
+                ))
+            })
+            .collect()
+    }
+
+    fn tx_update_subscribe(&self, _: TxId) -> anyhow::Result<BoxStream<TxStatusMessage>> {
+        // The replica doesn't observe the transaction pool of the primary,
+        // so only the statuses already stored in the database are returned.
+        Ok(futures::stream::empty().into_boxed())
+    }
+}
+
 impl DatabaseMessageProof for Database {
     fn block_history_proof(
         &self,
@@ -147,6 +186,35 @@ impl P2pPort for P2PAdapter {
     }
 }
 
+impl BlockImporterPort for GraphQLBlockImporterAdapter {
+    fn imported_heights(&self) -> BoxStream<BlockHeight> {
+        self.block_importer
+            .events()
+            .map(|result| *result.sealed_block.entity.header().height())
+            .into_boxed()
+    }
+
+    fn processed_heights(&self) -> BoxStream<BlockHeight> {
+        WatchStream::new(self.processed_height.clone())
+            .filter_map(futures::future::ready)
+            .into_boxed()
+    }
+}
+
+impl BlockImporterPort for ReadReplicaBlockImporter {
+    fn imported_heights(&self) -> BoxStream<BlockHeight> {
+        WatchStream::new(self.heights.on_chain_height.clone())
+            .filter_map(futures::future::ready)
+            .into_boxed()
+    }
+
+    fn processed_heights(&self) -> BoxStream<BlockHeight> {
+        WatchStream::new(self.heights.off_chain_height.clone())
+            .filter_map(futures::future::ready)
+            .into_boxed()
+    }
+}
+
 impl worker::BlockImporter for BlockImporterAdapter {
     fn block_events(&self) -> BoxStream<SharedImportResult> {
         self.events()
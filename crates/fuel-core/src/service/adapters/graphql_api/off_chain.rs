@@ -9,19 +9,43 @@ use crate::{
             OffChainDatabase,
         },
         storage::{
+            assets::{
+                AssetDetails,
+                Assets,
+                ContractAssets,
+            },
+            balances::{
+                Balances,
+                BalancesKey,
+            },
             contracts::ContractsInfo,
-            transactions::OwnedTransactionIndexCursor,
+            receipts::{
+                ContractLogKey,
+                ContractLogs,
+                ContractReceipt,
+                ContractReceiptKey,
+                ContractReceipts,
+                IndexedReceiptType,
+                ReceiptPointer,
+            },
+            transactions::{
+                OwnedTransactionIndexCursor,
+                PrunedTransactions,
+            },
         },
     },
 };
 use fuel_core_storage::{
+    blueprint::indexed::IteratorOverIndex,
     iter::{
         BoxedIter,
         IntoBoxedIter,
         IterDirection,
+        IteratorOverTable,
     },
     not_found,
     transactional::{
+        AtomicView,
         IntoTransaction,
         StorageTransaction,
     },
@@ -35,8 +59,10 @@ use fuel_core_txpool::types::{
 };
 use fuel_core_types::{
     blockchain::primitives::BlockId,
+    fuel_asm::Word,
     fuel_tx::{
         Address,
+        AssetId,
         Salt,
         TxPointer,
         UtxoId,
@@ -60,6 +86,17 @@ impl OffChainDatabase for Database<OffChain> {
             .ok_or(not_found!("TransactionId"))?
     }
 
+    fn pruned_transaction_height(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<BlockHeight>> {
+        let height = self
+            .storage_as_ref::<PrunedTransactions>()
+            .get(tx_id)?
+            .map(|height| height.into_owned());
+        Ok(height)
+    }
+
     fn owned_coins_ids(
         &self,
         owner: &Address,
@@ -97,6 +134,17 @@ impl OffChainDatabase for Database<OffChain> {
             .into_boxed()
     }
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        self.contract_transactions(contract_id, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -106,6 +154,97 @@ impl OffChainDatabase for Database<OffChain> {
 
         Ok(salt)
     }
+
+    fn balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u128> {
+        let balance = self
+            .storage_as_ref::<Balances>()
+            .get(&BalancesKey::new(owner, asset_id))?
+            .map(|balance| *balance)
+            .unwrap_or_default();
+
+        Ok(balance)
+    }
+
+    fn balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u128)>> {
+        self.iter_all_filtered::<Balances, _>(Some(*owner), None, Some(direction))
+            .map(|result| result.map(|(key, balance)| (*key.asset_id(), balance)))
+            .into_boxed()
+    }
+
+    fn contract_receipts(
+        &self,
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptPointer, ContractReceipt)>> {
+        let prefix = ContractReceiptKey::prefix(contract_id, receipt_type);
+        let start = start
+            .map(|pointer| ContractReceiptKey::new(contract_id, receipt_type, pointer));
+        self.iter_all_filtered::<ContractReceipts, _>(
+            Some(prefix),
+            start.as_ref(),
+            Some(direction),
+        )
+        .map(|result| result.map(|(key, receipt)| (key.pointer, receipt)))
+        .into_boxed()
+    }
+
+    fn contract_logs(
+        &self,
+        contract_id: &ContractId,
+        receipt_type: IndexedReceiptType,
+        log_id: Word,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(ReceiptPointer, ContractReceipt)>> {
+        let prefix = ContractLogKey::prefix(contract_id, receipt_type, log_id);
+        let start = start.map(|pointer| {
+            ContractLogKey::new(contract_id, receipt_type, log_id, pointer)
+        });
+        self.iter_all_filtered::<ContractLogs, _>(
+            Some(prefix),
+            start.as_ref(),
+            Some(direction),
+        )
+        .map(|result| {
+            let (key, _) = result?;
+            let receipt = self
+                .storage_as_ref::<ContractReceipts>()
+                .get(&key.receipt_key())?
+                .ok_or(not_found!(ContractReceipts))?
+                .into_owned();
+            Ok((key.pointer, receipt))
+        })
+        .into_boxed()
+    }
+
+    fn asset_details(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        let details = self
+            .storage_as_ref::<Assets>()
+            .get(asset_id)?
+            .ok_or(not_found!(Assets))?
+            .into_owned();
+
+        Ok(details)
+    }
+
+    fn contract_asset_ids(
+        &self,
+        contract_id: &ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<AssetId>> {
+        self.iter_by_index::<Assets, ContractAssets>(
+            contract_id,
+            start_asset.as_ref(),
+            Some(direction),
+        )
+    }
 }
 
 impl Transactional for Database<OffChain> {
@@ -114,4 +253,8 @@ impl Transactional for Database<OffChain> {
     fn transaction(&mut self) -> Self::Transaction<'_> {
         self.into_transaction()
     }
+
+    fn latest_height(&self) -> Option<BlockHeight> {
+        AtomicView::latest_height(self)
+    }
 }
@@ -53,6 +53,10 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockHeight"))?
     }
+
+    fn lowest_available_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.lowest_available_height()
+    }
 }
 
 impl DatabaseMessages for Database {
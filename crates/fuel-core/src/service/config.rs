@@ -6,6 +6,8 @@ use fuel_core_types::{
 };
 use std::{
     net::SocketAddr,
+    num::NonZeroU32,
+    path::PathBuf,
     time::Duration,
 };
 use strum_macros::{
@@ -63,6 +65,15 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The number of the latest blocks to keep. Older blocks, transactions
+    /// and their receipts are removed. If `None`, all blocks are kept.
+    pub state_pruning: Option<NonZeroU32>,
+    /// The interval between catching up with the primary node
+    /// when the databases are read replicas.
+    pub read_replica_catch_up_interval: Duration,
+    /// The directory for the backups of the databases created via the GraphQL API.
+    /// If `None`, the backups via the GraphQL API are disabled.
+    pub backup_directory: Option<PathBuf>,
 }
 
 impl Config {
@@ -83,6 +94,12 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            state_rewind_policy: Default::default(),
+            change_journal_policy: Default::default(),
+            column_profiles: Default::default(),
+            access_metrics: false,
+            access_trace_directory: None,
+            read_replica_of: None,
         };
 
         Self {
@@ -119,9 +136,18 @@ impl Config {
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            state_pruning: None,
+            read_replica_catch_up_interval: Duration::from_secs(1),
+            backup_directory: None,
         }
     }
 
+    /// Returns `true` if the node serves only the GraphQL API
+    /// over the databases of another node.
+    pub fn is_read_replica(&self) -> bool {
+        self.combined_db_config.is_read_replica()
+    }
+
     // TODO: Rework our configs system to avoid nesting of the same configs.
     pub fn make_config_consistent(mut self) -> Config {
         if !self.debug && !self.utxo_validation {
@@ -151,6 +177,24 @@ impl Config {
             self.block_producer.utxo_validation = self.utxo_validation;
         }
 
+        if self.is_read_replica() {
+            if self.block_production != Trigger::Never {
+                tracing::warn!("The block production is disabled for the read replica");
+                self.block_production = Trigger::Never;
+            }
+            #[cfg(feature = "p2p")]
+            if self.p2p.take().is_some() {
+                tracing::warn!("The P2P is disabled for the read replica");
+            }
+            #[cfg(feature = "relayer")]
+            if self.relayer.take().is_some() {
+                tracing::warn!("The relayer is disabled for the read replica");
+            }
+            if self.state_pruning.take().is_some() {
+                tracing::warn!("The state pruning is disabled for the read replica");
+            }
+        }
+
         self
     }
 }
@@ -181,4 +225,5 @@ pub struct VMConfig {
 pub enum DbType {
     InMemory,
     RocksDb,
+    Redb,
 }
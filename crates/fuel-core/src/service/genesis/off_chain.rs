@@ -8,6 +8,7 @@ use crate::{
     },
     graphql_api::{
         storage::{
+            assets::increase_base_asset_supply,
             blocks::FuelBlockIdsToHeights,
             coins::OwnedCoins,
             contracts::ContractsInfo,
@@ -124,7 +125,7 @@ impl ProcessState for Handler<OwnedMessageIds> {
         let events = group
             .into_iter()
             .map(|TableEntry { value, .. }| Cow::Owned(Event::MessageImported(value)));
-        worker_service::process_executor_events(events, tx)?;
+        worker_service::process_executor_events(events, &self.base_asset_id, tx)?;
         Ok(())
     }
 }
@@ -139,10 +140,16 @@ impl ProcessState for Handler<OwnedCoins> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut Database<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        // The genesis coins form the initial supply of the base asset.
+        for TableEntry { value, .. } in group.iter() {
+            if value.asset_id() == &self.base_asset_id {
+                increase_base_asset_supply(tx, &self.base_asset_id, *value.amount())?;
+            }
+        }
         let events = group.into_iter().map(|TableEntry { value, key }| {
             Cow::Owned(Event::CoinCreated(value.uncompress(key)))
         });
-        worker_service::process_executor_events(events, tx)?;
+        worker_service::process_executor_events(events, &self.base_asset_id, tx)?;
         Ok(())
     }
 }
@@ -612,6 +612,17 @@ mod tests {
         ) -> StorageResult<()> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
+
+        fn latest_view(
+            &self,
+        ) -> StorageResult<Arc<dyn TransactableStorage<BlockHeight, Column = Self::Column>>>
+        {
+            unimplemented!()
+        }
+
+        fn checkpoint(&self, _: &std::path::Path) -> StorageResult<()> {
+            unimplemented!()
+        }
     }
 
     #[test]
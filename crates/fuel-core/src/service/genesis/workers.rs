@@ -15,6 +15,7 @@ use crate::{
         on_chain::OnChain,
     },
     graphql_api::storage::{
+        assets::register_base_asset,
         coins::OwnedCoins,
         contracts::ContractsInfo,
         messages::OwnedMessageIds,
@@ -41,10 +42,14 @@ use fuel_core_storage::{
         Messages,
         Transactions,
     },
+    transactional::WriteTransaction,
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+    },
 };
 use tokio::sync::Notify;
 use tokio_rayon::AsyncRayonHandle;
@@ -55,6 +60,7 @@ pub struct GenesisWorkers {
     cancel_token: CancellationToken,
     block_height: BlockHeight,
     da_block_height: DaBlockHeight,
+    base_asset_id: AssetId,
     snapshot_reader: SnapshotReader,
     finished_signals: HashMap<String, Arc<Notify>>,
 }
@@ -63,11 +69,16 @@ impl GenesisWorkers {
     pub fn new(db: CombinedDatabase, snapshot_reader: SnapshotReader) -> Self {
         let block_height = snapshot_reader.block_height();
         let da_block_height = snapshot_reader.da_block_height();
+        let base_asset_id = *snapshot_reader
+            .chain_config()
+            .consensus_parameters
+            .base_asset_id();
         Self {
             db,
             cancel_token: CancellationToken::new(),
             block_height,
             da_block_height,
+            base_asset_id,
             snapshot_reader,
             finished_signals: HashMap::default(),
         }
@@ -89,13 +100,25 @@ impl GenesisWorkers {
 
     pub async fn run_off_chain_imports(&mut self) -> anyhow::Result<()> {
         tracing::info!("Running off-chain imports");
+        // The genesis coins and messages change the supply of the base asset,
+        // so it is registered before their import.
+        let mut off_chain = self.db.off_chain().clone();
+        let mut transaction = off_chain.write_transaction();
+        register_base_asset(&mut transaction, &self.base_asset_id, self.block_height)?;
+        transaction.commit()?;
         // TODO: Should we insert a FuelBlockIdsToHeights entry for the genesis block?
+        let coins = self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
         tokio::try_join!(
             self.spawn_worker_off_chain::<TransactionStatuses, TransactionStatuses>()?,
             self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?,
-            self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?,
-            self.spawn_worker_off_chain::<Coins, OwnedCoins>()?,
-            self.spawn_worker_off_chain::<Transactions, ContractsInfo>()?
+            self.spawn_worker_off_chain::<Transactions, ContractsInfo>()?,
+            // Coins and messages both update the balances of the base asset,
+            // so messages are imported only after coins to avoid lost updates.
+            async {
+                coins.await?;
+                self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?
+                    .await
+            }
         )
         .map(|_| ())
     }
@@ -126,7 +149,7 @@ impl GenesisWorkers {
         let runner = GenesisRunner::new(
             Some(finished_signal),
             self.cancel_token.clone(),
-            Handler::new(self.block_height, self.da_block_height),
+            Handler::new(self.block_height, self.da_block_height, self.base_asset_id),
             groups,
             self.db.on_chain().clone(),
         );
@@ -151,7 +174,11 @@ impl GenesisWorkers {
         let runner = GenesisRunner::new(
             Some(finished_signal),
             self.cancel_token.clone(),
-            Handler::<TableBeingWritten>::new(self.block_height, self.da_block_height),
+            Handler::<TableBeingWritten>::new(
+                self.block_height,
+                self.da_block_height,
+                self.base_asset_id,
+            ),
             groups,
             self.db.off_chain().clone(),
         );
@@ -170,14 +197,20 @@ impl GenesisWorkers {
 pub struct Handler<T> {
     pub block_height: BlockHeight,
     pub da_block_height: DaBlockHeight,
+    pub base_asset_id: AssetId,
     pub phaton_data: PhantomData<T>,
 }
 
 impl<T> Handler<T> {
-    pub fn new(block_height: BlockHeight, da_block_height: DaBlockHeight) -> Self {
+    pub fn new(
+        block_height: BlockHeight,
+        da_block_height: DaBlockHeight,
+        base_asset_id: AssetId,
+    ) -> Self {
         Self {
             block_height,
             da_block_height,
+            base_asset_id,
             phaton_data: PhantomData,
         }
     }
@@ -0,0 +1,116 @@
+use crate::combined_database::CombinedDatabase;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// The heights of the on-chain and off-chain databases of the primary node
+/// visible to the read replica after the latest catch up.
+#[derive(Clone)]
+pub struct SharedState {
+    pub on_chain_height: watch::Receiver<Option<BlockHeight>>,
+    pub off_chain_height: watch::Receiver<Option<BlockHeight>>,
+}
+
+/// The read replica task periodically makes visible the changes
+/// committed by the primary node into the databases.
+pub struct Task {
+    database: CombinedDatabase,
+    catch_up_interval: Duration,
+    on_chain_height: watch::Sender<Option<BlockHeight>>,
+    off_chain_height: watch::Sender<Option<BlockHeight>>,
+}
+
+impl Task {
+    /// Catches up with the primary and notifies the subscribers about new heights.
+    fn catch_up_with_primary(&self) -> anyhow::Result<()> {
+        self.database.catch_up_with_primary()?;
+        let on_chain_height = self.database.on_chain().latest_height()?;
+        let off_chain_height = self.database.off_chain().latest_height()?;
+        self.on_chain_height
+            .send_if_modified(|height| replace(height, on_chain_height));
+        self.off_chain_height
+            .send_if_modified(|height| replace(height, off_chain_height));
+        Ok(())
+    }
+}
+
+/// Replaces the `height` and returns `true` if it was changed.
+fn replace(height: &mut Option<BlockHeight>, new_height: Option<BlockHeight>) -> bool {
+    let modified = *height != new_height;
+    *height = new_height;
+    modified
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "ReadReplica";
+    type SharedData = SharedState;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        SharedState {
+            on_chain_height: self.on_chain_height.subscribe(),
+            off_chain_height: self.off_chain_height.subscribe(),
+        }
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.catch_up_with_primary()?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = tokio::time::sleep(self.catch_up_interval) => {
+                if let Err(err) = self.catch_up_with_primary() {
+                    // The primary may be in the middle of the compaction,
+                    // so the next attempt may succeed.
+                    tracing::warn!("Failed to catch up with the primary: {err}");
+                }
+
+                should_continue = true
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: CombinedDatabase,
+    catch_up_interval: Duration,
+) -> ServiceRunner<Task> {
+    // The heights are set by the first catch up during the start of the service.
+    let (on_chain_height, _) = watch::channel(None);
+    let (off_chain_height, _) = watch::channel(None);
+    ServiceRunner::new(Task {
+        database,
+        catch_up_interval,
+        on_chain_height,
+        off_chain_height,
+    })
+}
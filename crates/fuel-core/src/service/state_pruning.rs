@@ -0,0 +1,134 @@
+use crate::combined_database::CombinedDatabase;
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+use futures::StreamExt;
+use std::num::NonZeroU32;
+
+/// The maximum number of blocks removed by one iteration of the task.
+/// Limits the time the task spends on the pruning before checking the new events.
+const BLOCKS_PER_ITERATION: u32 = 100;
+
+/// The state pruning task removes the blocks, transactions and their off-chain
+/// indexes that are older than the `window` of the latest blocks.
+pub struct Task {
+    database: CombinedDatabase,
+    block_importer: BoxStream<SharedImportResult>,
+    window: NonZeroU32,
+    /// The data below this height should be pruned.
+    prune_below: Option<BlockHeight>,
+    /// The lowest height available in the database. `None` if it is unknown yet.
+    lowest_available_height: Option<BlockHeight>,
+}
+
+impl Task {
+    fn update_latest_height(&mut self, latest_height: BlockHeight) {
+        self.prune_below = u32::from(latest_height)
+            .saturating_add(1)
+            .checked_sub(self.window.get())
+            .map(BlockHeight::from);
+    }
+
+    fn is_pruning_pending(&self) -> bool {
+        match (self.lowest_available_height, self.prune_below) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(lowest), Some(prune_below)) => lowest < prune_below,
+        }
+    }
+
+    fn prune(&mut self) -> anyhow::Result<()> {
+        let Some(prune_below) = self.prune_below else {
+            return Ok(())
+        };
+        let lowest_available_height = self
+            .database
+            .prune_blocks_below(prune_below, BLOCKS_PER_ITERATION)?;
+        self.lowest_available_height = Some(lowest_available_height);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "StatePruning";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        // Catches up with the blocks imported while the pruning was disabled.
+        if let Some(latest_height) = self.database.on_chain().latest_height()? {
+            self.update_latest_height(latest_height);
+        }
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_importer.next() => {
+                if let Some(result) = result {
+                    let height = *result.sealed_block.entity.header().height();
+                    self.update_latest_height(height);
+
+                    should_continue = true
+                } else {
+                    should_continue = false
+                }
+            }
+
+            _ = core::future::ready(()), if self.is_pruning_pending() => {
+                self.prune()?;
+
+                should_continue = true
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The remaining data is pruned on the next start of the node.
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: CombinedDatabase,
+    block_importer: BoxStream<SharedImportResult>,
+    window: NonZeroU32,
+) -> ServiceRunner<Task> {
+    ServiceRunner::new(Task {
+        database,
+        block_importer,
+        window,
+        prune_below: None,
+        lowest_available_height: None,
+    })
+}
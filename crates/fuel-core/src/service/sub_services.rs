@@ -14,8 +14,11 @@ use crate::{
             BlockImporterAdapter,
             BlockProducerAdapter,
             ExecutorAdapter,
+            GraphQLBlockImporterAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
+            ReadReplicaBlockImporter,
+            ReadReplicaTxPool,
             TxPoolAdapter,
             VerifierAdapter,
         },
@@ -23,6 +26,7 @@ use crate::{
         SharedState,
         SubServices,
     },
+    state::instrumented::AccessContext,
 };
 use fuel_core_poa::Trigger;
 use std::sync::Arc;
@@ -62,19 +66,34 @@ pub fn init_sub_services(
     let last_height = *last_block_header.height();
 
     let executor = ExecutorAdapter::new(
-        database.on_chain().clone(),
-        database.relayer().clone(),
+        database
+            .on_chain()
+            .clone()
+            .with_access_context(AccessContext::Executor),
+        database
+            .relayer()
+            .clone()
+            .with_access_context(AccessContext::Executor),
         fuel_core_upgradable_executor::config::Config {
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
         },
     );
 
-    let verifier = VerifierAdapter::new(config, database.on_chain().clone());
+    let verifier = VerifierAdapter::new(
+        config,
+        database
+            .on_chain()
+            .clone()
+            .with_access_context(AccessContext::Importer),
+    );
 
     let importer_adapter = BlockImporterAdapter::new(
         config.block_importer.clone(),
-        database.on_chain().clone(),
+        database
+            .on_chain()
+            .clone()
+            .with_access_context(AccessContext::Importer),
         executor.clone(),
         verifier.clone(),
     );
@@ -82,7 +101,10 @@ pub fn init_sub_services(
     #[cfg(feature = "relayer")]
     let relayer_service = if let Some(config) = &config.relayer {
         Some(fuel_core_relayer::new_service(
-            database.relayer().clone(),
+            database
+                .relayer()
+                .clone()
+                .with_access_context(AccessContext::Relayer),
             config.clone(),
         )?)
     } else {
@@ -105,7 +127,10 @@ pub fn init_sub_services(
         fuel_core_p2p::service::new_service(
             chain_config.consensus_parameters.chain_id(),
             p2p_config,
-            database.on_chain().clone(),
+            database
+                .on_chain()
+                .clone()
+                .with_access_context(AccessContext::Sync),
             importer_adapter.clone(),
         )
     });
@@ -135,7 +160,10 @@ pub fn init_sub_services(
     let gas_price_provider = StaticGasPrice::new(config.static_gas_price);
     let txpool = fuel_core_txpool::new_service(
         config.txpool.clone(),
-        database.on_chain().clone(),
+        database
+            .on_chain()
+            .clone()
+            .with_access_context(AccessContext::TxPool),
         importer_adapter.clone(),
         p2p_adapter.clone(),
         last_height,
@@ -145,7 +173,10 @@ pub fn init_sub_services(
 
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
-        view_provider: database.on_chain().clone(),
+        view_provider: database
+            .on_chain()
+            .clone()
+            .with_access_context(AccessContext::Executor),
         txpool: tx_pool_adapter.clone(),
         executor: Arc::new(executor),
         relayer: Box::new(relayer_adapter.clone()),
@@ -157,7 +188,7 @@ pub fn init_sub_services(
     let poa_config: fuel_core_poa::Config = config.into();
     let mut production_enabled = !matches!(poa_config.trigger, Trigger::Never);
 
-    if !production_enabled && config.debug {
+    if !production_enabled && config.debug && !config.is_read_replica() {
         production_enabled = true;
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
@@ -194,13 +225,23 @@ pub fn init_sub_services(
         chain_config.consensus_parameters.clone(),
         config.debug,
     )
-    .data(database.on_chain().clone());
+    .data(
+        database
+            .on_chain()
+            .clone()
+            .with_access_context(AccessContext::GraphQL),
+    )
+    .data(database.clone());
 
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         tx_pool_adapter.clone(),
         importer_adapter.clone(),
-        database.off_chain().clone(),
+        database
+            .off_chain()
+            .clone()
+            .with_access_context(AccessContext::GraphQL),
         chain_config.consensus_parameters.chain_id(),
+        *chain_config.consensus_parameters.base_asset_id(),
     );
 
     let chain_config = config.snapshot_reader.chain_config();
@@ -214,22 +255,61 @@ pub fn init_sub_services(
         chain_name: chain_config.chain_name.clone(),
         consensus_parameters: chain_config.consensus_parameters.clone(),
         consensus_key: config.consensus_key.clone(),
+        backup_directory: config.backup_directory.clone(),
     };
 
+    // The read replica follows the blocks of the primary instead of the own importer.
+    let read_replica = config.is_read_replica().then(|| {
+        super::read_replica::new_service(
+            database.clone(),
+            config.read_replica_catch_up_interval,
+        )
+    });
+    let block_importer: fuel_core_graphql_api::api_service::BlockImporter =
+        match &read_replica {
+            Some(read_replica) => {
+                Box::new(ReadReplicaBlockImporter::new(read_replica.shared.clone()))
+            }
+            None => Box::new(GraphQLBlockImporterAdapter::new(
+                importer_adapter.clone(),
+                graphql_worker.shared.clone(),
+            )),
+        };
+
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
         graphql_config,
         schema,
-        database.on_chain().clone(),
-        database.off_chain().clone(),
-        Box::new(tx_pool_adapter),
+        database
+            .on_chain()
+            .clone()
+            .with_access_context(AccessContext::GraphQL),
+        database
+            .off_chain()
+            .clone()
+            .with_access_context(AccessContext::GraphQL),
+        // The read replica doesn't accept transactions.
+        if config.is_read_replica() {
+            Box::new(ReadReplicaTxPool)
+        } else {
+            Box::new(tx_pool_adapter)
+        },
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
+        block_importer,
         Box::new(gas_price_provider),
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
 
+    let state_pruning = config.state_pruning.map(|window| {
+        super::state_pruning::new_service(
+            database.clone(),
+            importer_adapter.events(),
+            window,
+        )
+    });
+
     let shared = SharedState {
         poa_adapter,
         txpool_shared_state: txpool.shared.clone(),
@@ -243,6 +323,12 @@ pub fn init_sub_services(
         config: config.clone(),
     };
 
+    if let Some(read_replica) = read_replica {
+        // The read replica only serves the GraphQL API over the databases of the primary.
+        let services: SubServices = vec![Box::new(graph_ql), Box::new(read_replica)];
+        return Ok((services, shared))
+    }
+
     #[allow(unused_mut)]
     // `FuelService` starts and shutdowns all sub-services in the `services` order
     let mut services: SubServices = vec![
@@ -270,5 +356,9 @@ pub fn init_sub_services(
 
     services.push(Box::new(graphql_worker));
 
+    if let Some(state_pruning) = state_pruning {
+        services.push(Box::new(state_pruning));
+    }
+
     Ok((services, shared))
 }
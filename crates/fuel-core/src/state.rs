@@ -1,4 +1,7 @@
-use crate::database::database_description::DatabaseDescription;
+use crate::{
+    database::database_description::DatabaseDescription,
+    state::instrumented::AccessContext,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -18,10 +21,17 @@ use fuel_core_storage::{
 };
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
+pub mod column_options;
+pub mod historical;
 pub mod in_memory;
+pub mod instrumented;
+pub mod read_cache;
+#[cfg(feature = "redb")]
+pub mod redb;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
 
@@ -38,6 +48,32 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
         height: Option<Height>,
         changes: Changes,
     ) -> StorageResult<()>;
+
+    /// Returns the read-only view of the storage pinned to its current state.
+    /// Changes committed after the call are not visible through the view.
+    fn latest_view(
+        &self,
+    ) -> StorageResult<Arc<dyn TransactableStorage<Height, Column = Self::Column>>>;
+
+    /// Creates a consistent copy of the storage in the `path`.
+    /// The copy can be opened later as a regular database.
+    fn checkpoint(&self, path: &Path) -> StorageResult<()>;
+
+    /// Makes visible the changes committed by the primary instance of the storage.
+    /// Returns `true` if the primary committed new changes since the previous call.
+    /// Does nothing if the storage isn't a secondary instance.
+    fn catch_up_with_primary(&self) -> StorageResult<bool> {
+        Ok(false)
+    }
+
+    /// Returns the storage that attributes accesses to the `context`.
+    /// It is `None` if the storage doesn't measure accesses.
+    fn with_access_context(
+        &self,
+        _: AccessContext,
+    ) -> Option<Arc<dyn TransactableStorage<Height, Column = Self::Column>>> {
+        None
+    }
 }
 
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
@@ -50,6 +86,31 @@ where
     fn commit_changes(&self, _: Option<Height>, _: Changes) -> StorageResult<()> {
         unimplemented!()
     }
+
+    fn latest_view(
+        &self,
+    ) -> StorageResult<Arc<dyn TransactableStorage<Height, Column = Self::Column>>> {
+        // The transaction isn't shared, so it can't be changed while the view exists.
+        // But the view would require a copy of all changes, and tests don't need it.
+        Err(anyhow::anyhow!("The storage transaction doesn't support views").into())
+    }
+
+    fn checkpoint(&self, _: &Path) -> StorageResult<()> {
+        Err(anyhow::anyhow!("The storage transaction doesn't support checkpoints").into())
+    }
+}
+
+/// Returns the smallest key that is greater than all keys with the `prefix`.
+/// The `None` means overflow, so there is not following prefix.
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
+pub(crate) fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
+    for byte in prefix.iter_mut().rev() {
+        if let Some(new_byte) = byte.checked_add(1) {
+            *byte = new_byte;
+            return Some(prefix);
+        }
+    }
+    None
 }
 
 /// A type that allows to iterate over the `Changes`.
@@ -0,0 +1,209 @@
+use crate::database::{
+    database_description::DatabaseDescription,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::kv_store::StorageColumn;
+use std::collections::BTreeMap;
+
+/// The compression algorithm applied to the data blocks of the column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    None,
+    Snappy,
+    #[default]
+    Lz4,
+    Zstd,
+}
+
+/// The compaction style of the column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompactionStyle {
+    /// Optimized for reads and space amplification.
+    #[default]
+    Level,
+    /// Optimized for writes, at the cost of reads and space amplification.
+    Universal,
+}
+
+/// The tuning options of the column in the RocksDB and the in-process read cache.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnOptions {
+    pub compression: Compression,
+    pub compaction_style: CompactionStyle,
+    /// The number of bits per key in the bloom filter. `0` disables the filter.
+    pub bloom_filter_bits_per_key: f64,
+    /// The length of the key prefix used by the prefix extractor. `0` disables the
+    /// extractor. It speeds up iteration over the keys with the same prefix.
+    pub prefix_length: usize,
+    /// The weight of the column in the block cache. The block cache is split between
+    /// columns proportionally to their weights. `0` disables the cache for the column.
+    pub block_cache_weight: u32,
+    /// The size in bytes of the in-process LRU cache of the values of the column.
+    /// Unlike the block cache, it stores the raw values of separate keys, so a hit
+    /// skips the RocksDB lookup. `0` disables the cache.
+    pub read_cache_size: usize,
+}
+
+impl Default for ColumnOptions {
+    fn default() -> Self {
+        Self {
+            compression: Compression::Lz4,
+            compaction_style: CompactionStyle::Level,
+            bloom_filter_bits_per_key: 10.0,
+            prefix_length: 0,
+            block_cache_weight: 1,
+            read_cache_size: 0,
+        }
+    }
+}
+
+impl ColumnOptions {
+    /// Options for the columns that are rarely read, like the history of the state.
+    pub fn cold() -> Self {
+        Self {
+            compression: Compression::Zstd,
+            bloom_filter_bits_per_key: 0.0,
+            block_cache_weight: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Options for the columns iterated by the prefix of the `length`.
+    pub fn with_prefix(length: usize) -> Self {
+        Self {
+            prefix_length: length,
+            ..Default::default()
+        }
+    }
+
+    /// Overrides the options with the values set in the `profile`.
+    pub fn with_profile(self, profile: &ColumnProfile) -> Self {
+        Self {
+            compression: profile.compression.unwrap_or(self.compression),
+            compaction_style: profile.compaction_style.unwrap_or(self.compaction_style),
+            bloom_filter_bits_per_key: profile
+                .bloom_filter_bits_per_key
+                .unwrap_or(self.bloom_filter_bits_per_key),
+            prefix_length: profile.prefix_length.unwrap_or(self.prefix_length),
+            block_cache_weight: profile
+                .block_cache_weight
+                .unwrap_or(self.block_cache_weight),
+            read_cache_size: profile.read_cache_size.unwrap_or(self.read_cache_size),
+        }
+    }
+}
+
+/// The user-defined options of the column. Unset fields keep the default
+/// values tuned for the column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ColumnProfile {
+    pub compression: Option<Compression>,
+    pub compaction_style: Option<CompactionStyle>,
+    pub bloom_filter_bits_per_key: Option<f64>,
+    pub prefix_length: Option<usize>,
+    pub block_cache_weight: Option<u32>,
+    pub read_cache_size: Option<usize>,
+}
+
+/// The profiles of the columns of one database by the names of the columns.
+pub type DatabaseColumnProfiles = BTreeMap<String, ColumnProfile>;
+
+/// The profiles of the columns of all databases.
+///
+/// ```toml
+/// [on_chain.ContractsRawCode]
+/// compression = "zstd"
+/// block_cache_weight = 4
+/// read_cache_size = 134217728
+///
+/// [off_chain.OwnedCoins]
+/// bloom_filter_bits_per_key = 16
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnProfiles {
+    pub on_chain: DatabaseColumnProfiles,
+    pub off_chain: DatabaseColumnProfiles,
+    pub relayer: DatabaseColumnProfiles,
+}
+
+/// Returns the options of all columns of the database. The default options of
+/// each column are overridden by the `profiles`.
+pub fn columns_options<Description>(
+    profiles: &DatabaseColumnProfiles,
+) -> DatabaseResult<Vec<(Description::Column, ColumnOptions)>>
+where
+    Description: DatabaseDescription,
+{
+    let columns = enum_iterator::all::<Description::Column>().collect::<Vec<_>>();
+
+    if let Some(unknown) = profiles
+        .keys()
+        .find(|name| !columns.iter().any(|column| column.name() == name.as_str()))
+    {
+        return Err(DatabaseError::Other(anyhow::anyhow!(
+            "The `{}` database doesn't have the `{unknown}` column",
+            Description::name()
+        )))
+    }
+
+    let options = columns
+        .into_iter()
+        .map(|column| {
+            let mut options = Description::column_options(&column);
+            if let Some(profile) = profiles.get(column.name()) {
+                options = options.with_profile(profile);
+            }
+            (column, options)
+        })
+        .collect();
+
+    Ok(options)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::database_description::on_chain::OnChain;
+    use fuel_core_storage::column::Column;
+
+    #[test]
+    fn columns_options__profile_overrides_only_set_fields() {
+        // Given
+        let profile = ColumnProfile {
+            compression: Some(Compression::Zstd),
+            ..Default::default()
+        };
+        let profiles =
+            DatabaseColumnProfiles::from([("ContractsState".to_string(), profile)]);
+
+        // When
+        let options = columns_options::<OnChain>(&profiles).unwrap();
+
+        // Then
+        let (_, options) = options
+            .into_iter()
+            .find(|(column, _)| *column == Column::ContractsState)
+            .unwrap();
+        assert_eq!(options.compression, Compression::Zstd);
+        assert_eq!(options.prefix_length, 32);
+    }
+
+    #[test]
+    fn columns_options__fails_for_unknown_column() {
+        // Given
+        let profiles =
+            DatabaseColumnProfiles::from([("Unknown".to_string(), Default::default())]);
+
+        // When
+        let result = columns_options::<OnChain>(&profiles);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    database::database_description::DatabaseDescription,
+    state::{
+        DataSource,
+        TransactableStorage,
+    },
+};
+use fuel_core_storage::{
+    iter::{
+        iterator,
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    transactional::Changes,
+    Result as StorageResult,
+};
+use itertools::{
+    EitherOrBoth,
+    Itertools,
+};
+use std::{
+    fmt::Debug,
+    path::Path,
+    sync::Arc,
+};
+
+/// The read-only view of the data source at a previous height.
+/// The `overlay` contains the changes required to revert
+/// the latest state of the `inner` data source to that height.
+#[derive(Debug, Clone)]
+pub struct HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    inner: DataSource<Description>,
+    overlay: Arc<Changes>,
+}
+
+impl<Description> HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    pub fn new(inner: DataSource<Description>, overlay: Changes) -> Self {
+        Self {
+            inner,
+            overlay: Arc::new(overlay),
+        }
+    }
+}
+
+impl<Description> KeyValueInspect for HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        let operation = self
+            .overlay
+            .get(&column.id())
+            .and_then(|operations| operations.get(key));
+
+        match operation {
+            Some(WriteOperation::Insert(value)) => Ok(Some(value.clone())),
+            Some(WriteOperation::Remove) => Ok(None),
+            None => self.inner.get(key, column),
+        }
+    }
+}
+
+impl<Description> IterableStore for HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let inner = self.inner.iter_store(column, prefix, start, direction);
+
+        let Some(operations) = self.overlay.get(&column.id()) else {
+            return inner
+        };
+
+        let overlay = iterator(operations, prefix, start, direction)
+            .map(|(key, operation)| (key.clone(), operation.clone()))
+            .collect::<Vec<_>>();
+
+        inner
+            .merge_join_by(overlay, move |item, (overlay_key, _)| {
+                let Ok((key, _)) = item else {
+                    // Errors are propagated as soon as possible.
+                    return core::cmp::Ordering::Less
+                };
+                match direction {
+                    IterDirection::Forward => key.cmp(overlay_key),
+                    IterDirection::Reverse => overlay_key.cmp(key),
+                }
+            })
+            .filter_map(|either| match either {
+                EitherOrBoth::Left(item) => Some(item),
+                EitherOrBoth::Right((key, operation))
+                | EitherOrBoth::Both(_, (key, operation)) => match operation {
+                    WriteOperation::Insert(value) => Some(Ok((key, value))),
+                    WriteOperation::Remove => None,
+                },
+            })
+            .into_boxed()
+    }
+}
+
+impl<Description> TransactableStorage<Description::Height> for HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn commit_changes(
+        &self,
+        _: Option<Description::Height>,
+        _: Changes,
+    ) -> StorageResult<()> {
+        Err(anyhow::anyhow!("The historical view of the database is read-only").into())
+    }
+
+    fn latest_view(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        // The inner data source is already pinned, so the view never changes.
+        Ok(Arc::new(self.clone()))
+    }
+
+    fn checkpoint(&self, _: &Path) -> StorageResult<()> {
+        Err(
+            anyhow::anyhow!("The historical view of the database can't be checkpointed")
+                .into(),
+        )
+    }
+}
@@ -28,15 +28,24 @@ use fuel_core_storage::{
 use std::{
     collections::BTreeMap,
     fmt::Debug,
-    sync::Mutex,
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
 };
 
+type ColumnData = BTreeMap<Vec<u8>, Value>;
+
 #[derive(Debug)]
 pub struct MemoryStore<Description = OnChain>
 where
     Description: DatabaseDescription,
 {
-    inner: Vec<Mutex<BTreeMap<Vec<u8>, Value>>>,
+    // The columns are shared copy-on-write with the views created from the store.
+    inner: Vec<Mutex<Arc<ColumnData>>>,
+    // The views returned by the `latest_view` are read-only.
+    read_only: bool,
     _marker: core::marker::PhantomData<Description>,
 }
 
@@ -48,8 +57,9 @@ where
         use strum::EnumCount;
         Self {
             inner: (0..Description::Column::COUNT)
-                .map(|_| Mutex::new(BTreeMap::new()))
+                .map(|_| Mutex::new(Arc::new(BTreeMap::new())))
                 .collect(),
+            read_only: false,
             _marker: Default::default(),
         }
     }
@@ -72,7 +82,7 @@ where
             (kv.0.clone(), kv.1.clone())
         }
 
-        let collection: Vec<_> = iterator(&lock, prefix, start, direction)
+        let collection: Vec<_> = iterator(lock.as_ref(), prefix, start, direction)
             .map(clone)
             .collect();
 
@@ -119,24 +129,70 @@ where
         _: Option<Description::Height>,
         changes: Changes,
     ) -> StorageResult<()> {
-        for (column, btree) in changes.into_iter() {
-            let mut lock = self.inner[column as usize]
-                .lock()
-                .map_err(|e| anyhow::anyhow!("The lock is poisoned: {}", e))?;
+        if self.read_only {
+            return Err(anyhow::anyhow!("The view of the memory store is read-only").into())
+        }
 
+        // Lock all affected columns in ascending order, so the views
+        // observe either none or all of the changes.
+        let mut changes = changes.into_iter().collect::<Vec<_>>();
+        changes.sort_by_key(|(column, _)| *column);
+        let mut locks = changes
+            .iter()
+            .map(|(column, _)| {
+                self.inner[*column as usize]
+                    .lock()
+                    .map_err(|e| anyhow::anyhow!("The lock is poisoned: {}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (lock, (_, btree)) in locks.iter_mut().zip(changes) {
+            // Clones the column only if some view still references it.
+            let column = Arc::make_mut(lock);
             for (key, operation) in btree.into_iter() {
                 match operation {
                     WriteOperation::Insert(value) => {
-                        lock.insert(key, value);
+                        column.insert(key, value);
                     }
                     WriteOperation::Remove => {
-                        lock.remove(&key);
+                        column.remove(&key);
                     }
                 }
             }
         }
         Ok(())
     }
+
+    fn latest_view(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        // Holding all locks at the same time gives a consistent view across columns.
+        let locks = self
+            .inner
+            .iter()
+            .map(|column| {
+                column
+                    .lock()
+                    .map_err(|e| anyhow::anyhow!("The lock is poisoned: {}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let inner = locks
+            .iter()
+            .map(|column| Mutex::new(Arc::clone(column)))
+            .collect();
+
+        Ok(Arc::new(Self {
+            inner,
+            read_only: true,
+            _marker: Default::default(),
+        }))
+    }
+
+    fn checkpoint(&self, _: &Path) -> StorageResult<()> {
+        Err(anyhow::anyhow!("The memory store doesn't support checkpoints").into())
+    }
 }
 
 #[cfg(test)]
@@ -145,9 +201,11 @@ mod tests {
     use fuel_core_storage::{
         column::Column,
         kv_store::KeyValueMutate,
-        transactional::ReadTransaction,
+        transactional::{
+            Modifiable,
+            ReadTransaction,
+        },
     };
-    use std::sync::Arc;
 
     impl<Description> KeyValueMutate for MemoryStore<Description>
     where
@@ -162,7 +220,7 @@ mod tests {
             let mut transaction = self.read_transaction();
             let len = transaction.write(key, column, buf)?;
             let changes = transaction.into_changes();
-            self.commit_changes(Default::default(), changes)?;
+            TransactableStorage::commit_changes(self, None, changes)?;
             Ok(len)
         }
 
@@ -170,81 +228,56 @@ mod tests {
             let mut transaction = self.read_transaction();
             transaction.delete(key, column)?;
             let changes = transaction.into_changes();
-            self.commit_changes(Default::default(), changes)?;
+            TransactableStorage::commit_changes(self, None, changes)?;
             Ok(())
         }
     }
 
-    #[test]
-    fn can_use_unit_value() {
-        let key = vec![0x00];
-
-        let mut db = MemoryStore::<OnChain>::default();
-        let expected = Arc::new(vec![]);
-        db.put(&key.to_vec(), Column::Metadata, expected.clone())
-            .unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        assert!(db.exists(&key, Column::Metadata).unwrap());
-
-        assert_eq!(
-            db.iter_all(Column::Metadata, None, None, IterDirection::Forward)
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap(),
-            vec![(key.clone(), expected.clone())]
-        );
-
-        assert_eq!(db.take(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        assert!(!db.exists(&key, Column::Metadata).unwrap());
+    impl<Description> Modifiable for MemoryStore<Description>
+    where
+        Description: DatabaseDescription,
+    {
+        fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+            TransactableStorage::commit_changes(self, None, changes)
+        }
     }
 
-    #[test]
-    fn can_use_unit_key() {
-        let key: Vec<u8> = Vec::with_capacity(0);
+    fuel_core_storage::kv_store_conformance_tests!(MemoryStore::<OnChain>::default());
 
+    #[test]
+    fn latest_view_does_not_see_later_commits() {
+        // Given
+        let key = vec![0xA, 0xB, 0xC];
         let mut db = MemoryStore::<OnChain>::default();
-        let expected = Arc::new(vec![1, 2, 3]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+        let view = db.latest_view().unwrap();
 
-        assert!(db.exists(&key, Column::Metadata).unwrap());
+        // When
+        db.put(&key, Column::Metadata, Arc::new(vec![2])).unwrap();
+        db.put(&key, Column::Coins, Arc::new(vec![3])).unwrap();
 
+        // Then
         assert_eq!(
-            db.iter_all(Column::Metadata, None, None, IterDirection::Forward)
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap(),
-            vec![(key.clone(), expected.clone())]
+            view.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(view.get(&key, Column::Coins).unwrap(), None);
+        assert_eq!(
+            db.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![2]))
         );
-
-        assert_eq!(db.take(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
 
     #[test]
-    fn can_use_unit_key_and_value() {
-        let key: Vec<u8> = Vec::with_capacity(0);
-
-        let mut db = MemoryStore::<OnChain>::default();
-        let expected = Arc::new(vec![]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        assert!(db.exists(&key, Column::Metadata).unwrap());
-
-        assert_eq!(
-            db.iter_all(Column::Metadata, None, None, IterDirection::Forward)
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap(),
-            vec![(key.clone(), expected.clone())]
-        );
+    fn latest_view_is_read_only() {
+        // Given
+        let db = MemoryStore::<OnChain>::default();
+        let view = db.latest_view().unwrap();
 
-        assert_eq!(db.take(&key, Column::Metadata).unwrap().unwrap(), expected);
+        // When
+        let result = view.commit_changes(None, Changes::default());
 
-        assert!(!db.exists(&key, Column::Metadata).unwrap());
+        // Then
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,637 @@
+//! The instrumentation of the storage that measures accesses to its columns.
+//!
+//! The reads and the committed writes are counted per column and per
+//! [`AccessContext`], the component that accesses the storage. Optionally,
+//! each access is recorded into the trace, which is written into
+//! the `<height>.csv` file when the height is committed.
+
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        DatabaseHeight,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        DataSource,
+        TransactableStorage,
+    },
+};
+use fuel_core_metrics::storage_access_metrics::{
+    storage_access_metrics,
+    ColumnAccessMetrics,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    transactional::Changes,
+    Error as StorageError,
+    Result as StorageResult,
+};
+use std::{
+    fmt::Debug,
+    fs::File,
+    io::{
+        BufWriter,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The maximum number of accesses recorded into the trace of one height.
+/// The accesses above the limit are only counted.
+const MAX_TRACE_RECORDS: usize = 1_000_000;
+
+/// The component on behalf of which the storage is accessed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AccessContext {
+    /// The execution of blocks, including their production.
+    Executor,
+    /// The validation and the commit of imported blocks.
+    Importer,
+    /// The GraphQL API and its worker.
+    GraphQL,
+    TxPool,
+    /// The synchronization with peers.
+    Sync,
+    Relayer,
+    #[default]
+    Other,
+}
+
+impl AccessContext {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Executor => "executor",
+            Self::Importer => "importer",
+            Self::GraphQL => "graphql",
+            Self::TxPool => "txpool",
+            Self::Sync => "sync",
+            Self::Relayer => "relayer",
+            Self::Other => "other",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AccessRecord {
+    context: AccessContext,
+    operation: &'static str,
+    column: &'static str,
+    key: Vec<u8>,
+    bytes: usize,
+    duration: Duration,
+}
+
+/// The column, its metrics, the operation, the key for the trace and the size
+/// of the value of the committed write.
+type CommittedWrite<'a> = (
+    &'static str,
+    &'a ColumnAccessMetrics,
+    &'static str,
+    Option<Vec<u8>>,
+    usize,
+);
+
+#[derive(Debug, Default)]
+struct Trace {
+    records: Vec<AccessRecord>,
+    dropped: usize,
+}
+
+/// Records accesses to the storage and writes them into the file per committed height.
+#[derive(Debug)]
+struct AccessTracer {
+    directory: PathBuf,
+    trace: Mutex<Trace>,
+}
+
+impl AccessTracer {
+    fn new(directory: &Path) -> DatabaseResult<Self> {
+        std::fs::create_dir_all(directory).map_err(|e| {
+            DatabaseError::Other(anyhow::anyhow!(
+                "Failed to create the access trace directory {directory:?}: {e}"
+            ))
+        })?;
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            trace: Mutex::new(Trace::default()),
+        })
+    }
+
+    fn record(&self, record: AccessRecord) -> StorageResult<()> {
+        let mut trace = lock(&self.trace)?;
+        if trace.records.len() < MAX_TRACE_RECORDS {
+            trace.records.push(record);
+        } else {
+            trace.dropped = trace.dropped.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    /// Writes the recorded accesses into the file of the `height` and starts a new trace.
+    fn flush(&self, height: u64) -> StorageResult<()> {
+        let trace = core::mem::take(&mut *lock(&self.trace)?);
+        self.write(height, trace).map_err(|e| {
+            StorageError::Other(anyhow::anyhow!(
+                "Failed to write the access trace of the height {height}: {e}"
+            ))
+        })
+    }
+
+    fn write(&self, height: u64, trace: Trace) -> std::io::Result<()> {
+        let file = File::create(self.directory.join(format!("{height}.csv")))?;
+        let mut writer = BufWriter::new(file);
+        writeln!(writer, "context,operation,column,key,bytes,nanos")?;
+        for record in trace.records {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{}",
+                record.context.as_str(),
+                record.operation,
+                record.column,
+                hex::encode(&record.key),
+                record.bytes,
+                record.duration.as_nanos()
+            )?;
+        }
+        if trace.dropped > 0 {
+            writeln!(writer, "# {} accesses were not recorded", trace.dropped)?;
+        }
+        writer.flush()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> StorageResult<MutexGuard<'_, T>> {
+    mutex
+        .lock()
+        .map_err(|e| StorageError::Other(anyhow::anyhow!("The lock is poisoned: {}", e)))
+}
+
+/// Reports accesses of one context to the metrics and the trace.
+#[derive(Debug)]
+struct Recorder<Description>
+where
+    Description: DatabaseDescription,
+{
+    context: AccessContext,
+    /// The metrics of the columns indexed by the id of the column.
+    metrics: Arc<Vec<Option<(&'static str, ColumnAccessMetrics)>>>,
+    tracer: Option<Arc<AccessTracer>>,
+    _marker: core::marker::PhantomData<Description>,
+}
+
+impl<Description> Clone for Recorder<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context,
+            metrics: self.metrics.clone(),
+            tracer: self.tracer.clone(),
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<Description> Recorder<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn new(context: AccessContext, tracer: Option<Arc<AccessTracer>>) -> Self {
+        use strum::EnumCount;
+        let mut metrics = vec![None; Description::Column::COUNT];
+        for column in enum_iterator::all::<Description::Column>() {
+            let column_metrics = storage_access_metrics().column(
+                Description::name(),
+                column.name(),
+                context.as_str(),
+            );
+            metrics[column.as_usize()] = Some((column.name(), column_metrics));
+        }
+
+        Self {
+            context,
+            metrics: Arc::new(metrics),
+            tracer,
+            _marker: Default::default(),
+        }
+    }
+
+    fn column(&self, id: usize) -> Option<&(&'static str, ColumnAccessMetrics)> {
+        self.metrics.get(id).and_then(Option::as_ref)
+    }
+
+    fn read(
+        &self,
+        operation: &'static str,
+        column: Description::Column,
+        key: &[u8],
+        bytes: usize,
+        duration: Duration,
+    ) -> StorageResult<()> {
+        if let Some((_, metrics)) = self.column(column.as_usize()) {
+            metrics.read(bytes, duration);
+        }
+        if let Some(tracer) = &self.tracer {
+            tracer.record(AccessRecord {
+                context: self.context,
+                operation,
+                column: column.name(),
+                key: key.to_vec(),
+                bytes,
+                duration,
+            })?;
+        }
+        Ok(())
+    }
+
+    fn measure_read<T>(
+        &self,
+        operation: &'static str,
+        column: Description::Column,
+        key: &[u8],
+        read: impl FnOnce() -> StorageResult<T>,
+        bytes: impl FnOnce(&T) -> usize,
+    ) -> StorageResult<T> {
+        let start = Instant::now();
+        let result = read();
+        let duration = start.elapsed();
+        if let Ok(value) = &result {
+            self.read(operation, column, key, bytes(value), duration)?;
+        }
+        result
+    }
+
+    fn trace_commit(
+        &self,
+        tracer: &AccessTracer,
+        height: Option<Description::Height>,
+        writes: Vec<CommittedWrite>,
+        total_bytes: usize,
+        duration: Duration,
+    ) -> StorageResult<()>
+    where
+        Description::Height: DatabaseHeight,
+    {
+        for (column, _, operation, key, bytes) in writes {
+            if let Some(key) = key {
+                tracer.record(AccessRecord {
+                    context: self.context,
+                    operation,
+                    column,
+                    key,
+                    bytes,
+                    duration: Duration::ZERO,
+                })?;
+            }
+        }
+        tracer.record(AccessRecord {
+            context: self.context,
+            operation: "commit",
+            column: "",
+            key: vec![],
+            bytes: total_bytes,
+            duration,
+        })?;
+
+        if let Some(height) = height {
+            tracer.flush(height.as_u64())?;
+        }
+        Ok(())
+    }
+}
+
+/// The storage decorator that measures accesses to the columns of the `storage`.
+#[derive(Debug)]
+pub struct InstrumentedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    storage: DataSource<Description>,
+    recorder: Recorder<Description>,
+}
+
+impl<Description> InstrumentedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Creates the instrumented `storage`. If the `trace_directory` is set,
+    /// the accesses are written into it per committed height.
+    pub fn new(
+        storage: DataSource<Description>,
+        trace_directory: Option<&Path>,
+    ) -> DatabaseResult<Self> {
+        let tracer = trace_directory
+            .map(AccessTracer::new)
+            .transpose()?
+            .map(Arc::new);
+
+        Ok(Self {
+            storage,
+            recorder: Recorder::new(AccessContext::default(), tracer),
+        })
+    }
+}
+
+impl<Description> KeyValueInspect for InstrumentedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        self.recorder.measure_read(
+            "exists",
+            column,
+            key,
+            || self.storage.as_ref().exists(key, column),
+            |_| 0,
+        )
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        self.recorder.measure_read(
+            "size_of_value",
+            column,
+            key,
+            || self.storage.as_ref().size_of_value(key, column),
+            |_| 0,
+        )
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.recorder.measure_read(
+            "get",
+            column,
+            key,
+            || self.storage.as_ref().get(key, column),
+            |value| value.as_ref().map(|value| value.len()).unwrap_or_default(),
+        )
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.recorder.measure_read(
+            "read",
+            column,
+            key,
+            || self.storage.as_ref().read(key, column, buf),
+            |read| read.unwrap_or_default(),
+        )
+    }
+}
+
+impl<Description> IterableStore for InstrumentedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let mut iter = self
+            .storage
+            .as_ref()
+            .iter_store(column, prefix, start, direction);
+        let recorder = self.recorder.clone();
+
+        core::iter::from_fn(move || {
+            let start = Instant::now();
+            let item = iter.next()?;
+            let duration = start.elapsed();
+            if let Ok((key, value)) = &item {
+                if let Err(e) = recorder.read("iter", column, key, value.len(), duration)
+                {
+                    return Some(Err(e))
+                }
+            }
+            Some(item)
+        })
+        .into_boxed()
+    }
+}
+
+impl<Description> TransactableStorage<Description::Height>
+    for InstrumentedStorage<Description>
+where
+    Description: DatabaseDescription,
+    Description::Height: DatabaseHeight,
+{
+    fn commit_changes(
+        &self,
+        height: Option<Description::Height>,
+        changes: Changes,
+    ) -> StorageResult<()> {
+        let mut writes = vec![];
+        for (column, operations) in changes.iter() {
+            let Some((column, metrics)) = self.recorder.column(*column as usize) else {
+                continue
+            };
+            for (key, operation) in operations {
+                let (operation, bytes) = match operation {
+                    WriteOperation::Insert(value) => ("insert", value.len()),
+                    WriteOperation::Remove => ("remove", 0),
+                };
+                // The keys are needed only for the trace.
+                let key = self.recorder.tracer.as_ref().map(|_| key.clone());
+                writes.push((*column, metrics, operation, key, bytes));
+            }
+        }
+
+        let start = Instant::now();
+        self.storage.commit_changes(height, changes)?;
+        let duration = start.elapsed();
+
+        let mut total_bytes = 0usize;
+        for (_, metrics, _, _, bytes) in writes.iter() {
+            metrics.write(*bytes);
+            total_bytes = total_bytes.saturating_add(*bytes);
+        }
+
+        if let Some(tracer) = &self.recorder.tracer {
+            // The changes are already committed, so the failure of the trace is only logged.
+            if let Err(e) =
+                self.recorder
+                    .trace_commit(tracer, height, writes, total_bytes, duration)
+            {
+                tracing::warn!("Failed to trace the commit: {e}");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn latest_view(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        Ok(Arc::new(Self {
+            storage: self.storage.latest_view()?,
+            recorder: self.recorder.clone(),
+        }))
+    }
+
+    fn checkpoint(&self, path: &Path) -> StorageResult<()> {
+        self.storage.checkpoint(path)
+    }
+
+    fn catch_up_with_primary(&self) -> StorageResult<bool> {
+        self.storage.catch_up_with_primary()
+    }
+
+    fn with_access_context(
+        &self,
+        context: AccessContext,
+    ) -> Option<Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>>
+    {
+        Some(Arc::new(Self {
+            storage: self.storage.clone(),
+            recorder: Recorder::new(context, self.recorder.tracer.clone()),
+        }))
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::database_description::on_chain::OnChain,
+        state::in_memory::memory_store::MemoryStore,
+    };
+    use fuel_core_storage::column::Column;
+    use fuel_core_types::fuel_types::BlockHeight;
+    use std::collections::BTreeMap;
+
+    const KEY: [u8; 2] = [0xA, 0xB];
+
+    fn instrumented_store(
+        trace_directory: &Path,
+    ) -> Arc<dyn TransactableStorage<BlockHeight, Column = Column>> {
+        let store: DataSource<OnChain> = Arc::new(MemoryStore::<OnChain>::default());
+        Arc::new(
+            InstrumentedStorage::<OnChain>::new(store, Some(trace_directory)).unwrap(),
+        )
+    }
+
+    fn insert() -> Changes {
+        let operation = WriteOperation::Insert(Arc::new(vec![1, 2, 3]));
+        Changes::from([(
+            Column::ContractsRawCode.id(),
+            BTreeMap::from([(KEY.to_vec(), operation)]),
+        )])
+    }
+
+    fn trace(directory: &Path, height: u32) -> Vec<String> {
+        std::fs::read_to_string(directory.join(format!("{height}.csv")))
+            .unwrap()
+            .lines()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn commit_changes__writes_accesses_into_trace_of_height() {
+        // Given
+        let directory = tempfile::TempDir::new().unwrap();
+        let store = instrumented_store(directory.path());
+        let executor = store.with_access_context(AccessContext::Executor).unwrap();
+        executor.get(&KEY, Column::ContractsRawCode).unwrap();
+
+        // When
+        executor
+            .commit_changes(Some(1u32.into()), insert())
+            .unwrap();
+
+        // Then
+        let trace = trace(directory.path(), 1);
+        assert_eq!(trace.len(), 4);
+        assert_eq!(trace[0], "context,operation,column,key,bytes,nanos");
+        assert!(trace[1].starts_with("executor,get,ContractsRawCode,0a0b,0,"));
+        assert_eq!(trace[2], "executor,insert,ContractsRawCode,0a0b,3,0");
+        assert!(trace[3].starts_with("executor,commit,,,3,"));
+    }
+
+    #[test]
+    fn commit_changes__keeps_trace_until_height_is_committed() {
+        // Given
+        let directory = tempfile::TempDir::new().unwrap();
+        let store = instrumented_store(directory.path());
+        let view = store.latest_view().unwrap();
+
+        // When
+        store.commit_changes(None, insert()).unwrap();
+        view.get(&KEY, Column::ContractsRawCode).unwrap();
+        store
+            .commit_changes(Some(1u32.into()), Changes::default())
+            .unwrap();
+
+        // Then
+        let trace = trace(directory.path(), 1);
+        assert_eq!(trace.len(), 5);
+        assert_eq!(trace[1], "other,insert,ContractsRawCode,0a0b,3,0");
+        assert!(trace[2].starts_with("other,commit,,,3,"));
+        assert!(trace[3].starts_with("other,get,ContractsRawCode,0a0b,0,"));
+        assert!(trace[4].starts_with("other,commit,,,0,"));
+    }
+
+    #[test]
+    fn get__fails_if_trace_lock_is_poisoned() {
+        // Given
+        let directory = tempfile::TempDir::new().unwrap();
+        let store: DataSource<OnChain> = Arc::new(MemoryStore::<OnChain>::default());
+        let store =
+            InstrumentedStorage::<OnChain>::new(store, Some(directory.path())).unwrap();
+        let tracer = store.recorder.tracer.clone().unwrap();
+        std::thread::spawn(move || {
+            let _guard = tracer.trace.lock().unwrap();
+            panic!("Poison the lock of the trace");
+        })
+        .join()
+        .expect_err("The thread should panic");
+
+        // When
+        let result = store.get(&KEY, Column::ContractsRawCode);
+
+        // Then
+        result.expect_err("Should fail because the lock of the trace is poisoned");
+    }
+}
@@ -0,0 +1,518 @@
+//! The in-process LRU cache of the values read from the storage.
+//!
+//! The cache is shared between the storage and its views. Each commit increments
+//! the version of the cache twice: before the storage is modified and after
+//! the cached values are updated. The odd version means that the commit is
+//! in progress, and the cache is bypassed until it finishes. The view uses
+//! the cache only while the version matches the version at its creation,
+//! so it never observes values committed after it was created.
+
+use crate::{
+    database::database_description::DatabaseDescription,
+    state::{
+        DataSource,
+        TransactableStorage,
+    },
+};
+use fuel_core_metrics::read_cache_metrics::{
+    read_cache_metrics,
+    ColumnCacheMetrics,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    transactional::Changes,
+    Error as StorageError,
+    Result as StorageResult,
+};
+use lru::LruCache;
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    path::Path,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+};
+
+/// The cached values of one column. The `None` caches the absence of the value.
+#[derive(Debug)]
+struct ColumnCache {
+    entries: LruCache<Vec<u8>, Option<Value>>,
+    /// The total size of the cached keys and values in bytes.
+    size: usize,
+    /// The maximum total size of the cached keys and values in bytes.
+    budget: usize,
+    metrics: ColumnCacheMetrics,
+}
+
+impl ColumnCache {
+    fn new(budget: usize, metrics: ColumnCacheMetrics) -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            size: 0,
+            budget,
+            metrics,
+        }
+    }
+
+    fn entry_size(key: &[u8], value: &Option<Value>) -> usize {
+        let value_size = value.as_ref().map(|value| value.len()).unwrap_or_default();
+        key.len().saturating_add(value_size)
+    }
+
+    /// Inserts the value and evicts the least recently used values above the budget.
+    fn insert(&mut self, key: Vec<u8>, value: Option<Value>) {
+        let size = Self::entry_size(&key, &value);
+        if size > self.budget {
+            self.remove(&key);
+            return
+        }
+
+        if let Some((key, old_value)) = self.entries.push(key, value) {
+            self.size = self.size.saturating_sub(Self::entry_size(&key, &old_value));
+        }
+        self.size = self.size.saturating_add(size);
+        self.evict();
+    }
+
+    /// Replaces the value if it is already cached, without promoting it.
+    fn update(&mut self, key: &[u8], value: Option<Value>) {
+        let size = Self::entry_size(key, &value);
+        let Some(cached) = self.entries.peek_mut(key) else {
+            return
+        };
+        let old_size = Self::entry_size(key, cached);
+        *cached = value;
+        self.size = self.size.saturating_sub(old_size).saturating_add(size);
+        self.evict();
+    }
+
+    /// Evicts the least recently used values until the size fits the budget.
+    fn evict(&mut self) {
+        while self.size > self.budget {
+            let Some((key, value)) = self.entries.pop_lru() else {
+                break
+            };
+            self.size = self.size.saturating_sub(Self::entry_size(&key, &value));
+        }
+        self.metrics.set_size(self.size);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.size = 0;
+        self.metrics.set_size(self.size);
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        if let Some(value) = self.entries.pop(key) {
+            self.size = self.size.saturating_sub(Self::entry_size(key, &value));
+            self.metrics.set_size(self.size);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Cache {
+    /// The even version means that the cached values match the storage.
+    version: AtomicU64,
+    /// Serializes commits, so the version is incremented by one commit at a time.
+    commit_lock: Mutex<()>,
+    columns: HashMap<u32, Mutex<ColumnCache>>,
+}
+
+/// The storage decorator that caches the values of the selected columns in memory.
+/// The changes are visible only if they are committed via the
+/// [`TransactableStorage::commit_changes`] of the decorator.
+#[derive(Debug)]
+pub struct ReadCache<Description>
+where
+    Description: DatabaseDescription,
+{
+    storage: DataSource<Description>,
+    cache: Arc<Cache>,
+    /// The version of the cache at the moment of the view creation.
+    /// It is `None` for the latest state of the storage.
+    pinned_version: Option<u64>,
+}
+
+impl<Description> ReadCache<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Creates the cache of the `storage` with the `budgets` in bytes of the columns.
+    pub fn new(
+        storage: DataSource<Description>,
+        budgets: impl IntoIterator<Item = (Description::Column, usize)>,
+    ) -> Self {
+        let metrics = read_cache_metrics();
+        let columns = budgets
+            .into_iter()
+            .map(|(column, budget)| {
+                let metrics = metrics.column(Description::name(), column.name());
+                (column.id(), Mutex::new(ColumnCache::new(budget, metrics)))
+            })
+            .collect();
+
+        Self {
+            storage,
+            cache: Arc::new(Cache {
+                version: AtomicU64::new(0),
+                commit_lock: Mutex::new(()),
+                columns,
+            }),
+            pinned_version: None,
+        }
+    }
+
+    fn column_cache(&self, column: Description::Column) -> Option<&Mutex<ColumnCache>> {
+        self.cache.columns.get(&column.id())
+    }
+
+    /// Returns the version if the cached values are valid for this storage.
+    fn usable_version(&self) -> Option<u64> {
+        let version = self.cache.version.load(Ordering::SeqCst);
+        let is_committing = version % 2 == 1;
+        let is_outdated = self
+            .pinned_version
+            .map(|pinned_version| pinned_version != version)
+            .unwrap_or(false);
+
+        (!is_committing && !is_outdated).then_some(version)
+    }
+
+    fn get_cached(
+        &self,
+        key: &[u8],
+        column: Description::Column,
+        cache: &Mutex<ColumnCache>,
+    ) -> StorageResult<Option<Value>> {
+        // The version is checked under the lock of the column, because
+        // the commit updates the cached values while holding the lock.
+        let version = {
+            let mut cache = lock(cache)?;
+            let version = self.usable_version();
+            if version.is_some() {
+                if let Some(value) = cache.entries.get(key).cloned() {
+                    cache.metrics.hit();
+                    return Ok(value)
+                }
+            }
+            cache.metrics.miss();
+            version
+        };
+
+        let value = self.storage.as_ref().get(key, column)?;
+
+        if let Some(version) = version {
+            let mut cache = lock(cache)?;
+            // The storage wasn't modified during the read if the version is the same.
+            if self.usable_version() == Some(version) {
+                cache.insert(key.to_vec(), value.clone());
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+impl<Description> KeyValueInspect for ReadCache<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        match self.column_cache(column) {
+            Some(cache) => Ok(self.get_cached(key, column, cache)?.is_some()),
+            None => self.storage.as_ref().exists(key, column),
+        }
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        match self.column_cache(column) {
+            Some(cache) => Ok(self
+                .get_cached(key, column, cache)?
+                .map(|value| value.len())),
+            None => self.storage.as_ref().size_of_value(key, column),
+        }
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        match self.column_cache(column) {
+            Some(cache) => self.get_cached(key, column, cache),
+            None => self.storage.as_ref().get(key, column),
+        }
+    }
+}
+
+impl<Description> IterableStore for ReadCache<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.storage
+            .as_ref()
+            .iter_store(column, prefix, start, direction)
+    }
+}
+
+impl<Description> TransactableStorage<Description::Height> for ReadCache<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn commit_changes(
+        &self,
+        height: Option<Description::Height>,
+        changes: Changes,
+    ) -> StorageResult<()> {
+        if self.pinned_version.is_some() {
+            // The view is read-only, so the underlying storage rejects the changes.
+            return self.storage.commit_changes(height, changes)
+        }
+
+        let updates = changes
+            .iter()
+            .filter(|(column, _)| self.cache.columns.contains_key(column))
+            .map(|(column, tree)| {
+                let values = tree
+                    .iter()
+                    .map(|(key, operation)| {
+                        let value = match operation {
+                            WriteOperation::Insert(value) => Some(value.clone()),
+                            WriteOperation::Remove => None,
+                        };
+                        (key.clone(), value)
+                    })
+                    .collect::<Vec<_>>();
+                (*column, values)
+            })
+            .collect::<Vec<_>>();
+
+        let _guard = lock(&self.cache.commit_lock)?;
+        self.cache.version.fetch_add(1, Ordering::SeqCst);
+        let result = self.storage.commit_changes(height, changes);
+
+        for (column, values) in updates {
+            let Some(cache) = self.cache.columns.get(&column) else {
+                continue
+            };
+            let mut cache = lock(cache)?;
+            for (key, value) in values {
+                if result.is_ok() {
+                    cache.update(&key, value);
+                } else {
+                    // The state of the storage is unknown after the failure.
+                    cache.remove(&key);
+                }
+            }
+        }
+        self.cache.version.fetch_add(1, Ordering::SeqCst);
+
+        result
+    }
+
+    fn latest_view(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        // The version is read before the view is created. If the commit happens
+        // in between, the version never matches again, and the view bypasses the cache.
+        let version = self
+            .pinned_version
+            .unwrap_or_else(|| self.cache.version.load(Ordering::SeqCst));
+        let storage = self.storage.latest_view()?;
+
+        Ok(Arc::new(Self {
+            storage,
+            cache: self.cache.clone(),
+            pinned_version: Some(version),
+        }))
+    }
+
+    fn checkpoint(&self, path: &Path) -> StorageResult<()> {
+        self.storage.checkpoint(path)
+    }
+
+    fn catch_up_with_primary(&self) -> StorageResult<bool> {
+        if self.pinned_version.is_some() {
+            return self.storage.catch_up_with_primary()
+        }
+
+        // The cache is bypassed while the changes of the primary become visible.
+        let _guard = lock(&self.cache.commit_lock)?;
+        self.cache.version.fetch_add(1, Ordering::SeqCst);
+        let result = self.storage.catch_up_with_primary();
+        // The keys changed by the primary are unknown, so the cached values are dropped
+        // only if the primary committed something since the previous catch up.
+        if !matches!(result, Ok(false)) {
+            for cache in self.cache.columns.values() {
+                lock(cache)?.clear();
+            }
+        }
+        self.cache.version.fetch_add(1, Ordering::SeqCst);
+
+        result
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> StorageResult<MutexGuard<'_, T>> {
+    mutex
+        .lock()
+        .map_err(|e| StorageError::Other(anyhow::anyhow!("The lock is poisoned: {}", e)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::database_description::on_chain::OnChain,
+        state::{
+            in_memory::memory_store::MemoryStore,
+            DataSource,
+        },
+    };
+    use fuel_core_metrics::read_cache_metrics::ColumnCacheMetrics;
+    use fuel_core_storage::column::Column;
+    use std::collections::BTreeMap;
+
+    const COLUMN: Column = Column::ContractsRawCode;
+    const KEY: [u8; 4] = [0xA, 0xB, 0xC, 0xD];
+
+    fn cached_store() -> (DataSource<OnChain>, ReadCache<OnChain>) {
+        let store: DataSource<OnChain> = Arc::new(MemoryStore::<OnChain>::default());
+        let cached = ReadCache::new(store.clone(), [(COLUMN, 1024)]);
+        (store, cached)
+    }
+
+    fn insert(value: u8) -> Changes {
+        let operation = WriteOperation::Insert(Arc::new(vec![value]));
+        Changes::from([(COLUMN.id(), BTreeMap::from([(KEY.to_vec(), operation)]))])
+    }
+
+    fn value(value: u8) -> Option<Value> {
+        Some(Arc::new(vec![value]))
+    }
+
+    #[test]
+    fn get__returns_cached_value() {
+        // Given
+        let (store, cached) = cached_store();
+        cached.commit_changes(None, insert(1)).unwrap();
+        cached.get(&KEY, COLUMN).unwrap();
+
+        // When
+        store.commit_changes(None, insert(2)).unwrap();
+
+        // Then
+        assert_eq!(cached.get(&KEY, COLUMN).unwrap(), value(1));
+    }
+
+    #[test]
+    fn get__returns_value_committed_through_cache() {
+        // Given
+        let (_, cached) = cached_store();
+        cached.commit_changes(None, insert(1)).unwrap();
+        cached.get(&KEY, COLUMN).unwrap();
+
+        // When
+        cached.commit_changes(None, insert(2)).unwrap();
+
+        // Then
+        assert_eq!(cached.get(&KEY, COLUMN).unwrap(), value(2));
+    }
+
+    #[test]
+    fn get__returns_value_committed_after_caching_its_absence() {
+        // Given
+        let (_, cached) = cached_store();
+        assert_eq!(cached.get(&KEY, COLUMN).unwrap(), None);
+
+        // When
+        cached.commit_changes(None, insert(1)).unwrap();
+
+        // Then
+        assert_eq!(cached.get(&KEY, COLUMN).unwrap(), value(1));
+    }
+
+    #[test]
+    fn latest_view__does_not_see_values_committed_after_creation() {
+        // Given
+        let (_, cached) = cached_store();
+        cached.commit_changes(None, insert(1)).unwrap();
+        let view = cached.latest_view().unwrap();
+
+        // When
+        cached.get(&KEY, COLUMN).unwrap();
+        cached.commit_changes(None, insert(2)).unwrap();
+
+        // Then
+        assert_eq!(view.get(&KEY, COLUMN).unwrap(), value(1));
+        assert_eq!(cached.get(&KEY, COLUMN).unwrap(), value(2));
+    }
+
+    #[test]
+    fn catch_up_with_primary__keeps_cached_values_without_new_changes() {
+        // Given
+        let (store, cached) = cached_store();
+        cached.commit_changes(None, insert(1)).unwrap();
+        cached.get(&KEY, COLUMN).unwrap();
+        store.commit_changes(None, insert(2)).unwrap();
+
+        // When
+        let caught_up = cached.catch_up_with_primary().unwrap();
+
+        // Then
+        assert!(!caught_up);
+        assert_eq!(cached.get(&KEY, COLUMN).unwrap(), value(1));
+    }
+
+    #[test]
+    fn insert__evicts_least_recently_used_values_above_budget() {
+        // Given
+        let mut cache = ColumnCache::new(8, ColumnCacheMetrics::default());
+        cache.insert(vec![1], value(1));
+        cache.insert(vec![2], value(2));
+        cache.insert(vec![3], value(3));
+        cache.entries.get(&vec![1]);
+
+        // When
+        cache.insert(vec![4, 4, 4], value(4));
+
+        // Then
+        assert!(cache.entries.contains(&vec![1]));
+        assert!(!cache.entries.contains(&vec![2]));
+        assert!(cache.entries.contains(&vec![3]));
+        assert!(cache.entries.contains(&vec![4, 4, 4]));
+        assert_eq!(cache.size, 8);
+    }
+}
@@ -0,0 +1,598 @@
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        next_prefix,
+        IterDirection,
+        TransactableStorage,
+    },
+};
+use ::redb::{
+    Database,
+    ReadOnlyTable,
+    ReadTransaction,
+    TableDefinition,
+};
+use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    transactional::Changes,
+    Result as StorageResult,
+};
+use std::{
+    fmt,
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    iter,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+use tempfile::TempDir;
+
+type Table = ReadOnlyTable<&'static [u8], &'static [u8]>;
+
+/// The name of the file of the database inside its directory.
+const DATA_FILE: &str = "data.redb";
+
+/// The storage backed by the [redb](https://docs.rs/redb) pure-Rust embedded database.
+/// Each column is stored in a separate table of the same database file,
+/// so the changes of all columns are committed atomically.
+pub struct RedbDb<Description> {
+    // The read transaction pins the snapshot to the state at the moment of its creation.
+    snapshot: Option<Arc<ReadTransaction>>,
+    db: Arc<Database>,
+    // The temporary directory is removed when the last instance is dropped.
+    _tmp_dir: Option<Arc<TempDir>>,
+    _marker: core::marker::PhantomData<Description>,
+}
+
+impl<Description> Debug for RedbDb<Description> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedbDb")
+            .field("db", &self.db)
+            .field("is_snapshot", &self.snapshot.is_some())
+            .finish()
+    }
+}
+
+impl<Description> RedbDb<Description>
+where
+    Description: DatabaseDescription,
+{
+    pub fn default_open_temp(capacity: Option<usize>) -> DatabaseResult<Self> {
+        let tmp_dir = TempDir::new().map_err(|e| DatabaseError::Other(e.into()))?;
+        let mut db = Self::open(tmp_dir.path(), capacity)?;
+        db._tmp_dir = Some(Arc::new(tmp_dir));
+        Ok(db)
+    }
+
+    /// Opens the database in the `path`, creating it with all columns if it doesn't exist.
+    /// The `capacity` is the size of the page cache in bytes.
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        let path = Self::db_path(path.as_ref());
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+
+        let mut builder = Database::builder();
+        if let Some(capacity) = capacity {
+            builder.set_cache_size(capacity);
+        }
+        let db = builder
+            .create(path)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Self::create_tables(&db)?;
+
+        Ok(Self {
+            snapshot: None,
+            db: Arc::new(db),
+            _tmp_dir: None,
+            _marker: Default::default(),
+        })
+    }
+
+    pub fn prune(path: &Path) -> DatabaseResult<()> {
+        let path = path.join(Description::name());
+        if path.exists() {
+            std::fs::remove_dir_all(path).map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+        Ok(())
+    }
+
+    /// Creates the read-only instance of the database pinned to the current state.
+    /// Changes committed after the call are not visible through the snapshot.
+    pub fn create_snapshot(&self) -> DatabaseResult<Self> {
+        let transaction = self.read_transaction()?;
+
+        Ok(Self {
+            snapshot: Some(transaction),
+            db: self.db.clone(),
+            _tmp_dir: self._tmp_dir.clone(),
+            _marker: Default::default(),
+        })
+    }
+
+    /// Creates the copy of the database in the `path`. The copy contains
+    /// the state of the snapshot or the latest committed state otherwise.
+    pub fn checkpoint<P: AsRef<Path>>(&self, path: P) -> DatabaseResult<()> {
+        let source = self.read_transaction()?;
+        let target = Self::open(path, None)?;
+
+        let transaction = target
+            .db
+            .begin_write()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        for column in enum_iterator::all::<Description::Column>() {
+            let name = Self::table_name(column.id());
+            let mut table = transaction
+                .open_table(TableDefinition::<&[u8], &[u8]>::new(&name))
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+            let entries = Self::open_table(&source, column.id())?
+                .range::<&[u8]>(..)
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+            for entry in entries {
+                let (key, value) = entry.map_err(|e| DatabaseError::Other(e.into()))?;
+                table
+                    .insert(key.value(), value.value())
+                    .map_err(|e| DatabaseError::Other(e.into()))?;
+            }
+        }
+        transaction
+            .commit()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
+    fn db_path(path: &Path) -> PathBuf {
+        path.join(Description::name()).join(DATA_FILE)
+    }
+
+    fn table_name(column: u32) -> String {
+        format!("col-{}", column)
+    }
+
+    fn create_tables(db: &Database) -> DatabaseResult<()> {
+        let transaction = db
+            .begin_write()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        for column in enum_iterator::all::<Description::Column>() {
+            let name = Self::table_name(column.id());
+            transaction
+                .open_table(TableDefinition::<&[u8], &[u8]>::new(&name))
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+        transaction
+            .commit()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
+    /// Returns the read transaction of the snapshot, if any,
+    /// or a new one over the latest committed state.
+    fn read_transaction(&self) -> DatabaseResult<Arc<ReadTransaction>> {
+        match &self.snapshot {
+            Some(snapshot) => Ok(snapshot.clone()),
+            None => {
+                let transaction = self
+                    .db
+                    .begin_read()
+                    .map_err(|e| DatabaseError::Other(e.into()))?;
+                Ok(Arc::new(transaction))
+            }
+        }
+    }
+
+    fn open_table(transaction: &ReadTransaction, column: u32) -> DatabaseResult<Table> {
+        let name = Self::table_name(column);
+        transaction
+            .open_table(TableDefinition::<&[u8], &[u8]>::new(&name))
+            .map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    fn table(&self, column: Description::Column) -> DatabaseResult<Table> {
+        Self::open_table(self.read_transaction()?.as_ref(), column.id())
+    }
+
+    fn _iter_all(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> DatabaseResult<impl Iterator<Item = KVItem>> {
+        let table = self.table(column)?;
+        let range = match (prefix, start, direction) {
+            (_, Some(start), IterDirection::Forward) => table.range::<&[u8]>(start..),
+            (_, Some(start), IterDirection::Reverse) => table.range::<&[u8]>(..=start),
+            (Some(prefix), None, IterDirection::Forward) => {
+                table.range::<&[u8]>(prefix..)
+            }
+            (Some(prefix), None, IterDirection::Reverse) => {
+                match next_prefix(prefix.to_vec()) {
+                    Some(next_prefix) => table.range::<&[u8]>(..next_prefix.as_slice()),
+                    // No next prefix, so we can start backward iteration from the end.
+                    None => table.range::<&[u8]>(..),
+                }
+            }
+            (None, None, _) => table.range::<&[u8]>(..),
+        }
+        .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        let range = match direction {
+            IterDirection::Forward => range.into_boxed(),
+            IterDirection::Reverse => range.rev().into_boxed(),
+        };
+
+        let iter = range.map(|item| {
+            item.map(|(key, value)| {
+                let key_as_vec = key.value().to_vec();
+                let value_as_vec = value.value().to_vec();
+
+                database_metrics().read_meter.inc();
+                database_metrics().bytes_read.observe(
+                    (key_as_vec.len().saturating_add(value_as_vec.len())) as f64,
+                );
+
+                (key_as_vec, Arc::new(value_as_vec))
+            })
+            .map_err(|e| DatabaseError::Other(e.into()).into())
+        });
+
+        Ok(iter)
+    }
+}
+
+impl<Description> KeyValueInspect for RedbDb<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        database_metrics().read_meter.inc();
+
+        Ok(self
+            .table(column)?
+            .get(key)
+            .map_err(|e| DatabaseError::Other(e.into()))?
+            .map(|value| value.value().len()))
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        database_metrics().read_meter.inc();
+
+        let value = self
+            .table(column)?
+            .get(key)
+            .map_err(|e| DatabaseError::Other(e.into()))?
+            .map(|value| value.value().to_vec());
+
+        if let Some(value) = &value {
+            database_metrics().bytes_read.observe(value.len() as f64);
+        }
+
+        Ok(value.map(Arc::new))
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        mut buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        database_metrics().read_meter.inc();
+
+        let r = self
+            .table(column)?
+            .get(key)
+            .map_err(|e| DatabaseError::Other(e.into()))?
+            .map(|value| {
+                let value = value.value();
+                let read = value.len();
+                std::io::Write::write_all(&mut buf, value)
+                    .map_err(|e| DatabaseError::Other(anyhow::anyhow!(e)))?;
+                StorageResult::Ok(read)
+            })
+            .transpose()?;
+
+        if let Some(r) = &r {
+            database_metrics().bytes_read.observe(*r as f64);
+        }
+
+        Ok(r)
+    }
+}
+
+impl<Description> IterableStore for RedbDb<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        if let (Some(prefix), Some(start)) = (prefix, start) {
+            // If the `start` doesn't have the same `prefix`, return nothing.
+            if !start.starts_with(prefix) {
+                return iter::empty().into_boxed();
+            }
+        }
+
+        let iter = match self._iter_all(column, prefix, start, direction) {
+            Ok(iter) => iter,
+            Err(e) => return iter::once(Err(e.into())).into_boxed(),
+        };
+
+        match prefix {
+            Some(prefix) => {
+                // End iterating when we've gone outside the prefix.
+                let prefix = prefix.to_vec();
+                iter.take_while(move |item| {
+                    if let Ok((key, _)) = item {
+                        key.starts_with(prefix.as_slice())
+                    } else {
+                        true
+                    }
+                })
+                .into_boxed()
+            }
+            None => iter.into_boxed(),
+        }
+    }
+}
+
+impl<Description> TransactableStorage<Description::Height> for RedbDb<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn commit_changes(
+        &self,
+        _: Option<Description::Height>,
+        changes: Changes,
+    ) -> StorageResult<()> {
+        if self.snapshot.is_some() {
+            return Err(DatabaseError::Other(anyhow::anyhow!(
+                "The snapshot of the database is read-only"
+            ))
+            .into())
+        }
+
+        let transaction = self
+            .db
+            .begin_write()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        let mut bytes_written = 0usize;
+
+        for (column, ops) in changes {
+            let name = Self::table_name(column);
+            let mut table = transaction
+                .open_table(TableDefinition::<&[u8], &[u8]>::new(&name))
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+            for (key, op) in ops {
+                bytes_written = bytes_written.saturating_add(key.len());
+                match op {
+                    WriteOperation::Insert(value) => {
+                        bytes_written = bytes_written.saturating_add(value.len());
+                        table
+                            .insert(key.as_slice(), value.as_slice())
+                            .map_err(|e| DatabaseError::Other(e.into()))?;
+                    }
+                    WriteOperation::Remove => {
+                        table
+                            .remove(key.as_slice())
+                            .map_err(|e| DatabaseError::Other(e.into()))?;
+                    }
+                }
+            }
+        }
+
+        database_metrics().write_meter.inc();
+        database_metrics()
+            .bytes_written
+            .observe(bytes_written as f64);
+
+        transaction
+            .commit()
+            .map_err(|e| DatabaseError::Other(e.into()).into())
+    }
+
+    fn latest_view(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        Ok(Arc::new(self.create_snapshot()?))
+    }
+
+    fn checkpoint(&self, path: &Path) -> StorageResult<()> {
+        // Calls the inherent method of the `RedbDb`.
+        RedbDb::checkpoint(self, path).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::database_description::on_chain::OnChain;
+    use fuel_core_storage::{
+        column::Column,
+        kv_store::KeyValueMutate,
+        transactional::{
+            Modifiable,
+            ReadTransaction,
+        },
+    };
+    use std::collections::HashMap;
+
+    impl<Description> KeyValueMutate for RedbDb<Description>
+    where
+        Description: DatabaseDescription,
+    {
+        fn write(
+            &mut self,
+            key: &[u8],
+            column: Self::Column,
+            buf: &[u8],
+        ) -> StorageResult<usize> {
+            let mut transaction = self.read_transaction();
+            let len = transaction.write(key, column, buf)?;
+            let changes = transaction.into_changes();
+            TransactableStorage::commit_changes(self, None, changes)?;
+
+            Ok(len)
+        }
+
+        fn delete(&mut self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+            let mut transaction = self.read_transaction();
+            transaction.delete(key, column)?;
+            let changes = transaction.into_changes();
+            TransactableStorage::commit_changes(self, None, changes)?;
+            Ok(())
+        }
+    }
+
+    impl<Description> Modifiable for RedbDb<Description>
+    where
+        Description: DatabaseDescription,
+    {
+        fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+            TransactableStorage::commit_changes(self, None, changes)
+        }
+    }
+
+    fuel_core_storage::kv_store_conformance_tests!(RedbDb::<OnChain>::default_open_temp(
+        None
+    )
+    .unwrap());
+
+    fn create_db() -> (RedbDb<OnChain>, TempDir) {
+        let tmp_dir = TempDir::new().unwrap();
+        (RedbDb::open(tmp_dir.path(), None).unwrap(), tmp_dir)
+    }
+
+    #[test]
+    fn snapshot_does_not_see_later_commits() {
+        // Given
+        let key = vec![0xA, 0xB, 0xC];
+        let (mut db, _tmp) = create_db();
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+        let snapshot = db.create_snapshot().unwrap();
+
+        // When
+        db.put(&key, Column::Metadata, Arc::new(vec![2])).unwrap();
+        db.put(&key, Column::Coins, Arc::new(vec![3])).unwrap();
+
+        // Then
+        assert_eq!(
+            snapshot.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(snapshot.get(&key, Column::Coins).unwrap(), None);
+        assert_eq!(
+            snapshot
+                .iter_store(Column::Metadata, None, None, IterDirection::Forward)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![(key.clone(), Arc::new(vec![1]))]
+        );
+    }
+
+    #[test]
+    fn snapshot_outlives_the_original_instance() {
+        // Given
+        let key = vec![0xA, 0xB, 0xC];
+        let (mut db, _tmp) = create_db();
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+        let snapshot = db.create_snapshot().unwrap();
+
+        // When
+        drop(db);
+
+        // Then
+        assert_eq!(
+            snapshot.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+    }
+
+    #[test]
+    fn snapshot_is_read_only() {
+        // Given
+        let (db, _tmp) = create_db();
+        let snapshot = db.create_snapshot().unwrap();
+
+        // When
+        let result = snapshot.commit_changes(Default::default(), HashMap::new());
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn checkpoint_can_be_opened_as_database() {
+        // Given
+        let key = vec![0xA, 0xB, 0xC];
+        let (mut db, _tmp) = create_db();
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+        let checkpoint_dir = TempDir::new().unwrap();
+
+        // When
+        db.checkpoint(checkpoint_dir.path()).unwrap();
+        db.put(&key, Column::Metadata, Arc::new(vec![2])).unwrap();
+
+        // Then
+        let checkpoint = RedbDb::<OnChain>::open(checkpoint_dir.path(), None).unwrap();
+        assert_eq!(
+            checkpoint.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+    }
+
+    #[test]
+    fn data_persists_after_reopening() {
+        // Given
+        let key = vec![0xA, 0xB, 0xC];
+        let (mut db, tmp) = create_db();
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+
+        // When
+        drop(db);
+        let db = RedbDb::<OnChain>::open(tmp.path(), None).unwrap();
+
+        // Then
+        assert_eq!(
+            db.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+    }
+}
@@ -6,6 +6,14 @@ use crate::{
         Result as DatabaseResult,
     },
     state::{
+        column_options::{
+            columns_options,
+            ColumnOptions,
+            CompactionStyle,
+            Compression,
+            DatabaseColumnProfiles,
+        },
+        next_prefix,
         IterDirection,
         TransactableStorage,
     },
@@ -29,10 +37,13 @@ use fuel_core_storage::{
 };
 use rand::RngCore;
 use rocksdb::{
+    checkpoint::Checkpoint,
+    properties,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
     ColumnFamilyDescriptor,
+    DBCompactionStyle,
     DBCompressionType,
     DBWithThreadMode,
     IteratorMode,
@@ -44,6 +55,11 @@ use rocksdb::{
 };
 use std::{
     cmp,
+    cmp::Reverse,
+    collections::{
+        BTreeMap,
+        BinaryHeap,
+    },
     env,
     fmt,
     fmt::{
@@ -125,14 +141,27 @@ impl Drop for DropResources {
     }
 }
 
-#[derive(Debug)]
+type Snapshot = rocksdb::SnapshotWithThreadMode<'static, DB>;
+
 pub struct RocksDb<Description> {
-    db: DB,
+    // The snapshot references the `db`, so it must be dropped before it.
+    // Fields are dropped in the order of declaration.
+    snapshot: Option<Snapshot>,
+    db: Arc<DB>,
     // used for RAII
-    _drop: DropResources,
+    _drop: Arc<DropResources>,
     _marker: core::marker::PhantomData<Description>,
 }
 
+impl<Description> Debug for RocksDb<Description> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RocksDb")
+            .field("db", &self.db)
+            .field("is_snapshot", &self.snapshot.is_some())
+            .finish()
+    }
+}
+
 impl<Description> RocksDb<Description>
 where
     Description: DatabaseDescription,
@@ -142,18 +171,15 @@ where
         let path = tmp_dir.path();
         let result = Self::open(
             path,
-            enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
+            columns_options::<Description>(&Default::default())?,
             capacity,
         );
         let mut db = result?;
 
-        db._drop = {
-            move || {
-                // cleanup temp dir
-                drop(tmp_dir);
-            }
-        }
-        .into();
+        db._drop = Arc::new(DropResources::from(move || {
+            // cleanup temp dir
+            drop(tmp_dir);
+        }));
 
         Ok(db)
     }
@@ -162,11 +188,17 @@ where
         path: P,
         capacity: Option<usize>,
     ) -> DatabaseResult<Self> {
-        Self::open(
-            path,
-            enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
-            capacity,
-        )
+        Self::open_with_profiles(path, capacity, &Default::default())
+    }
+
+    /// Opens the database with all columns, overriding their default options
+    /// by the `profiles`.
+    pub fn open_with_profiles<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+        profiles: &DatabaseColumnProfiles,
+    ) -> DatabaseResult<Self> {
+        Self::open(path, columns_options::<Description>(profiles)?, capacity)
     }
 
     pub fn prune(path: &Path) -> DatabaseResult<()> {
@@ -178,63 +210,27 @@ where
 
     pub fn open<P: AsRef<Path>>(
         path: P,
-        columns: Vec<Description::Column>,
+        columns: Vec<(Description::Column, ColumnOptions)>,
         capacity: Option<usize>,
     ) -> DatabaseResult<Self> {
         let path = path.as_ref().join(Description::name());
-        let mut block_opts = BlockBasedOptions::default();
-        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
-        block_opts.set_format_version(5);
-
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity as recommended by
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let block_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(block_cache_size);
-            block_opts.set_block_cache(&cache);
-            // "index and filter blocks will be stored in block cache, together with all other data blocks."
-            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
-            block_opts.set_cache_index_and_filter_blocks(true);
-            // Don't evict L0 filter/index blocks from the cache
-            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        } else {
-            block_opts.disable_cache();
-        }
-        block_opts.set_bloom_filter(10.0, true);
-
-        let cf_descriptors = columns.clone().into_iter().map(|i| {
-            ColumnFamilyDescriptor::new(
-                Self::col_name(i.id()),
-                Self::cf_opts(i, &block_opts),
-            )
-        });
+        let cf_opts = Self::columns_cf_opts(&columns, capacity);
+        let cf_descriptors = || {
+            cf_opts.iter().map(|(column, opts)| {
+                ColumnFamilyDescriptor::new(Self::col_name(*column), opts.clone())
+            })
+        };
 
-        let mut opts = Options::default();
+        let mut opts = Self::db_opts(capacity);
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
-        // TODO: Make it customizable https://github.com/FuelLabs/fuel-core/issues/1666
-        opts.set_max_total_wal_size(64 * 1024 * 1024);
-        let cpu_number =
-            i32::try_from(num_cpus::get()).expect("The number of CPU can't exceed `i32`");
-        opts.increase_parallelism(cmp::max(1, cpu_number / 2));
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity. Another 1/3 is
-            // used by block cache and the last 1 / 3 remains for other purposes:
-            //
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let row_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(row_cache_size);
-            opts.set_row_cache(&cache);
-        }
 
-        let db = match DB::open_cf_descriptors(&opts, &path, cf_descriptors) {
+        let db = match DB::open_cf_descriptors(&opts, &path, cf_descriptors()) {
             Err(_) => {
                 // setup cfs
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
-                        for i in columns {
-                            let opts = Self::cf_opts(i, &block_opts);
-                            db.create_cf(Self::col_name(i.id()), &opts)
+                        for (column, opts) in &cf_opts {
+                            db.create_cf(Self::col_name(*column), opts)
                                 .map_err(|e| DatabaseError::Other(e.into()))?;
                         }
                         Ok(db)
@@ -244,13 +240,7 @@ where
                         DB::repair(&opts, &path)
                             .map_err(|e| DatabaseError::Other(e.into()))?;
 
-                        let cf_descriptors = columns.clone().into_iter().map(|i| {
-                            ColumnFamilyDescriptor::new(
-                                Self::col_name(i.id()),
-                                Self::cf_opts(i, &block_opts),
-                            )
-                        });
-                        DB::open_cf_descriptors(&opts, &path, cf_descriptors)
+                        DB::open_cf_descriptors(&opts, &path, cf_descriptors())
                     }
                 }
             }
@@ -258,13 +248,288 @@ where
         }
         .map_err(|e| DatabaseError::Other(e.into()))?;
         let rocks_db = RocksDb {
-            db,
+            snapshot: None,
+            db: Arc::new(db),
+            _drop: Default::default(),
+            _marker: Default::default(),
+        };
+        Ok(rocks_db)
+    }
+
+    /// Opens the database in the `primary_path` as a secondary instance.
+    /// The secondary is read-only and sees the changes of the primary instance
+    /// only after [`Self::try_catch_up_with_primary`]. The `secondary_path`
+    /// keeps the own files of the secondary, like its info logs.
+    pub fn open_secondary<P: AsRef<Path>>(
+        primary_path: P,
+        secondary_path: P,
+        columns: Vec<(Description::Column, ColumnOptions)>,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        let primary_path = primary_path.as_ref().join(Description::name());
+        let secondary_path = secondary_path.as_ref().join(Description::name());
+        let cf_descriptors = Self::columns_cf_opts(&columns, capacity).into_iter().map(
+            |(column, opts)| ColumnFamilyDescriptor::new(Self::col_name(column), opts),
+        );
+
+        let mut opts = Self::db_opts(capacity);
+        // The secondary instance must keep all files open to follow the primary.
+        opts.set_max_open_files(-1);
+
+        let db = DB::open_cf_descriptors_as_secondary(
+            &opts,
+            &primary_path,
+            &secondary_path,
+            cf_descriptors,
+        )
+        .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        let rocks_db = RocksDb {
+            snapshot: None,
+            db: Arc::new(db),
             _drop: Default::default(),
             _marker: Default::default(),
         };
         Ok(rocks_db)
     }
 
+    /// Makes visible the changes committed by the primary instance since
+    /// the previous call. Returns `true` if the primary committed new changes,
+    /// what is detected by the change of the latest sequence number.
+    /// Does nothing for the primary instance.
+    pub fn try_catch_up_with_primary(&self) -> DatabaseResult<bool> {
+        let sequence_number = self.db.latest_sequence_number();
+        self.db
+            .try_catch_up_with_primary()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(self.db.latest_sequence_number() != sequence_number)
+    }
+
+    fn columns_cf_opts(
+        columns: &[(Description::Column, ColumnOptions)],
+        capacity: Option<usize>,
+    ) -> Vec<(u32, Options)> {
+        // Set block cache size 1/3 of the capacity as recommended by
+        // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+        // The block cache is split between columns according to their weights.
+        let block_cache_size = capacity.map(|capacity| capacity / 3);
+        let total_weight = columns
+            .iter()
+            .map(|(_, options)| u64::from(options.block_cache_weight))
+            .sum::<u64>();
+        columns
+            .iter()
+            .map(|(column, options)| {
+                let cache_size = block_cache_size.and_then(|size| {
+                    column_cache_size(size, options.block_cache_weight, total_weight)
+                });
+                (column.id(), Self::cf_opts(options, cache_size))
+            })
+            .collect()
+    }
+
+    fn db_opts(capacity: Option<usize>) -> Options {
+        let mut opts = Options::default();
+        opts.set_compression_type(DBCompressionType::Lz4);
+        // TODO: Make it customizable https://github.com/FuelLabs/fuel-core/issues/1666
+        opts.set_max_total_wal_size(64 * 1024 * 1024);
+        let cpu_number =
+            i32::try_from(num_cpus::get()).expect("The number of CPU can't exceed `i32`");
+        opts.increase_parallelism(cmp::max(1, cpu_number / 2));
+        if let Some(capacity) = capacity {
+            // Set cache size 1/3 of the capacity. Another 1/3 is
+            // used by block cache and the last 1 / 3 remains for other purposes:
+            //
+            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+            let row_cache_size = capacity / 3;
+            let cache = Cache::new_lru_cache(row_cache_size);
+            opts.set_row_cache(&cache);
+        }
+        opts
+    }
+
+    /// Opens the existing database in the read-only mode.
+    /// All columns of the `Description` must exist in the database.
+    pub fn open_read_only<P: AsRef<Path>>(path: P) -> DatabaseResult<Self> {
+        let path = path.as_ref().join(Description::name());
+        let columns = enum_iterator::all::<Description::Column>()
+            .map(|column| Self::col_name(column.id()));
+        let db = DB::open_cf_for_read_only(&Options::default(), &path, columns, false)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        let rocks_db = RocksDb {
+            snapshot: None,
+            db: Arc::new(db),
+            _drop: Default::default(),
+            _marker: Default::default(),
+        };
+        Ok(rocks_db)
+    }
+
+    /// Creates the read-only instance of the database pinned to the current state.
+    /// Changes committed after the call are not visible through the snapshot.
+    pub fn create_snapshot(&self) -> Self {
+        let snapshot = self.db.snapshot();
+        // SAFETY: The transmute only extends the lifetime of the snapshot to `'static`.
+        // The snapshot references the `DB` owned by the `Arc`, so the address
+        // of the `DB` is stable while any clone of the `Arc` is alive. The returned
+        // instance holds a clone of the `Arc` in the `db` field declared after
+        // the `snapshot` field, so the snapshot is dropped first. The snapshot
+        // is private and never handed out, so it can't outlive the instance.
+        let snapshot = unsafe {
+            core::mem::transmute::<rocksdb::SnapshotWithThreadMode<'_, DB>, Snapshot>(
+                snapshot,
+            )
+        };
+
+        Self {
+            snapshot: Some(snapshot),
+            db: self.db.clone(),
+            _drop: self._drop.clone(),
+            _marker: Default::default(),
+        }
+    }
+
+    /// Creates the checkpoint of the database in the `path`. The checkpoint is
+    /// a consistent copy of the database that shares the immutable files with it
+    /// where possible. The database can be opened from the same `path` later.
+    pub fn checkpoint<P: AsRef<Path>>(&self, path: P) -> DatabaseResult<()> {
+        if self.snapshot.is_some() {
+            // The checkpoint always captures the latest state of the database,
+            // so it can't be created for the snapshot.
+            return Err(DatabaseError::Other(anyhow::anyhow!(
+                "The snapshot of the database can't be checkpointed"
+            )))
+        }
+
+        let path = path.as_ref().join(Description::name());
+        let checkpoint = Checkpoint::new(self.db.as_ref())
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        checkpoint
+            .create_checkpoint(path)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
+    /// Collects the statistics of all columns from the properties of the RocksDB.
+    /// If `largest_keys` is not zero, each column is scanned to find its largest entries.
+    pub fn statistics(
+        &self,
+        largest_keys: usize,
+    ) -> DatabaseResult<Vec<ColumnStatistics>> {
+        let live_files = self
+            .db
+            .live_files()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        enum_iterator::all::<Description::Column>()
+            .map(|column| {
+                let cf = self.cf(column);
+                let int_property = |name: &std::ffi::CStr| {
+                    self.db
+                        .property_int_value_cf(&cf, name)
+                        .map(Option::unwrap_or_default)
+                        .map_err(|e| DatabaseError::Other(e.into()))
+                };
+
+                // The compression ratio is reported per level, so the ratio of
+                // the column is the ratio of levels weighted by the size of their files.
+                let column_name = Self::col_name(column.id());
+                let mut level_sizes = BTreeMap::<usize, u64>::new();
+                for file in live_files
+                    .iter()
+                    .filter(|file| file.column_family_name == column_name)
+                {
+                    let level = usize::try_from(file.level).unwrap_or_default();
+                    let size = level_sizes.entry(level).or_default();
+                    *size = size.saturating_add(file.size as u64);
+                }
+                let mut compressed_size = 0u64;
+                let mut uncompressed_size = 0f64;
+                for (level, size) in level_sizes {
+                    let ratio = self
+                        .db
+                        .property_value_cf(
+                            &cf,
+                            properties::compression_ratio_at_level(level),
+                        )
+                        .map_err(|e| DatabaseError::Other(e.into()))?
+                        .and_then(|ratio| ratio.parse::<f64>().ok())
+                        .filter(|ratio| *ratio > 0.0);
+                    if let Some(ratio) = ratio {
+                        compressed_size = compressed_size.saturating_add(size);
+                        uncompressed_size += ratio * size as f64;
+                    }
+                }
+                let compression_ratio = (compressed_size > 0)
+                    .then(|| uncompressed_size / compressed_size as f64);
+
+                Ok(ColumnStatistics {
+                    column: column.name().to_string(),
+                    estimated_keys: int_property(properties::ESTIMATE_NUM_KEYS)?,
+                    sst_files_size: int_property(properties::TOTAL_SST_FILES_SIZE)?,
+                    estimated_live_data_size: int_property(
+                        properties::ESTIMATE_LIVE_DATA_SIZE,
+                    )?,
+                    compression_ratio,
+                    largest_keys: self.largest_keys(column, largest_keys)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the `count` keys with the largest entries in the `column`,
+    /// in descending order of the size of the entry.
+    fn largest_keys(
+        &self,
+        column: Description::Column,
+        count: usize,
+    ) -> DatabaseResult<Vec<KeyStatistics>> {
+        if count == 0 {
+            return Ok(vec![])
+        }
+
+        let mut largest = BinaryHeap::<Reverse<(usize, Vec<u8>, usize)>>::new();
+        let iter = self.db.iterator_cf_opt(
+            &self.cf(column),
+            self.read_options(),
+            IteratorMode::Start,
+        );
+        for item in iter {
+            let (key, value) = item.map_err(|e| DatabaseError::Other(e.into()))?;
+            let size = key.len().saturating_add(value.len());
+            let is_larger = largest
+                .peek()
+                .map(|Reverse((smallest, _, _))| *smallest < size)
+                .unwrap_or(true);
+            if largest.len() < count || is_larger {
+                largest.push(Reverse((size, key.into_vec(), value.len())));
+                if largest.len() > count {
+                    largest.pop();
+                }
+            }
+        }
+
+        Ok(largest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, key, value_size))| KeyStatistics {
+                key_size: key.len(),
+                key: hex::encode(key),
+                value_size,
+            })
+            .collect())
+    }
+
+    /// Returns the read options pinned to the snapshot, if any.
+    fn read_options(&self) -> ReadOptions {
+        let mut opts = ReadOptions::default();
+        if let Some(snapshot) = &self.snapshot {
+            opts.set_snapshot(snapshot);
+        }
+        opts
+    }
+
     fn cf(&self, column: Description::Column) -> Arc<BoundColumnFamily> {
         self.cf_u32(column.id())
     }
@@ -279,15 +544,38 @@ where
         format!("col-{}", column)
     }
 
-    fn cf_opts(column: Description::Column, block_opts: &BlockBasedOptions) -> Options {
+    fn cf_opts(options: &ColumnOptions, block_cache_size: Option<usize>) -> Options {
+        let mut block_opts = BlockBasedOptions::default();
+        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
+        block_opts.set_format_version(5);
+
+        if let Some(block_cache_size) = block_cache_size {
+            let cache = Cache::new_lru_cache(block_cache_size);
+            block_opts.set_block_cache(&cache);
+            // "index and filter blocks will be stored in block cache, together with all other data blocks."
+            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
+            block_opts.set_cache_index_and_filter_blocks(true);
+            // Don't evict L0 filter/index blocks from the cache
+            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
+        } else {
+            block_opts.disable_cache();
+        }
+
+        if options.bloom_filter_bits_per_key > 0.0 {
+            block_opts.set_bloom_filter(options.bloom_filter_bits_per_key, true);
+        }
+
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
-        opts.set_block_based_table_factory(block_opts);
+        opts.set_compression_type(options.compression.into());
+        opts.set_compaction_style(options.compaction_style.into());
+        opts.set_block_based_table_factory(&block_opts);
 
         // All double-keys should be configured here
-        if let Some(size) = Description::prefix(&column) {
-            opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(size))
+        if options.prefix_length > 0 {
+            opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(
+                options.prefix_length,
+            ))
         }
 
         opts
@@ -324,7 +612,7 @@ where
             );
             let prefix = prefix.to_vec();
             self
-                ._iter_all(column, ReadOptions::default(), iter_mode)
+                ._iter_all(column, self.read_options(), iter_mode)
                 // Skip the element under the `next_start_key` key.
                 .skip(1)
                 .take_while(move |item| {
@@ -338,7 +626,7 @@ where
         } else {
             // No next item, so we can start backward iteration from the end.
             let prefix = prefix.to_vec();
-            self._iter_all(column, ReadOptions::default(), IteratorMode::End)
+            self._iter_all(column, self.read_options(), IteratorMode::End)
                 .take_while(move |item| {
                     if let Ok((key, _)) = item {
                         key.starts_with(prefix.as_slice())
@@ -375,6 +663,70 @@ where
     }
 }
 
+/// The statistics of one column of the RocksDB.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ColumnStatistics {
+    /// The name of the column.
+    pub column: String,
+    /// The number of keys estimated by the RocksDB.
+    pub estimated_keys: u64,
+    /// The total size of the SST files of the column in bytes.
+    pub sst_files_size: u64,
+    /// The size of the live data estimated by the RocksDB in bytes.
+    pub estimated_live_data_size: u64,
+    /// The uncompressed size of the data divided by the size of the SST files.
+    /// `None` if the column doesn't have SST files.
+    pub compression_ratio: Option<f64>,
+    /// The keys with the largest entries in descending order of the entry size.
+    pub largest_keys: Vec<KeyStatistics>,
+}
+
+/// The size of the entry under the key.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct KeyStatistics {
+    /// The hex-encoded key.
+    pub key: String,
+    pub key_size: usize,
+    pub value_size: usize,
+}
+
+/// Returns the size of the block cache of the column with the `weight`.
+/// `None` means the column doesn't use the block cache.
+fn column_cache_size(
+    block_cache_size: usize,
+    weight: u32,
+    total_weight: u64,
+) -> Option<usize> {
+    if weight == 0 || total_weight == 0 {
+        return None
+    }
+
+    let size = (block_cache_size as u128)
+        .saturating_mul(u128::from(weight))
+        .checked_div(u128::from(total_weight))?;
+    usize::try_from(size).ok()
+}
+
+impl From<Compression> for DBCompressionType {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::None => DBCompressionType::None,
+            Compression::Snappy => DBCompressionType::Snappy,
+            Compression::Lz4 => DBCompressionType::Lz4,
+            Compression::Zstd => DBCompressionType::Zstd,
+        }
+    }
+}
+
+impl From<CompactionStyle> for DBCompactionStyle {
+    fn from(style: CompactionStyle) -> Self {
+        match style {
+            CompactionStyle::Level => DBCompactionStyle::Level,
+            CompactionStyle::Universal => DBCompactionStyle::Universal,
+        }
+    }
+}
+
 impl<Description> KeyValueInspect for RocksDb<Description>
 where
     Description: DatabaseDescription,
@@ -390,7 +742,7 @@ where
 
         Ok(self
             .db
-            .get_pinned_cf(&self.cf(column), key)
+            .get_pinned_cf_opt(&self.cf(column), key, &self.read_options())
             .map_err(|e| DatabaseError::Other(e.into()))?
             .map(|value| value.len()))
     }
@@ -400,7 +752,7 @@ where
 
         let value = self
             .db
-            .get_cf(&self.cf(column), key)
+            .get_cf_opt(&self.cf(column), key, &self.read_options())
             .map_err(|e| DatabaseError::Other(e.into()))?;
 
         if let Some(value) = &value {
@@ -420,7 +772,7 @@ where
 
         let r = self
             .db
-            .get_pinned_cf(&self.cf(column), key)
+            .get_pinned_cf_opt(&self.cf(column), key, &self.read_options())
             .map_err(|e| DatabaseError::Other(e.into()))?
             .map(|value| {
                 let read = value.len();
@@ -458,7 +810,7 @@ where
                         // end always iterates in reverse
                         IterDirection::Reverse => IteratorMode::End,
                     };
-                self._iter_all(column, ReadOptions::default(), iter_mode)
+                self._iter_all(column, self.read_options(), iter_mode)
                     .into_boxed()
             }
             (Some(prefix), None) => {
@@ -470,17 +822,31 @@ where
                         prefix,
                         convert_to_rocksdb_direction(direction),
                     );
-                    let mut opts = ReadOptions::default();
-                    opts.set_prefix_same_as_start(true);
+                    // The prefix extractor of the column may be configured with
+                    // a length different from the `prefix`, so the iteration
+                    // is bounded explicitly instead of relying on it.
+                    let mut opts = self.read_options();
+                    if let Some(next_prefix) = next_prefix(prefix.to_vec()) {
+                        opts.set_iterate_upper_bound(next_prefix);
+                    }
 
-                    self._iter_all(column, opts, iter_mode).into_boxed()
+                    let prefix = prefix.to_vec();
+                    self._iter_all(column, opts, iter_mode)
+                        .take_while(move |item| {
+                            if let Ok((key, _)) = item {
+                                key.starts_with(prefix.as_slice())
+                            } else {
+                                true
+                            }
+                        })
+                        .into_boxed()
                 }
             }
             (None, Some(start)) => {
                 // start iterating in a certain direction from the start key
                 let iter_mode =
                     IteratorMode::From(start, convert_to_rocksdb_direction(direction));
-                self._iter_all(column, ReadOptions::default(), iter_mode)
+                self._iter_all(column, self.read_options(), iter_mode)
                     .into_boxed()
             }
             (Some(prefix), Some(start)) => {
@@ -495,7 +861,7 @@ where
                 let prefix = prefix.to_vec();
                 let iter_mode =
                     IteratorMode::From(start, convert_to_rocksdb_direction(direction));
-                self._iter_all(column, ReadOptions::default(), iter_mode)
+                self._iter_all(column, self.read_options(), iter_mode)
                     .take_while(move |item| {
                         if let Ok((key, _)) = item {
                             key.starts_with(prefix.as_slice())
@@ -518,6 +884,13 @@ where
         _: Option<Description::Height>,
         changes: Changes,
     ) -> StorageResult<()> {
+        if self.snapshot.is_some() {
+            return Err(DatabaseError::Other(anyhow::anyhow!(
+                "The snapshot of the database is read-only"
+            ))
+            .into())
+        }
+
         let mut batch = WriteBatch::default();
 
         for (column, ops) in changes {
@@ -543,32 +916,45 @@ where
             .write(batch)
             .map_err(|e| DatabaseError::Other(e.into()).into())
     }
-}
 
-/// The `None` means overflow, so there is not following prefix.
-fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
-    for byte in prefix.iter_mut().rev() {
-        if let Some(new_byte) = byte.checked_add(1) {
-            *byte = new_byte;
-            return Some(prefix);
-        }
+    fn latest_view(
+        &self,
+    ) -> StorageResult<
+        Arc<dyn TransactableStorage<Description::Height, Column = Self::Column>>,
+    > {
+        Ok(Arc::new(self.create_snapshot()))
+    }
+
+    fn checkpoint(&self, path: &Path) -> StorageResult<()> {
+        // Calls the inherent method of the `RocksDb`.
+        RocksDb::checkpoint(self, path).map_err(Into::into)
+    }
+
+    fn catch_up_with_primary(&self) -> StorageResult<bool> {
+        self.try_catch_up_with_primary().map_err(Into::into)
     }
-    None
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::database::database_description::on_chain::OnChain;
+    use crate::{
+        database::database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            relayer::Relayer,
+        },
+        state::column_options::ColumnProfile,
+    };
     use fuel_core_storage::{
         column::Column,
         kv_store::KeyValueMutate,
-        transactional::ReadTransaction,
-    };
-    use std::collections::{
-        BTreeMap,
-        HashMap,
+        transactional::{
+            Modifiable,
+            ReadTransaction,
+        },
     };
+    use std::collections::HashMap;
     use tempfile::TempDir;
 
     impl<Description> KeyValueMutate for RocksDb<Description>
@@ -584,7 +970,7 @@ mod tests {
             let mut transaction = self.read_transaction();
             let len = transaction.write(key, column, buf)?;
             let changes = transaction.into_changes();
-            self.commit_changes(Default::default(), changes)?;
+            TransactableStorage::commit_changes(self, None, changes)?;
 
             Ok(len)
         }
@@ -593,11 +979,24 @@ mod tests {
             let mut transaction = self.read_transaction();
             transaction.delete(key, column)?;
             let changes = transaction.into_changes();
-            self.commit_changes(Default::default(), changes)?;
+            TransactableStorage::commit_changes(self, None, changes)?;
             Ok(())
         }
     }
 
+    impl<Description> Modifiable for RocksDb<Description>
+    where
+        Description: DatabaseDescription,
+    {
+        fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+            TransactableStorage::commit_changes(self, None, changes)
+        }
+    }
+
+    fuel_core_storage::kv_store_conformance_tests!(
+        RocksDb::<OnChain>::default_open_temp(None).unwrap()
+    );
+
     fn create_db() -> (RocksDb<OnChain>, TempDir) {
         let tmp_dir = TempDir::new().unwrap();
         (
@@ -607,159 +1006,158 @@ mod tests {
     }
 
     #[test]
-    fn can_put_and_read() {
+    fn default_open_temp_supports_compression_of_default_profiles() {
+        // Given
         let key = vec![0xA, 0xB, 0xC];
+        let mut on_chain = RocksDb::<OnChain>::default_open_temp(None).unwrap();
 
-        let (mut db, _tmp) = create_db();
-        let expected = Arc::new(vec![1, 2, 3]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected)
-    }
-
-    #[test]
-    fn put_returns_previous_value() {
-        let key = vec![0xA, 0xB, 0xC];
-
-        let (mut db, _tmp) = create_db();
-        let expected = Arc::new(vec![1, 2, 3]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-        let prev = db
-            .replace(&key, Column::Metadata, Arc::new(vec![2, 4, 6]))
+        // When
+        on_chain
+            .put(&key, Column::FuelBlocks, Arc::new(vec![1]))
+            .unwrap();
+        on_chain
+            .put(&key, Column::ModificationsHistory, Arc::new(vec![2]))
             .unwrap();
 
-        assert_eq!(prev, Some(expected));
-    }
-
-    #[test]
-    fn delete_and_get() {
-        let key = vec![0xA, 0xB, 0xC];
-
-        let (mut db, _tmp) = create_db();
-        let expected = Arc::new(vec![1, 2, 3]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        db.delete(&key, Column::Metadata).unwrap();
-        assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
+        // Then
+        assert_eq!(
+            on_chain.get(&key, Column::FuelBlocks).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(
+            on_chain.get(&key, Column::ModificationsHistory).unwrap(),
+            Some(Arc::new(vec![2]))
+        );
+        RocksDb::<OffChain>::default_open_temp(None).unwrap();
+        RocksDb::<Relayer>::default_open_temp(None).unwrap();
     }
 
     #[test]
-    fn key_exists() {
+    fn snapshot_does_not_see_later_commits() {
+        // Given
         let key = vec![0xA, 0xB, 0xC];
-
         let (mut db, _tmp) = create_db();
-        let expected = Arc::new(vec![1, 2, 3]);
-        db.put(&key, Column::Metadata, expected).unwrap();
-        assert!(db.exists(&key, Column::Metadata).unwrap());
-    }
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+        let snapshot = db.create_snapshot();
 
-    #[test]
-    fn commit_changes_inserts() {
-        let key = vec![0xA, 0xB, 0xC];
-        let value = Arc::new(vec![1, 2, 3]);
+        // When
+        db.put(&key, Column::Metadata, Arc::new(vec![2])).unwrap();
+        db.put(&key, Column::Coins, Arc::new(vec![3])).unwrap();
 
-        let (db, _tmp) = create_db();
-        let ops = vec![(
-            Column::Metadata.id(),
-            BTreeMap::from_iter(vec![(
-                key.clone(),
-                WriteOperation::Insert(value.clone()),
-            )]),
-        )];
-
-        db.commit_changes(Default::default(), HashMap::from_iter(ops))
-            .unwrap();
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), value)
+        // Then
+        assert_eq!(
+            snapshot.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(snapshot.get(&key, Column::Coins).unwrap(), None);
+        assert_eq!(
+            snapshot
+                .iter_store(Column::Metadata, None, None, IterDirection::Forward)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![(key.clone(), Arc::new(vec![1]))]
+        );
     }
 
     #[test]
-    fn commit_changes_removes() {
+    fn snapshot_outlives_the_original_instance() {
+        // Given
         let key = vec![0xA, 0xB, 0xC];
-        let value = Arc::new(vec![1, 2, 3]);
-
         let (mut db, _tmp) = create_db();
-        db.put(&key, Column::Metadata, value).unwrap();
+        db.put(&key, Column::Metadata, Arc::new(vec![1])).unwrap();
+        let snapshot = db.create_snapshot();
 
-        let ops = vec![(
-            Column::Metadata.id(),
-            BTreeMap::from_iter(vec![(key.clone(), WriteOperation::Remove)]),
-        )];
-        db.commit_changes(Default::default(), HashMap::from_iter(ops))
-            .unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
-    }
-
-    #[test]
-    fn can_use_unit_value() {
-        let key = vec![0x00];
-
-        let (mut db, _tmp) = create_db();
-        let expected = Arc::new(vec![]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        assert!(db.exists(&key, Column::Metadata).unwrap());
+        // When
+        drop(db);
 
+        // Then
         assert_eq!(
-            db.iter_store(Column::Metadata, None, None, IterDirection::Forward)
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap()[0],
-            (key.clone(), expected.clone())
+            snapshot.get(&key, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
         );
-
-        assert_eq!(db.take(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
 
     #[test]
-    fn can_use_unit_key() {
-        let key: Vec<u8> = Vec::with_capacity(0);
-
-        let (mut db, _tmp) = create_db();
-        let expected = Arc::new(vec![1, 2, 3]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
+    fn snapshot_is_read_only() {
+        // Given
+        let (db, _tmp) = create_db();
+        let snapshot = db.create_snapshot();
 
-        assert!(db.exists(&key, Column::Metadata).unwrap());
+        // When
+        let result = snapshot.commit_changes(Default::default(), HashMap::new());
 
-        assert_eq!(
-            db.iter_store(Column::Metadata, None, None, IterDirection::Forward)
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap()[0],
-            (key.clone(), expected.clone())
-        );
+        // Then
+        assert!(result.is_err());
+    }
 
-        assert_eq!(db.take(&key, Column::Metadata).unwrap().unwrap(), expected);
+    #[test]
+    fn prefix_iteration_ignores_keys_outside_of_the_prefix_extractor_length() {
+        // Given
+        let tmp_dir = TempDir::new().unwrap();
+        let profile = ColumnProfile {
+            prefix_length: Some(1),
+            ..Default::default()
+        };
+        let profiles = DatabaseColumnProfiles::from([("Metadata".to_string(), profile)]);
+        let mut db =
+            RocksDb::<OnChain>::open_with_profiles(tmp_dir.path(), None, &profiles)
+                .unwrap();
+        let value = Arc::new(vec![]);
+        db.put(&[1, 1, 1], Column::Metadata, value.clone()).unwrap();
+        db.put(&[1, 1, 2], Column::Metadata, value.clone()).unwrap();
+        db.put(&[1, 2, 1], Column::Metadata, value.clone()).unwrap();
+
+        // When
+        let keys = db
+            .iter_store(
+                Column::Metadata,
+                Some([1, 1].as_slice()),
+                None,
+                IterDirection::Forward,
+            )
+            .map(|item| item.unwrap().0)
+            .collect::<Vec<_>>();
 
-        assert!(!db.exists(&key, Column::Metadata).unwrap());
+        // Then
+        assert_eq!(keys, vec![vec![1, 1, 1], vec![1, 1, 2]]);
     }
 
     #[test]
-    fn can_use_unit_key_and_value() {
-        let key: Vec<u8> = Vec::with_capacity(0);
-
+    fn statistics_returns_the_largest_keys_of_the_column() {
+        // Given
         let (mut db, _tmp) = create_db();
-        let expected = Arc::new(vec![]);
-        db.put(&key, Column::Metadata, expected.clone()).unwrap();
-
-        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
+        db.put(&[1], Column::Metadata, Arc::new(vec![0; 10]))
+            .unwrap();
+        db.put(&[2], Column::Metadata, Arc::new(vec![0; 30]))
+            .unwrap();
+        db.put(&[3], Column::Metadata, Arc::new(vec![0; 20]))
+            .unwrap();
+        db.db.flush_cf(&db.cf(Column::Metadata)).unwrap();
 
-        assert!(db.exists(&key, Column::Metadata).unwrap());
+        // When
+        let statistics = db.statistics(2).unwrap();
 
+        // Then
+        let metadata = statistics
+            .into_iter()
+            .find(|statistics| statistics.column == Column::Metadata.name())
+            .unwrap();
+        assert_eq!(metadata.estimated_keys, 3);
+        assert!(metadata.sst_files_size > 0);
         assert_eq!(
-            db.iter_store(Column::Metadata, None, None, IterDirection::Forward)
-                .collect::<Result<Vec<_>, _>>()
-                .unwrap()[0],
-            (key.clone(), expected.clone())
+            metadata.largest_keys,
+            vec![
+                KeyStatistics {
+                    key: "02".to_string(),
+                    key_size: 1,
+                    value_size: 30,
+                },
+                KeyStatistics {
+                    key: "03".to_string(),
+                    key_size: 1,
+                    value_size: 20,
+                },
+            ]
         );
-
-        assert_eq!(db.take(&key, Column::Metadata).unwrap().unwrap(), expected);
-
-        assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
 }
@@ -10,8 +10,10 @@ pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
+pub mod read_cache_metrics;
 pub mod response;
 pub mod services;
+pub mod storage_access_metrics;
 pub mod txpool_metrics;
 
 // recommended bucket defaults for logging response times
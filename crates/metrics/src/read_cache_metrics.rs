@@ -0,0 +1,100 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct Label {
+    // the name of the database
+    database: String,
+    // the name of the column
+    column: String,
+}
+
+pub struct ReadCacheMetrics {
+    pub registry: Registry,
+    hits: Family<Label, Counter>,
+    misses: Family<Label, Counter>,
+    size: Family<Label, Gauge>,
+}
+
+impl ReadCacheMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+
+        let hits = Family::<Label, Counter>::default();
+        let misses = Family::<Label, Counter>::default();
+        let size = Family::<Label, Gauge>::default();
+
+        registry.register(
+            "database_read_cache_hits",
+            "The number of reads served by the in-process cache of the column",
+            hits.clone(),
+        );
+        registry.register(
+            "database_read_cache_misses",
+            "The number of reads of the cached column that went to the database",
+            misses.clone(),
+        );
+        registry.register(
+            "database_read_cache_size_bytes",
+            "The total size of the keys and values in the cache of the column",
+            size.clone(),
+        );
+
+        Self {
+            registry,
+            hits,
+            misses,
+            size,
+        }
+    }
+
+    /// Returns the metrics of the cache of the `column` in the `database`.
+    pub fn column(&self, database: &str, column: &str) -> ColumnCacheMetrics {
+        let label = Label {
+            database: database.to_string(),
+            column: column.to_string(),
+        };
+
+        ColumnCacheMetrics {
+            hits: self.hits.get_or_create(&label).clone(),
+            misses: self.misses.get_or_create(&label).clone(),
+            size: self.size.get_or_create(&label).clone(),
+        }
+    }
+}
+
+/// The metrics of the cache of one column.
+/// They share the state with the registered metrics, so updates are cheap.
+#[derive(Clone, Debug, Default)]
+pub struct ColumnCacheMetrics {
+    hits: Counter,
+    misses: Counter,
+    size: Gauge,
+}
+
+impl ColumnCacheMetrics {
+    pub fn hit(&self) {
+        self.hits.inc();
+    }
+
+    pub fn miss(&self) {
+        self.misses.inc();
+    }
+
+    pub fn set_size(&self, size: usize) {
+        self.size.set(i64::try_from(size).unwrap_or(i64::MAX));
+    }
+}
+
+static READ_CACHE_METRICS: OnceLock<ReadCacheMetrics> = OnceLock::new();
+pub fn read_cache_metrics() -> &'static ReadCacheMetrics {
+    READ_CACHE_METRICS.get_or_init(ReadCacheMetrics::new)
+}
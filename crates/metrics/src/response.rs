@@ -2,7 +2,9 @@ use crate::{
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
+    read_cache_metrics::read_cache_metrics,
     services::services_metrics,
+    storage_access_metrics::storage_access_metrics,
     txpool_metrics::txpool_metrics,
 };
 use axum::{
@@ -50,6 +52,14 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body();
     }
 
+    if encode(&mut encoded, &read_cache_metrics().registry).is_err() {
+        return error_body();
+    }
+
+    if encode(&mut encoded, &storage_access_metrics().registry).is_err() {
+        return error_body();
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
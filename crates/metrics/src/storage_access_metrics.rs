@@ -0,0 +1,138 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        histogram::{
+            exponential_buckets,
+            Histogram,
+        },
+    },
+    registry::Registry,
+};
+use std::{
+    sync::OnceLock,
+    time::Duration,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct Label {
+    // the name of the database
+    database: String,
+    // the name of the column
+    column: String,
+    // the component that accesses the database
+    context: String,
+}
+
+pub struct StorageAccessMetrics {
+    pub registry: Registry,
+    reads: Family<Label, Counter>,
+    bytes_read: Family<Label, Counter>,
+    read_duration: Family<Label, Histogram>,
+    writes: Family<Label, Counter>,
+    bytes_written: Family<Label, Counter>,
+}
+
+impl StorageAccessMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+
+        let reads = Family::<Label, Counter>::default();
+        let bytes_read = Family::<Label, Counter>::default();
+        // Reads take from a microsecond for cached values to milliseconds for the disk.
+        let read_duration = Family::<Label, Histogram>::new_with_constructor(|| {
+            Histogram::new(exponential_buckets(0.000_001, 4.0, 10))
+        });
+        let writes = Family::<Label, Counter>::default();
+        let bytes_written = Family::<Label, Counter>::default();
+
+        registry.register(
+            "database_column_reads",
+            "The number of reads of the column",
+            reads.clone(),
+        );
+        registry.register(
+            "database_column_read_bytes",
+            "The number of bytes read from the column",
+            bytes_read.clone(),
+        );
+        registry.register(
+            "database_column_read_duration_s",
+            "The duration of reads from the column",
+            read_duration.clone(),
+        );
+        registry.register(
+            "database_column_writes",
+            "The number of committed inserts and removals in the column",
+            writes.clone(),
+        );
+        registry.register(
+            "database_column_written_bytes",
+            "The number of bytes committed into the column",
+            bytes_written.clone(),
+        );
+
+        Self {
+            registry,
+            reads,
+            bytes_read,
+            read_duration,
+            writes,
+            bytes_written,
+        }
+    }
+
+    /// Returns the metrics of the `column` in the `database` accessed by the `context`.
+    pub fn column(
+        &self,
+        database: &str,
+        column: &str,
+        context: &str,
+    ) -> ColumnAccessMetrics {
+        let label = Label {
+            database: database.to_string(),
+            column: column.to_string(),
+            context: context.to_string(),
+        };
+
+        ColumnAccessMetrics {
+            reads: self.reads.get_or_create(&label).clone(),
+            bytes_read: self.bytes_read.get_or_create(&label).clone(),
+            read_duration: self.read_duration.get_or_create(&label).clone(),
+            writes: self.writes.get_or_create(&label).clone(),
+            bytes_written: self.bytes_written.get_or_create(&label).clone(),
+        }
+    }
+}
+
+/// The metrics of accesses to one column by one context.
+/// They share the state with the registered metrics, so updates are cheap.
+#[derive(Clone, Debug)]
+pub struct ColumnAccessMetrics {
+    reads: Counter,
+    bytes_read: Counter,
+    read_duration: Histogram,
+    writes: Counter,
+    bytes_written: Counter,
+}
+
+impl ColumnAccessMetrics {
+    pub fn read(&self, bytes: usize, duration: Duration) {
+        self.reads.inc();
+        self.bytes_read
+            .inc_by(u64::try_from(bytes).unwrap_or(u64::MAX));
+        self.read_duration.observe(duration.as_secs_f64());
+    }
+
+    pub fn write(&self, bytes: usize) {
+        self.writes.inc();
+        self.bytes_written
+            .inc_by(u64::try_from(bytes).unwrap_or(u64::MAX));
+    }
+}
+
+static STORAGE_ACCESS_METRICS: OnceLock<StorageAccessMetrics> = OnceLock::new();
+pub fn storage_access_metrics() -> &'static StorageAccessMetrics {
+    STORAGE_ACCESS_METRICS.get_or_init(StorageAccessMetrics::new)
+}
@@ -65,7 +65,7 @@ where
                 )
             }
             Consensus::PoA(_) => {
-                let view = self.view_provider.latest_view();
+                let view = self.view_provider.latest_view()?;
                 fuel_core_poa::verifier::verify_block_fields(&view, block)
             }
             _ => Err(anyhow::anyhow!("Unsupported consensus: {:?}", consensus)),
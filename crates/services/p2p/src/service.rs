@@ -447,7 +447,7 @@ where
             config,
         } = self;
 
-        let view = view_provider.latest_view();
+        let view = view_provider.latest_view()?;
         let genesis = view.get_genesis()?;
         let config = config.init(genesis)?;
         let Config {
@@ -592,8 +592,9 @@ where
                     Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
                         match request_message {
                             RequestMessage::Transactions(range) => {
-                                let view = self.view_provider.latest_view();
-                                match view.get_transactions(range.clone()) {
+                                let transactions = self.view_provider.latest_view()
+                                    .and_then(|view| view.get_transactions(range.clone()));
+                                match transactions {
                                     Ok(response) => {
                                         let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::Transactions(response));
                                     },
@@ -613,8 +614,9 @@ where
                                     let response = None;
                                     let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
                                 } else {
-                                    let view = self.view_provider.latest_view();
-                                    match view.get_sealed_headers(range.clone()) {
+                                    let headers = self.view_provider.latest_view()
+                                        .and_then(|view| view.get_sealed_headers(range.clone()));
+                                    match headers {
                                         Ok(headers) => {
                                             let response = Some(headers);
                                             let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
@@ -903,8 +905,8 @@ pub mod tests {
             unimplemented!()
         }
 
-        fn latest_view(&self) -> Self::View {
-            self.clone()
+        fn latest_view(&self) -> StorageResult<Self::View> {
+            Ok(self.clone())
         }
     }
 
@@ -1032,8 +1034,8 @@ pub mod tests {
             unimplemented!()
         }
 
-        fn latest_view(&self) -> Self::View {
-            self.clone()
+        fn latest_view(&self) -> StorageResult<Self::View> {
+            Ok(self.clone())
         }
     }
 
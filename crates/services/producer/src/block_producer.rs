@@ -293,7 +293,7 @@ where
         height: BlockHeight,
         block_time: Tai64,
     ) -> anyhow::Result<PartialBlockHeader> {
-        let view = self.view_provider.latest_view();
+        let view = self.view_provider.latest_view()?;
         let previous_block_info = self.previous_block_info(height, &view)?;
         let consensus_parameters_version = view.latest_consensus_parameters_version()?;
         let state_transition_bytecode_version =
@@ -230,11 +230,11 @@ impl AtomicView for MockDb {
     }
 
     fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
-        Ok(self.latest_view())
+        self.latest_view()
     }
 
-    fn latest_view(&self) -> Self::View {
-        self.clone()
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        Ok(self.clone())
     }
 }
 
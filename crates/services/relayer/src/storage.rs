@@ -49,6 +49,10 @@ pub enum Column {
     History = 1,
     /// The column that tracks the da height of the relayer.
     RelayerHeight = 2,
+    /// The history of modifications of the relayer storage.
+    ModificationsHistory = 3,
+    /// The journal of committed changes of the relayer storage.
+    ChangeJournal = 4,
 }
 
 impl Column {
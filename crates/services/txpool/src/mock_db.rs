@@ -102,10 +102,10 @@ impl AtomicView for MockDBProvider {
     }
 
     fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
-        Ok(self.latest_view())
+        self.latest_view()
     }
 
-    fn latest_view(&self) -> Self::View {
-        self.0.clone()
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        Ok(self.0.clone())
     }
 }
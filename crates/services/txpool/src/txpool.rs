@@ -346,7 +346,10 @@ where
         &mut self,
         tx: Checked<Transaction>,
     ) -> Result<InsertionResult, Error> {
-        let view = self.database.latest_view();
+        let view = self
+            .database
+            .latest_view()
+            .map_err(|e| Error::Database(format!("{:?}", e)))?;
         self.insert_inner(tx, &view)
     }
 
@@ -447,7 +450,15 @@ where
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
         let mut res = Vec::new();
-        let view = self.database.latest_view();
+        let view = match self.database.latest_view() {
+            Ok(view) => view,
+            Err(e) => {
+                return txs
+                    .iter()
+                    .map(|_| Err(Error::Database(format!("{:?}", e))))
+                    .collect()
+            }
+        };
 
         for tx in txs.into_iter() {
             res.push(self.insert_inner(tx, &view));
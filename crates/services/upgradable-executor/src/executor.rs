@@ -260,8 +260,8 @@ where
             }
         });
 
-        let storage = self.storage_view_provider.latest_view();
-        let relayer = self.relayer_view_provider.latest_view();
+        let storage = self.storage_view_provider.latest_view()?;
+        let relayer = self.relayer_view_provider.latest_view()?;
 
         let instance = crate::instance::Instance::new(&self.engine)
             .add_source(source)?
@@ -281,8 +281,8 @@ where
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
-        let storage = self.storage_view_provider.latest_view();
-        let relayer = self.relayer_view_provider.latest_view();
+        let storage = self.storage_view_provider.latest_view()?;
+        let relayer = self.relayer_view_provider.latest_view()?;
 
         let instance = fuel_core_executor::executor::ExecutionInstance {
             relayer,
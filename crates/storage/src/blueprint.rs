@@ -1,7 +1,8 @@
 //! The module defines structures for the [`Mappable`] tables.
 //! Each table may have its blueprint that defines how it works with the storage.
 //! The table may have a plain blueprint that simply works in CRUD mode, or it may be an SMT-based
-//! blueprint that maintains a valid Merkle tree over the storage entries.
+//! blueprint that maintains a valid Merkle tree over the storage entries, or an indexed
+//! blueprint that maintains a secondary index over the storage entries.
 
 use crate::{
     codec::{
@@ -16,9 +17,11 @@ use crate::{
     },
     Mappable,
     Result as StorageResult,
+    SparseMerkleProof,
 };
 use fuel_vm_private::prelude::MerkleRoot;
 
+pub mod indexed;
 pub mod merklized;
 pub mod plain;
 pub mod sparse;
@@ -158,3 +161,14 @@ where
     /// Returns the root of the Merkle tree.
     fn root(storage: &S, key: &Key) -> StorageResult<MerkleRoot>;
 }
+
+/// It is an extension of the blueprint that supports generation of the sparse Merkle proofs
+/// of the inclusion or exclusion of the key in the Merkle tree over the storage.
+pub trait SupportsMerkleProof<M, S>: BlueprintInspect<M, S>
+where
+    M: Mappable,
+    S: KeyValueInspect,
+{
+    /// Returns the proof of the `key` in the Merkle tree.
+    fn proof(storage: &S, key: &M::Key) -> StorageResult<SparseMerkleProof>;
+}
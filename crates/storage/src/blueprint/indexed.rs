@@ -0,0 +1,539 @@
+//! The module defines the `Indexed` blueprint for the storage.
+//! The `Indexed` blueprint wraps another blueprint of the primary table and maintains
+//! a secondary index over its entries. The index allows iterating over the primary keys
+//! of the entries by the secondary key, like iterating over the coins of the owner.
+//!
+//! The index is stored in its own column. Each entry of the index has an empty value and
+//! the key `SecondaryKey ++ PrimaryKey`, where both parts are encoded by the codecs of
+//! the index and of the primary table. So all primary keys with the same secondary key
+//! share the prefix and are sorted in the order of the primary table.
+//!
+//! The table may have several indexes by nesting the blueprints:
+//! `Indexed<Indexed<Plain<KeyCodec, ValueCodec>, FirstIndex>, SecondIndex>`.
+
+use crate::{
+    blueprint::{
+        BlueprintInspect,
+        BlueprintMutate,
+        SupportsBatching,
+    },
+    codec::{
+        Decode,
+        Encode,
+        Encoder,
+    },
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        BatchOperations,
+        KeyValueInspect,
+        KeyValueMutate,
+        StorageColumn,
+    },
+    structured_storage::TableWithBlueprint,
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+};
+use std::{
+    borrow::Borrow,
+    sync::Arc,
+};
+
+/// The secondary index over the entries of the primary table `M`.
+pub trait SecondaryIndex<M>
+where
+    M: TableWithBlueprint,
+{
+    /// The secondary key of the index.
+    type Key;
+    /// The codec used to encode the secondary key.
+    /// The encoding should have a fixed size. Otherwise, the iteration by one secondary key
+    /// may return the entries of another secondary key that starts with the same bytes.
+    type KeyCodec: Encode<Self::Key>;
+    /// The secondary keys of one entry of the primary table. Usually, it is an `Option`
+    /// for the entries with at most one secondary key, or a `Vec` for several keys.
+    type Keys: IntoIterator<Item = Self::Key>;
+
+    /// The column occupied by the index.
+    fn column() -> M::Column;
+
+    /// Returns the secondary keys under which the entry of the primary table is indexed.
+    fn keys(key: &M::Key, value: &M::Value) -> Self::Keys;
+}
+
+/// The `Indexed` blueprint stores the entries of the table with the `Blueprint` and
+/// keeps the secondary `Index` in sync with them. The old secondary keys of the entry
+/// are removed from the index on every modification and the new ones are inserted.
+pub struct Indexed<Blueprint, Index> {
+    _marker: core::marker::PhantomData<(Blueprint, Index)>,
+}
+
+impl<Blueprint, Index> Indexed<Blueprint, Index> {
+    fn index_key<M, S>(secondary_key: &Index::Key, key: &M::Key) -> Vec<u8>
+    where
+        M: TableWithBlueprint,
+        S: KeyValueInspect,
+        Blueprint: BlueprintInspect<M, S>,
+        Index: SecondaryIndex<M>,
+    {
+        let secondary_encoder = Index::KeyCodec::encode(secondary_key);
+        let primary_encoder = Blueprint::KeyCodec::encode(key);
+        let secondary_bytes = secondary_encoder.as_bytes();
+        let primary_bytes = primary_encoder.as_bytes();
+
+        let mut bytes =
+            Vec::with_capacity(secondary_bytes.len().saturating_add(primary_bytes.len()));
+        bytes.extend_from_slice(secondary_bytes.as_ref());
+        bytes.extend_from_slice(primary_bytes.as_ref());
+        bytes
+    }
+
+    fn insert_into_index<M, S>(
+        storage: &mut S,
+        key: &M::Key,
+        value: &M::Value,
+    ) -> StorageResult<()>
+    where
+        M: TableWithBlueprint,
+        S: KeyValueMutate<Column = M::Column>,
+        Blueprint: BlueprintInspect<M, S>,
+        Index: SecondaryIndex<M>,
+    {
+        for secondary_key in Index::keys(key, value) {
+            let index_key = Self::index_key::<M, S>(&secondary_key, key);
+            storage.put(&index_key, Index::column(), Arc::new(vec![]))?;
+        }
+        Ok(())
+    }
+
+    fn remove_from_index<M, S>(
+        storage: &mut S,
+        key: &M::Key,
+        value: &M::Value,
+    ) -> StorageResult<()>
+    where
+        M: TableWithBlueprint,
+        S: KeyValueMutate<Column = M::Column>,
+        Blueprint: BlueprintInspect<M, S>,
+        Index: SecondaryIndex<M>,
+    {
+        for secondary_key in Index::keys(key, value) {
+            let index_key = Self::index_key::<M, S>(&secondary_key, key);
+            storage.delete(&index_key, Index::column())?;
+        }
+        Ok(())
+    }
+}
+
+impl<M, S, Blueprint, Index> BlueprintInspect<M, S> for Indexed<Blueprint, Index>
+where
+    M: Mappable,
+    S: KeyValueInspect,
+    Blueprint: BlueprintInspect<M, S>,
+{
+    type KeyCodec = Blueprint::KeyCodec;
+    type ValueCodec = Blueprint::ValueCodec;
+
+    fn exists(storage: &S, key: &M::Key, column: S::Column) -> StorageResult<bool> {
+        Blueprint::exists(storage, key, column)
+    }
+
+    fn size_of_value(
+        storage: &S,
+        key: &M::Key,
+        column: S::Column,
+    ) -> StorageResult<Option<usize>> {
+        Blueprint::size_of_value(storage, key, column)
+    }
+
+    fn get(
+        storage: &S,
+        key: &M::Key,
+        column: S::Column,
+    ) -> StorageResult<Option<M::OwnedValue>> {
+        Blueprint::get(storage, key, column)
+    }
+}
+
+impl<Column, M, S, Blueprint, Index> BlueprintMutate<M, S> for Indexed<Blueprint, Index>
+where
+    Column: StorageColumn,
+    M: TableWithBlueprint<Column = Column>,
+    M::OwnedValue: Borrow<M::Value>,
+    S: KeyValueMutate<Column = Column>,
+    Blueprint: BlueprintMutate<M, S>,
+    Index: SecondaryIndex<M>,
+{
+    fn put(
+        storage: &mut S,
+        key: &M::Key,
+        column: S::Column,
+        value: &M::Value,
+    ) -> StorageResult<()> {
+        Self::replace(storage, key, column, value).map(|_| ())
+    }
+
+    fn replace(
+        storage: &mut S,
+        key: &M::Key,
+        column: S::Column,
+        value: &M::Value,
+    ) -> StorageResult<Option<M::OwnedValue>> {
+        let prev = Blueprint::replace(storage, key, column, value)?;
+        if let Some(prev) = &prev {
+            Self::remove_from_index::<M, S>(storage, key, prev.borrow())?;
+        }
+        Self::insert_into_index::<M, S>(storage, key, value)?;
+        Ok(prev)
+    }
+
+    fn take(
+        storage: &mut S,
+        key: &M::Key,
+        column: S::Column,
+    ) -> StorageResult<Option<M::OwnedValue>> {
+        let prev = Blueprint::take(storage, key, column)?;
+        if let Some(prev) = &prev {
+            Self::remove_from_index::<M, S>(storage, key, prev.borrow())?;
+        }
+        Ok(prev)
+    }
+
+    fn delete(storage: &mut S, key: &M::Key, column: S::Column) -> StorageResult<()> {
+        Self::take(storage, key, column).map(|_| ())
+    }
+}
+
+impl<Column, M, S, Blueprint, Index> SupportsBatching<M, S> for Indexed<Blueprint, Index>
+where
+    Column: StorageColumn,
+    M: TableWithBlueprint<Column = Column>,
+    M::OwnedValue: Borrow<M::Value>,
+    S: BatchOperations<Column = Column>,
+    Blueprint: BlueprintMutate<M, S>,
+    Index: SecondaryIndex<M>,
+{
+    fn init<'a, Iter>(storage: &mut S, column: S::Column, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a M::Key, &'a M::Value)>,
+        M::Key: 'a,
+        M::Value: 'a,
+    {
+        Self::insert(storage, column, set)
+    }
+
+    fn insert<'a, Iter>(
+        storage: &mut S,
+        column: S::Column,
+        set: Iter,
+    ) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a M::Key, &'a M::Value)>,
+        M::Key: 'a,
+        M::Value: 'a,
+    {
+        // The previous values are required to update the index,
+        // so the batch is inserted entry by entry.
+        for (key, value) in set {
+            <Self as BlueprintMutate<M, S>>::put(storage, key, column, value)?;
+        }
+        Ok(())
+    }
+
+    fn remove<'a, Iter>(
+        storage: &mut S,
+        column: S::Column,
+        set: Iter,
+    ) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = &'a M::Key>,
+        M::Key: 'a,
+    {
+        for key in set {
+            <Self as BlueprintMutate<M, S>>::delete(storage, key, column)?;
+        }
+        Ok(())
+    }
+}
+
+/// A helper trait to iterate over the primary keys of the table by the secondary key.
+pub trait IteratorOverIndex: IterableStore {
+    /// Returns an iterator over the primary keys of the table `M` indexed under the
+    /// `secondary_key` by the `Index`. The iteration starts from the `start` primary key.
+    fn iter_by_index<M, Index>(
+        &self,
+        secondary_key: &Index::Key,
+        start: Option<&M::Key>,
+        direction: Option<IterDirection>,
+    ) -> BoxedIter<StorageResult<M::OwnedKey>>
+    where
+        Self: Sized,
+        M: TableWithBlueprint<Column = Self::Column>,
+        M::Blueprint: BlueprintInspect<M, Self>,
+        Index: SecondaryIndex<M>,
+    {
+        let prefix = Index::KeyCodec::encode(secondary_key)
+            .as_bytes()
+            .into_owned();
+        let start = start.map(|start| {
+            Indexed::<M::Blueprint, Index>::index_key::<M, Self>(secondary_key, start)
+        });
+        let prefix_len = prefix.len();
+
+        self.iter_store(
+            Index::column(),
+            Some(prefix.as_slice()),
+            start.as_deref(),
+            direction.unwrap_or_default(),
+        )
+        .map(move |item| {
+            item.and_then(|(key, _)| {
+                let primary_key = key.get(prefix_len..).ok_or_else(|| {
+                    StorageError::Other(anyhow::anyhow!(
+                        "The key of the index entry is shorter than its prefix"
+                    ))
+                })?;
+                <M::Blueprint as BlueprintInspect<M, Self>>::KeyCodec::decode(primary_key)
+                    .map_err(StorageError::Codec)
+            })
+        })
+        .into_boxed()
+    }
+}
+
+impl<S> IteratorOverIndex for S where S: IterableStore {}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        blueprint::plain::Plain,
+        codec::{
+            postcard::Postcard,
+            primitive::Primitive,
+            raw::Raw,
+        },
+        structured_storage::test::InMemoryStorage,
+        tables::Coins,
+        transactional::WriteTransaction,
+        StorageAsMut,
+        StorageBatchMutate,
+    };
+    use fuel_core_types::{
+        entities::coins::coin::{
+            CompressedCoin,
+            CompressedCoinV1,
+        },
+        fuel_tx::{
+            Address,
+            UtxoId,
+        },
+    };
+
+    #[derive(Copy, Clone, Debug, strum_macros::IntoStaticStr)]
+    enum Column {
+        Coins,
+        CoinsByOwner,
+    }
+
+    impl StorageColumn for Column {
+        fn name(&self) -> &'static str {
+            self.into()
+        }
+
+        fn id(&self) -> u32 {
+            *self as u32
+        }
+    }
+
+    struct IndexedCoins;
+
+    impl Mappable for IndexedCoins {
+        type Key = <Coins as Mappable>::Key;
+        type OwnedKey = <Coins as Mappable>::OwnedKey;
+        type Value = <Coins as Mappable>::Value;
+        type OwnedValue = <Coins as Mappable>::OwnedValue;
+    }
+
+    impl TableWithBlueprint for IndexedCoins {
+        type Blueprint = Indexed<Plain<Primitive<34>, Postcard>, CoinsByOwner>;
+        type Column = Column;
+
+        fn column() -> Column {
+            Column::Coins
+        }
+    }
+
+    struct CoinsByOwner;
+
+    impl SecondaryIndex<IndexedCoins> for CoinsByOwner {
+        type Key = Address;
+        type KeyCodec = Raw;
+        type Keys = Option<Address>;
+
+        fn column() -> Column {
+            Column::CoinsByOwner
+        }
+
+        fn keys(_: &UtxoId, coin: &CompressedCoin) -> Option<Address> {
+            Some(*coin.owner())
+        }
+    }
+
+    fn coin(owner: Address) -> CompressedCoin {
+        CompressedCoin::V1(CompressedCoinV1 {
+            owner,
+            ..Default::default()
+        })
+    }
+
+    fn utxo_id(index: u8) -> UtxoId {
+        UtxoId::new([index; 32].into(), index.into())
+    }
+
+    fn owned_coins(
+        storage: &InMemoryStorage<Column>,
+        owner: &Address,
+        start: Option<&UtxoId>,
+        direction: Option<IterDirection>,
+    ) -> Vec<UtxoId> {
+        storage
+            .iter_by_index::<IndexedCoins, CoinsByOwner>(owner, start, direction)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn iter_by_index__returns_only_keys_of_the_secondary_key() {
+        // Given
+        let alice = Address::from([1; 32]);
+        let bob = Address::from([2; 32]);
+        let mut storage = InMemoryStorage::default();
+        let mut transaction = storage.write_transaction();
+        for (index, owner) in [(1, alice), (2, bob), (3, alice)] {
+            transaction
+                .storage_as_mut::<IndexedCoins>()
+                .insert(&utxo_id(index), &coin(owner))
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+
+        // When
+        let alice_coins = owned_coins(&storage, &alice, None, None);
+        let bob_coins = owned_coins(&storage, &bob, None, None);
+
+        // Then
+        assert_eq!(alice_coins, vec![utxo_id(1), utxo_id(3)]);
+        assert_eq!(bob_coins, vec![utxo_id(2)]);
+    }
+
+    #[test]
+    fn iter_by_index__respects_start_and_direction() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let mut storage = InMemoryStorage::default();
+        let mut transaction = storage.write_transaction();
+        for index in 1..=4 {
+            transaction
+                .storage_as_mut::<IndexedCoins>()
+                .insert(&utxo_id(index), &coin(owner))
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+
+        // When
+        let forward = owned_coins(&storage, &owner, Some(&utxo_id(2)), None);
+        let backward = owned_coins(
+            &storage,
+            &owner,
+            Some(&utxo_id(3)),
+            Some(IterDirection::Reverse),
+        );
+
+        // Then
+        assert_eq!(forward, vec![utxo_id(2), utxo_id(3), utxo_id(4)]);
+        assert_eq!(backward, vec![utxo_id(3), utxo_id(2), utxo_id(1)]);
+    }
+
+    #[test]
+    fn insert__moves_entry_to_the_new_secondary_key() {
+        // Given
+        let alice = Address::from([1; 32]);
+        let bob = Address::from([2; 32]);
+        let mut storage = InMemoryStorage::default();
+        let mut transaction = storage.write_transaction();
+        transaction
+            .storage_as_mut::<IndexedCoins>()
+            .insert(&utxo_id(1), &coin(alice))
+            .unwrap();
+
+        // When
+        let prev = transaction
+            .storage_as_mut::<IndexedCoins>()
+            .insert(&utxo_id(1), &coin(bob))
+            .unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        assert_eq!(prev, Some(coin(alice)));
+        assert_eq!(owned_coins(&storage, &alice, None, None), vec![]);
+        assert_eq!(owned_coins(&storage, &bob, None, None), vec![utxo_id(1)]);
+    }
+
+    #[test]
+    fn remove__deletes_entry_from_the_index() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let mut storage = InMemoryStorage::default();
+        let mut transaction = storage.write_transaction();
+        transaction
+            .storage_as_mut::<IndexedCoins>()
+            .insert(&utxo_id(1), &coin(owner))
+            .unwrap();
+        transaction
+            .storage_as_mut::<IndexedCoins>()
+            .insert(&utxo_id(2), &coin(owner))
+            .unwrap();
+
+        // When
+        transaction
+            .storage_as_mut::<IndexedCoins>()
+            .remove(&utxo_id(1))
+            .unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        assert_eq!(owned_coins(&storage, &owner, None, None), vec![utxo_id(2)]);
+    }
+
+    #[test]
+    fn batch_operations__maintain_the_index() {
+        // Given
+        let owner = Address::from([1; 32]);
+        let coins = [(utxo_id(1), coin(owner)), (utxo_id(2), coin(owner))];
+        let mut storage = InMemoryStorage::default();
+        let mut transaction = storage.write_transaction();
+
+        // When
+        StorageBatchMutate::<IndexedCoins>::insert_batch(
+            &mut transaction,
+            coins.iter().map(|(key, value)| (key, value)),
+        )
+        .unwrap();
+        StorageBatchMutate::<IndexedCoins>::remove_batch(
+            &mut transaction,
+            [utxo_id(1)].iter(),
+        )
+        .unwrap();
+        transaction.commit().unwrap();
+
+        // Then
+        assert_eq!(owned_coins(&storage, &owner, None, None), vec![utxo_id(2)]);
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
         BlueprintMutate,
         SupportsBatching,
         SupportsMerkle,
+        SupportsMerkleProof,
     },
     codec::{
         Decode,
@@ -28,6 +29,7 @@ use crate::{
     Mappable,
     MerkleRoot,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageAsMut,
     StorageInspect,
     StorageMutate,
@@ -269,6 +271,37 @@ where
     }
 }
 
+impl<M, S, KeyCodec, ValueCodec, Metadata, Nodes, KeyConverter> SupportsMerkleProof<M, S>
+    for Sparse<KeyCodec, ValueCodec, Metadata, Nodes, KeyConverter>
+where
+    M: Mappable,
+    S: KeyValueInspect,
+    KeyCodec: Encode<M::Key> + Decode<M::OwnedKey>,
+    ValueCodec: Encode<M::Value> + Decode<M::OwnedValue>,
+    Metadata: Mappable<Value = SparseMerkleMetadata, OwnedValue = SparseMerkleMetadata>,
+    Nodes: Mappable<
+        Key = MerkleRoot,
+        Value = sparse::Primitive,
+        OwnedValue = sparse::Primitive,
+    >,
+    KeyConverter: PrimaryKey<InputKey = M::Key, OutputKey = Metadata::Key>,
+    S: StorageInspect<Metadata, Error = StorageError>
+        + StorageInspect<Nodes, Error = StorageError>,
+{
+    fn proof(storage: &S, key: &M::Key) -> StorageResult<SparseMerkleProof> {
+        let primary_key = KeyConverter::primary_key(key);
+        let root =
+            <Self as SupportsMerkle<Metadata::Key, M, S>>::root(storage, primary_key)?;
+        let tree: MerkleTree<Nodes, _> = MerkleTree::load(storage, &root)
+            .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))?;
+
+        let key_encoder = KeyCodec::encode(key);
+        let key_bytes = key_encoder.as_bytes();
+        tree.generate_proof(&MerkleTreeKey::new(key_bytes.as_ref()))
+            .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))
+    }
+}
+
 type NodeKeyCodec<S, Nodes> =
     <<Nodes as TableWithBlueprint>::Blueprint as BlueprintInspect<Nodes, S>>::KeyCodec;
 type NodeValueCodec<S, Nodes> =
@@ -708,6 +741,133 @@ macro_rules! root_storage_tests {
 
                 assert!(metadata.is_none());
             }
+
+            fn merkle_leaf(
+                key: &<$table as $crate::Mappable>::Key,
+                value: &<$table as $crate::Mappable>::Value,
+            ) -> (fuel_core_types::fuel_merkle::sparse::MerkleTreeKey, Vec<u8>) {
+                use $crate::{
+                    blueprint::BlueprintInspect,
+                    codec::{
+                        Encode,
+                        Encoder,
+                    },
+                    structured_storage::TableWithBlueprint,
+                };
+                type Blueprint = <$table as TableWithBlueprint>::Blueprint;
+                type Storage = InMemoryStorage<<$table as TableWithBlueprint>::Column>;
+                type KeyCodec = <Blueprint as BlueprintInspect<$table, Storage>>::KeyCodec;
+                type ValueCodec = <Blueprint as BlueprintInspect<$table, Storage>>::ValueCodec;
+
+                let key = KeyCodec::encode(key);
+                let key = fuel_core_types::fuel_merkle::sparse::MerkleTreeKey::new(key.as_bytes());
+                let value = ValueCodec::encode_as_value(value);
+                (key, value.to_vec())
+            }
+
+            #[test]
+            fn proof_proves_inclusion_of_the_existing_key() {
+                let mut storage = InMemoryStorage::default();
+                let mut storage_transaction = storage.write_transaction();
+
+                let rng = &mut StdRng::seed_from_u64(1234);
+
+                // Given
+                let key = $generate_key(&$current_key, rng);
+                let value = $generate_value(rng);
+                let another_key = $generate_key(&$current_key, rng);
+                let another_value = $generate_value(rng);
+                storage_transaction
+                    .storage_as_mut::<$table>()
+                    .insert(&key, &value)
+                    .unwrap();
+                storage_transaction
+                    .storage_as_mut::<$table>()
+                    .insert(&another_key, &another_value)
+                    .unwrap();
+                let root = storage_transaction
+                    .storage_as_mut::<$table>()
+                    .root(&$current_key)
+                    .unwrap();
+
+                // When
+                let proof = $crate::MerkleProofStorage::<$table>::proof(
+                    &storage_transaction,
+                    &key,
+                )
+                .unwrap();
+
+                // Then
+                let $crate::SparseMerkleProof::Inclusion(proof) = proof else {
+                    panic!("Expected inclusion proof, got {proof:?}");
+                };
+                let (merkle_key, merkle_value) = merkle_leaf(&key, &value);
+                let (_, another_merkle_value) = merkle_leaf(&another_key, &another_value);
+                assert!(proof.verify(&root, &merkle_key, &merkle_value));
+                assert!(!proof.verify(&root, &merkle_key, &another_merkle_value));
+            }
+
+            #[test]
+            fn proof_proves_exclusion_of_the_missing_key() {
+                let mut storage = InMemoryStorage::default();
+                let mut storage_transaction = storage.write_transaction();
+
+                let rng = &mut StdRng::seed_from_u64(1234);
+
+                // Given
+                let key = $generate_key(&$current_key, rng);
+                let value = $generate_value(rng);
+                let missing_key = $generate_key(&$current_key, rng);
+                storage_transaction
+                    .storage_as_mut::<$table>()
+                    .insert(&key, &value)
+                    .unwrap();
+                let root = storage_transaction
+                    .storage_as_mut::<$table>()
+                    .root(&$current_key)
+                    .unwrap();
+
+                // When
+                let proof = $crate::MerkleProofStorage::<$table>::proof(
+                    &storage_transaction,
+                    &missing_key,
+                )
+                .unwrap();
+
+                // Then
+                let $crate::SparseMerkleProof::Exclusion(proof) = proof else {
+                    panic!("Expected exclusion proof, got {proof:?}");
+                };
+                let (merkle_key, _) = merkle_leaf(&key, &value);
+                let (missing_merkle_key, _) = merkle_leaf(&missing_key, &value);
+                assert!(proof.verify(&root, &missing_merkle_key));
+                assert!(!proof.verify(&root, &merkle_key));
+            }
+
+            #[test]
+            fn proof_proves_exclusion_for_empty_metadata() {
+                let mut storage = InMemoryStorage::default();
+                let storage_transaction = storage.write_transaction();
+
+                let rng = &mut StdRng::seed_from_u64(1234);
+                let key = $generate_key(&$current_key, rng);
+                let value = $generate_value(rng);
+                let empty_root = fuel_core_types::fuel_merkle::sparse::in_memory::MerkleTree::new().root();
+
+                // When
+                let proof = $crate::MerkleProofStorage::<$table>::proof(
+                    &storage_transaction,
+                    &key,
+                )
+                .unwrap();
+
+                // Then
+                let $crate::SparseMerkleProof::Exclusion(proof) = proof else {
+                    panic!("Expected exclusion proof, got {proof:?}");
+                };
+                let (merkle_key, _) = merkle_leaf(&key, &value);
+                assert!(proof.verify(&empty_root, &merkle_key));
+            }
         }}
     };
 }
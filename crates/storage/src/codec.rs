@@ -7,6 +7,8 @@ use std::{
     ops::Deref,
 };
 
+#[cfg(feature = "zstd")]
+pub mod compressed;
 pub mod manual;
 pub mod postcard;
 pub mod primitive;
@@ -0,0 +1,268 @@
+//! The module contains the implementation of the `Compressed` codec.
+//! The codec wraps another codec and compresses the bytes produced by it.
+//! It is useful for tables with large and compressible values, like transactions.
+//!
+//! The compressed value starts with the [`Compression::VERSION`] byte of the algorithm
+//! followed by the output of the [`Compression::compress`]. The decoding falls back to
+//! the inner codec only if the value doesn't have this prefix, see
+//! [`Compression::is_compressed`]. It allows switching the existing table to
+//! the `Compressed` codec without migration of the values stored before.
+//! The compressed value that can't be decompressed is an error. If the compression
+//! fails, the value is stored uncompressed, so the encoding never fails.
+//!
+//! The codec is opt-in: tables of this crate, like
+//! [`Transactions`](crate::tables::Transactions), keep their codecs, because
+//! the stored format of these tables shouldn't depend on the enabled features.
+//! A table switches to the codec by changing the codec in its blueprint.
+//!
+//! The codec can't be used for tables that require the `Raw` value codec,
+//! like [`ContractsRawCode`](crate::tables::ContractsRawCode), because
+//! [`StorageRead`](crate::StorageRead) and [`StorageSize`](crate::StorageSize)
+//! work with the stored bytes directly.
+
+use crate::codec::{
+    Decode,
+    Encode,
+    Encoder,
+};
+use std::borrow::Cow;
+
+/// The compression algorithm used by the [`Compressed`] codec.
+pub trait Compression {
+    /// The version byte that prefixes the values compressed by the algorithm.
+    const VERSION: u8;
+
+    /// Compresses the bytes.
+    fn compress(bytes: &[u8]) -> anyhow::Result<Vec<u8>>;
+
+    /// Decompresses the bytes compressed by the [`Self::compress`].
+    fn decompress(bytes: &[u8]) -> anyhow::Result<Vec<u8>>;
+
+    /// Returns `true` if the bytes start like the output of the [`Self::compress`].
+    /// The check distinguishes the compressed values from the uncompressed ones
+    /// that accidentally start with the [`Self::VERSION`] byte.
+    fn is_compressed(bytes: &[u8]) -> bool;
+}
+
+/// The codec encodes the value with the `Codec` and compresses it with the `Algorithm`.
+pub struct Compressed<Codec, Algorithm>(core::marker::PhantomData<(Codec, Algorithm)>);
+
+impl<T, Codec, Algorithm> Encode<T> for Compressed<Codec, Algorithm>
+where
+    T: ?Sized,
+    Codec: Encode<T>,
+    Algorithm: Compression,
+{
+    type Encoder<'a>
+        = Cow<'a, [u8]>
+    where
+        T: 'a;
+
+    fn encode(t: &T) -> Self::Encoder<'_> {
+        let encoder = Codec::encode(t);
+        let uncompressed = encoder.as_bytes();
+
+        match Algorithm::compress(uncompressed.as_ref()) {
+            Ok(compressed) => {
+                let mut bytes = Vec::with_capacity(compressed.len().saturating_add(1));
+                bytes.push(Algorithm::VERSION);
+                bytes.extend_from_slice(&compressed);
+                Cow::Owned(bytes)
+            }
+            // The decoding reads the uncompressed values, so they are stored as is.
+            Err(_) => Cow::Owned(uncompressed.into_owned()),
+        }
+    }
+}
+
+impl<T, Codec, Algorithm> Decode<T> for Compressed<Codec, Algorithm>
+where
+    Codec: Decode<T>,
+    Algorithm: Compression,
+{
+    fn decode(bytes: &[u8]) -> anyhow::Result<T> {
+        if let Some((&version, compressed)) = bytes.split_first() {
+            if version == Algorithm::VERSION && Algorithm::is_compressed(compressed) {
+                let decompressed = Algorithm::decompress(compressed).map_err(|e| {
+                    anyhow::anyhow!("Failed to decompress the value: {e}")
+                })?;
+                return Codec::decode(&decompressed)
+            }
+        }
+
+        // The value was stored before the table started to use compression.
+        Codec::decode(bytes)
+    }
+}
+
+/// The [Zstandard](https://facebook.github.io/zstd/) compression algorithm.
+/// The compressed frames start with the magic number and include the checksum
+/// of the content, so the corrupted frame fails the decompression.
+pub struct Zstd;
+
+impl Zstd {
+    /// The default compression level of the `zstd`, it balances the speed and the ratio.
+    pub const LEVEL: i32 = 3;
+
+    /// The little-endian magic number at the beginning of each `zstd` frame.
+    pub const MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+}
+
+impl Compression for Zstd {
+    const VERSION: u8 = 1;
+
+    fn compress(bytes: &[u8]) -> anyhow::Result<Vec<u8>> {
+        std::thread_local! {
+            // The creation of the compression context is expensive, so it is reused.
+            static COMPRESSOR: core::cell::RefCell<Option<zstd::bulk::Compressor<'static>>> =
+                const { core::cell::RefCell::new(None) };
+        }
+
+        COMPRESSOR.with(|compressor| {
+            let mut compressor = compressor.borrow_mut();
+            let compressor = match compressor.as_mut() {
+                Some(compressor) => compressor,
+                None => {
+                    let mut new_compressor = zstd::bulk::Compressor::new(Zstd::LEVEL)?;
+                    new_compressor.include_checksum(true)?;
+                    compressor.insert(new_compressor)
+                }
+            };
+            Ok(compressor.compress(bytes)?)
+        })
+    }
+
+    fn decompress(bytes: &[u8]) -> anyhow::Result<Vec<u8>> {
+        Ok(zstd::stream::decode_all(bytes)?)
+    }
+
+    fn is_compressed(bytes: &[u8]) -> bool {
+        bytes.starts_with(&Self::MAGIC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::{
+        postcard::Postcard,
+        raw::Raw,
+    };
+    use fuel_core_types::fuel_tx::Transaction;
+
+    type CompressedPostcard = Compressed<Postcard, Zstd>;
+    type CompressedRaw = Compressed<Raw, Zstd>;
+
+    #[test]
+    fn decode_returns_encoded_value() {
+        // Given
+        let transaction = Transaction::default_test_tx();
+
+        // When
+        let encoded = CompressedPostcard::encode(&transaction);
+        let decoded: Transaction = CompressedPostcard::decode(&encoded).unwrap();
+
+        // Then
+        assert_eq!(encoded[0], Zstd::VERSION);
+        assert_eq!(decoded, transaction);
+    }
+
+    #[test]
+    fn encode_compresses_repetitive_values() {
+        // Given
+        let value = vec![42u8; 1024];
+
+        // When
+        let encoded = CompressedRaw::encode(&value);
+
+        // Then
+        assert!(encoded.len() < value.len() / 10);
+        let decoded: Vec<u8> = CompressedRaw::decode(&encoded).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn decode_reads_uncompressed_values() {
+        // Given
+        let transaction = Transaction::default_test_tx();
+        let uncompressed = Postcard::encode(&transaction);
+
+        // When
+        let decoded: Transaction = CompressedPostcard::decode(&uncompressed).unwrap();
+
+        // Then
+        assert_eq!(decoded, transaction);
+    }
+
+    #[test]
+    fn decode_reads_uncompressed_values_starting_with_version() {
+        // Given
+        let value = vec![Zstd::VERSION, 1, 2, 3];
+
+        // When
+        let decoded: Vec<u8> = CompressedRaw::decode(&value).unwrap();
+
+        // Then
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn decode_reads_empty_uncompressed_values() {
+        // Given
+        let value: Vec<u8> = vec![];
+
+        // When
+        let decoded: Vec<u8> = CompressedRaw::decode(&value).unwrap();
+
+        // Then
+        assert_eq!(decoded, value);
+    }
+
+    struct FailingCompression;
+
+    impl Compression for FailingCompression {
+        const VERSION: u8 = 1;
+
+        fn compress(_: &[u8]) -> anyhow::Result<Vec<u8>> {
+            Err(anyhow::anyhow!("The compression always fails"))
+        }
+
+        fn decompress(_: &[u8]) -> anyhow::Result<Vec<u8>> {
+            Err(anyhow::anyhow!("The decompression always fails"))
+        }
+
+        fn is_compressed(_: &[u8]) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn encode_stores_uncompressed_value_if_compression_fails() {
+        // Given
+        let value = vec![42u8; 1024];
+
+        // When
+        let encoded = Compressed::<Raw, FailingCompression>::encode(&value);
+
+        // Then
+        assert_eq!(encoded.as_ref(), value.as_slice());
+        let decoded: Vec<u8> =
+            Compressed::<Raw, FailingCompression>::decode(&encoded).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn decode_fails_for_corrupted_compressed_values() {
+        // Given
+        let value = vec![42u8; 1024];
+        let mut encoded = CompressedRaw::encode(&value).into_owned();
+        let last = encoded.len() - 1;
+        encoded[last] ^= 0xFF;
+
+        // When
+        let result: anyhow::Result<Vec<u8>> = CompressedRaw::decode(&encoded);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -65,6 +65,18 @@ pub enum Column {
     // TODO: Remove this column and use `Metadata` column instead.
     /// Table for genesis state import progress tracking.
     GenesisMetadata = 20,
+    /// The history of modifications of the state. The entry for each height contains
+    /// the changes required to revert the state to the previous height.
+    ModificationsHistory = 21,
+    /// The lowest height of the block available in the database.
+    /// Blocks below it were removed by the state pruning.
+    LowestAvailableHeight = 22,
+    /// The journal of committed changes for external consumers. The record for each commit
+    /// contains the old and new values of all keys modified by the commit.
+    ChangeJournal = 23,
+    /// The height to which the rollback of the databases was started.
+    /// It is removed after the rollback finishes.
+    RollbackTarget = 24,
 }
 
 impl Column {
@@ -65,6 +65,57 @@ impl Default for IterDirection {
     }
 }
 
+/// The range `[start, end)` of the encoded keys in the column.
+/// It allows splitting the column into shards that can be iterated independently.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyRange {
+    /// The first key of the range. `None` means the beginning of the column.
+    pub start: Option<Vec<u8>>,
+    /// The key after the last key of the range. `None` means the end of the column.
+    pub end: Option<Vec<u8>>,
+}
+
+impl KeyRange {
+    /// The maximum number of shards. The shards are split by the first two bytes of the key.
+    pub const MAX_SHARDS: usize = 1 << 16;
+
+    /// Returns the range that covers the whole column.
+    pub fn full() -> Self {
+        Self::default()
+    }
+
+    /// Splits the key space of the column into `shards` consecutive ranges that cover it.
+    /// The ranges evenly split the values of the first two bytes of the key. So shards
+    /// have similar sizes for the columns with uniformly distributed keys, like hashes.
+    /// The number of shards is clamped to `[1, MAX_SHARDS]`.
+    pub fn shards(shards: usize) -> Vec<Self> {
+        let shards = shards.clamp(1, Self::MAX_SHARDS);
+        let boundaries = (1..shards).map(|shard| {
+            let boundary = shard
+                .saturating_mul(Self::MAX_SHARDS)
+                .checked_div(shards)
+                .unwrap_or_default();
+            // The `shard` is less than `shards`, so the boundary always fits into `u16`.
+            let boundary = u16::try_from(boundary).unwrap_or(u16::MAX);
+            Some(boundary.to_be_bytes().to_vec())
+        });
+        let starts = core::iter::once(None).chain(boundaries.clone());
+        let ends = boundaries.chain(core::iter::once(None));
+
+        starts
+            .zip(ends)
+            .map(|(start, end)| Self { start, end })
+            .collect()
+    }
+
+    /// Returns `true` if the encoded `key` belongs to the range.
+    pub fn contains(&self, key: &[u8]) -> bool {
+        let before_start = matches!(&self.start, Some(start) if key < start.as_slice());
+        let after_end = matches!(&self.end, Some(end) if key >= end.as_slice());
+        !before_start && !after_end
+    }
+}
+
 /// A trait for iterating over the storage of [`KeyValueInspect`].
 #[impl_tools::autoimpl(for<T: trait> &T, &mut T, Box<T>, Arc<T>)]
 pub trait IterableStore: KeyValueInspect {
@@ -92,6 +143,12 @@ where
     ) -> BoxedIter<super::Result<(M::OwnedKey, M::OwnedValue)>>
     where
         P: AsRef<[u8]>;
+
+    /// Returns an iterator over the entries in the table with the encoded keys in the `range`.
+    fn iter_table_range(
+        &self,
+        range: &KeyRange,
+    ) -> BoxedIter<super::Result<(M::OwnedKey, M::OwnedValue)>>;
 }
 
 impl<Column, M, S> IterableTable<M> for S
@@ -122,24 +179,50 @@ where
             start.as_ref().map(|cow| cow.as_ref()),
             direction.unwrap_or_default(),
         )
-        .map(|val| {
-            val.and_then(|(key, value)| {
-                let key = <M::Blueprint as BlueprintInspect<M, Self>>::KeyCodec::decode(
-                    key.as_slice(),
-                )
-                .map_err(|e| crate::Error::Codec(anyhow::anyhow!(e)))?;
-                let value =
-                    <M::Blueprint as BlueprintInspect<M, Self>>::ValueCodec::decode(
-                        value.as_slice(),
-                    )
-                    .map_err(|e| crate::Error::Codec(anyhow::anyhow!(e)))?;
-                Ok((key, value))
-            })
+        .map(|item| decode_entry::<M, Self>(item))
+        .into_boxed()
+    }
+
+    fn iter_table_range(
+        &self,
+        range: &KeyRange,
+    ) -> BoxedIter<super::Result<(M::OwnedKey, M::OwnedValue)>> {
+        let end = range.end.clone();
+
+        IterableStore::iter_store(
+            self,
+            M::column(),
+            None,
+            range.start.as_deref(),
+            IterDirection::Forward,
+        )
+        .take_while(move |item| match (item, &end) {
+            (Ok((key, _)), Some(end)) => key < end,
+            _ => true,
         })
+        .map(|item| decode_entry::<M, Self>(item))
         .into_boxed()
     }
 }
 
+fn decode_entry<M, S>(item: KVItem) -> super::Result<(M::OwnedKey, M::OwnedValue)>
+where
+    M: TableWithBlueprint,
+    M::Blueprint: BlueprintInspect<M, S>,
+    S: KeyValueInspect,
+{
+    item.and_then(|(key, value)| {
+        let key =
+            <M::Blueprint as BlueprintInspect<M, S>>::KeyCodec::decode(key.as_slice())
+                .map_err(|e| crate::Error::Codec(anyhow::anyhow!(e)))?;
+        let value = <M::Blueprint as BlueprintInspect<M, S>>::ValueCodec::decode(
+            value.as_slice(),
+        )
+        .map_err(|e| crate::Error::Codec(anyhow::anyhow!(e)))?;
+        Ok((key, value))
+    })
+}
+
 /// A helper trait to provide a user-friendly API over table iteration.
 pub trait IteratorOverTable {
     /// Returns an iterator over the all entries in the table.
@@ -194,6 +277,45 @@ pub trait IteratorOverTable {
     {
         self.iter_table(prefix, start, direction)
     }
+
+    /// Returns an iterator over the all entries in the table with the encoded keys in the `range`.
+    fn iter_all_in_range<M>(
+        &self,
+        range: &KeyRange,
+    ) -> BoxedIter<super::Result<(M::OwnedKey, M::OwnedValue)>>
+    where
+        M: Mappable,
+        Self: IterableTable<M>,
+    {
+        self.iter_table_range(range)
+    }
+
+    /// Returns a parallel iterator over the all entries in the table.
+    /// The table is split into `shards` by the [`KeyRange::shards`], and the `rayon`
+    /// thread pool iterates over them concurrently. Collecting the iterator preserves
+    /// the order of the entries.
+    #[cfg(feature = "rayon")]
+    fn par_iter_all<M>(
+        &self,
+        shards: usize,
+    ) -> impl rayon::iter::ParallelIterator<
+        Item = super::Result<(M::OwnedKey, M::OwnedValue)>,
+    > + '_
+    where
+        M: Mappable,
+        M::OwnedKey: Send,
+        M::OwnedValue: Send,
+        Self: IterableTable<M> + Sync,
+    {
+        use rayon::iter::{
+            IntoParallelIterator,
+            ParallelIterator,
+        };
+
+        KeyRange::shards(shards)
+            .into_par_iter()
+            .flat_map_iter(move |range| self.iter_all_in_range::<M>(&range))
+    }
 }
 
 impl<S> IteratorOverTable for S {}
@@ -238,6 +360,11 @@ pub fn iterator<'a, V>(
             }
         }
         (Some(prefix), Some(start)) => {
+            // If the `start` doesn't have the same `prefix`, return nothing.
+            if !start.starts_with(prefix) {
+                return core::iter::empty().into_boxed();
+            }
+
             let prefix = prefix.to_vec();
             if direction == IterDirection::Forward {
                 tree.range(start.to_vec()..)
@@ -252,3 +379,112 @@ pub fn iterator<'a, V>(
         }
     }
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        column::Column,
+        structured_storage::test::InMemoryStorage,
+        tables::Coins,
+        transactional::WriteTransaction,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn storage_with_coins(count: usize) -> InMemoryStorage<Column> {
+        let mut rng = StdRng::seed_from_u64(0xF00D);
+        let mut storage = InMemoryStorage::default();
+        let mut transaction = storage.write_transaction();
+        for _ in 0..count {
+            let utxo_id = UtxoId::new(rng.gen(), rng.gen());
+            transaction
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id, &CompressedCoin::default())
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+        storage
+    }
+
+    fn all_coins(storage: &InMemoryStorage<Column>) -> Vec<UtxoId> {
+        storage
+            .iter_all::<Coins>(None)
+            .map(|entry| entry.map(|(key, _)| key))
+            .collect::<crate::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn shards__cover_the_key_space_without_gaps() {
+        // When
+        let shards = KeyRange::shards(5);
+
+        // Then
+        assert_eq!(shards.len(), 5);
+        assert_eq!(shards.first().unwrap().start, None);
+        assert_eq!(shards.last().unwrap().end, None);
+        for pair in shards.windows(2) {
+            assert!(pair[0].end.is_some());
+            assert_eq!(pair[0].end, pair[1].start);
+            assert!(pair[1].start < pair[1].end || pair[1].end.is_none());
+        }
+    }
+
+    #[test]
+    fn shards__zero_shards_returns_the_full_range() {
+        // When
+        let shards = KeyRange::shards(0);
+
+        // Then
+        assert_eq!(shards, vec![KeyRange::full()]);
+    }
+
+    #[test]
+    fn iter_all_in_range__shards_together_return_all_entries_in_order() {
+        // Given
+        let storage = storage_with_coins(100);
+        let shards = KeyRange::shards(7);
+
+        // When
+        let mut coins = vec![];
+        for shard in &shards {
+            for entry in storage.iter_all_in_range::<Coins>(shard) {
+                let (utxo_id, _) = entry.unwrap();
+                let key = crate::codec::primitive::utxo_id_to_bytes(&utxo_id);
+                assert!(shard.contains(&key));
+                coins.push(utxo_id);
+            }
+        }
+
+        // Then
+        assert_eq!(coins, all_coins(&storage));
+    }
+
+    #[test]
+    fn par_iter_all__collects_all_entries_in_order() {
+        use rayon::iter::ParallelIterator;
+
+        // Given
+        let storage = storage_with_coins(100);
+
+        // When
+        let coins = storage
+            .par_iter_all::<Coins>(16)
+            .map(|entry| entry.map(|(key, _)| key))
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        assert_eq!(coins, all_coins(&storage));
+    }
+}
@@ -33,6 +33,7 @@ pub mod test_helpers;
 pub mod transactional;
 pub mod vm_storage;
 
+pub use fuel_core_types::fuel_merkle::sparse::proof::Proof as SparseMerkleProof;
 pub use fuel_vm_private::storage::{
     ContractsAssetKey,
     ContractsStateData,
@@ -147,6 +148,15 @@ pub trait StorageBatchMutate<Type: Mappable>: StorageMutate<Type> {
         Type::Key: 'a;
 }
 
+/// The trait allows generating the sparse Merkle proof of the key in the Merkle tree
+/// built over the table. The proof proves the inclusion of the key into the tree if
+/// the table contains the key, and the exclusion otherwise.
+#[impl_tools::autoimpl(for<T: trait> &T, &mut T)]
+pub trait MerkleProofStorage<Type: Mappable> {
+    /// Returns the proof of the `key` against the current root of the Merkle tree.
+    fn proof(&self, key: &Type::Key) -> Result<SparseMerkleProof>;
+}
+
 /// Creates `StorageError::NotFound` error with file and line information inside.
 ///
 /// # Examples
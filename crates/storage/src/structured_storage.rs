@@ -7,6 +7,7 @@ use crate::{
         BlueprintMutate,
         SupportsBatching,
         SupportsMerkle,
+        SupportsMerkleProof,
     },
     codec::{
         raw::Raw,
@@ -33,9 +34,11 @@ use crate::{
     },
     Error as StorageError,
     Mappable,
+    MerkleProofStorage,
     MerkleRoot,
     MerkleRootStorage,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageBatchMutate,
     StorageInspect,
     StorageMutate,
@@ -298,6 +301,17 @@ where
     }
 }
 
+impl<Column, S, M> MerkleProofStorage<M> for StructuredStorage<S>
+where
+    S: KeyValueInspect<Column = Column>,
+    M: TableWithBlueprint<Column = Column>,
+    M::Blueprint: SupportsMerkleProof<M, StructuredStorage<S>>,
+{
+    fn proof(&self, key: &M::Key) -> StorageResult<SparseMerkleProof> {
+        <M as TableWithBlueprint>::Blueprint::proof(self, key)
+    }
+}
+
 impl<Column, S, M> StorageRead<M> for StructuredStorage<S>
 where
     S: KeyValueInspect<Column = Column>,
@@ -372,15 +386,28 @@ where
 #[cfg(feature = "test-helpers")]
 pub mod test {
     use crate as fuel_core_storage;
-    use crate::kv_store::{
-        KeyValueInspect,
-        StorageColumn,
+    use crate::{
+        iter::{
+            iterator,
+            BoxedIter,
+            IntoBoxedIter,
+            IterDirection,
+            IterableStore,
+        },
+        kv_store::{
+            KVItem,
+            KeyValueInspect,
+            StorageColumn,
+        },
     };
     use fuel_core_storage::{
         kv_store::Value,
         Result as StorageResult,
     };
-    use std::collections::HashMap;
+    use std::collections::{
+        BTreeMap,
+        HashMap,
+    };
 
     type Storage = HashMap<(u32, Vec<u8>), Value>;
 
@@ -418,4 +445,29 @@ pub mod test {
             Ok(value)
         }
     }
+
+    impl<Column> IterableStore for InMemoryStorage<Column>
+    where
+        Column: StorageColumn,
+    {
+        fn iter_store(
+            &self,
+            column: Self::Column,
+            prefix: Option<&[u8]>,
+            start: Option<&[u8]>,
+            direction: IterDirection,
+        ) -> BoxedIter<KVItem> {
+            let column = column.id();
+            let tree: BTreeMap<_, _> = self
+                .storage
+                .iter()
+                .filter(|((entry_column, _), _)| *entry_column == column)
+                .map(|((_, key), value)| (key.clone(), value.clone()))
+                .collect();
+            let entries: Vec<_> = iterator(&tree, prefix, start, direction)
+                .map(|(key, value)| Ok((key.clone(), value.clone())))
+                .collect();
+            entries.into_iter().into_boxed()
+        }
+    }
 }
@@ -14,6 +14,8 @@ use crate::{
     StorageMutate,
 };
 
+pub mod kv_store;
+
 /// The trait is used to provide a generic mocked implementation for all possible `StorageInspect`,
 /// `StorageMutate`, and `MerkleRootStorage` traits.
 pub trait MockStorageMethods {
@@ -0,0 +1,743 @@
+//! The conformance test suite for the key-value store backends.
+//!
+//! Each function checks one property of the backend and panics if it is violated.
+//! The [`kv_store_conformance_tests`](crate::kv_store_conformance_tests) macro
+//! instantiates all of them as tests for the backend.
+
+use crate::{
+    column::Column,
+    iter::{
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        Value,
+        WriteOperation,
+    },
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        Modifiable,
+        StorageTransaction,
+    },
+};
+use proptest::{
+    prelude::*,
+    test_runner::{
+        Config,
+        TestRunner,
+    },
+};
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+};
+
+/// The columns used by the suite.
+const COLUMNS: [Column; 2] = [Column::Metadata, Column::Coins];
+
+/// The number of cases of the property tests.
+const PROPERTY_CASES: u32 = 64;
+
+fn commit<S>(storage: &mut S, column: Column, ops: Vec<(Vec<u8>, WriteOperation)>)
+where
+    S: Modifiable,
+{
+    let changes = Changes::from([(column as u32, ops.into_iter().collect())]);
+    storage.commit_changes(changes).unwrap();
+}
+
+fn insert<S>(storage: &mut S, column: Column, key: &[u8], value: &[u8])
+where
+    S: Modifiable,
+{
+    let operation = WriteOperation::Insert(Arc::new(value.to_vec()));
+    commit(storage, column, vec![(key.to_vec(), operation)]);
+}
+
+fn remove<S>(storage: &mut S, column: Column, key: &[u8])
+where
+    S: Modifiable,
+{
+    commit(
+        storage,
+        column,
+        vec![(key.to_vec(), WriteOperation::Remove)],
+    );
+}
+
+fn keys<S>(
+    storage: &S,
+    column: Column,
+    prefix: Option<&[u8]>,
+    start: Option<&[u8]>,
+    direction: IterDirection,
+) -> Vec<Vec<u8>>
+where
+    S: IterableStore<Column = Column>,
+{
+    storage
+        .iter_store(column, prefix, start, direction)
+        .map(|item| item.unwrap().0)
+        .collect()
+}
+
+/// Inserts the keys with the empty values into the `Metadata` column.
+fn insert_keys<S>(storage: &mut S, keys: &[&[u8]])
+where
+    S: Modifiable,
+{
+    let ops = keys
+        .iter()
+        .map(|key| (key.to_vec(), WriteOperation::Insert(Arc::new(vec![]))))
+        .collect();
+    commit(storage, Column::Metadata, ops);
+}
+
+/// The inserted value is returned by the `get`.
+pub fn get_returns_inserted_value<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    let key = [0xA, 0xB, 0xC];
+    assert_eq!(storage.get(&key, Column::Metadata).unwrap(), None);
+
+    insert(&mut storage, Column::Metadata, &key, &[1, 2, 3]);
+
+    assert_eq!(
+        storage.get(&key, Column::Metadata).unwrap(),
+        Some(Arc::new(vec![1, 2, 3]))
+    );
+}
+
+/// The insert overrides the previous value of the key.
+pub fn insert_overrides_previous_value<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    let key = [0xA, 0xB, 0xC];
+    insert(&mut storage, Column::Metadata, &key, &[1, 2, 3]);
+
+    insert(&mut storage, Column::Metadata, &key, &[2, 4]);
+
+    assert_eq!(
+        storage.get(&key, Column::Metadata).unwrap(),
+        Some(Arc::new(vec![2, 4]))
+    );
+    assert_eq!(
+        storage.size_of_value(&key, Column::Metadata).unwrap(),
+        Some(2)
+    );
+}
+
+/// The removed key doesn't exist anymore.
+pub fn remove_deletes_value<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    let key = [0xA, 0xB, 0xC];
+    insert(&mut storage, Column::Metadata, &key, &[1, 2, 3]);
+    assert!(storage.exists(&key, Column::Metadata).unwrap());
+
+    remove(&mut storage, Column::Metadata, &key);
+
+    assert_eq!(storage.get(&key, Column::Metadata).unwrap(), None);
+    assert!(!storage.exists(&key, Column::Metadata).unwrap());
+}
+
+/// The removal of the missing key is not an error.
+pub fn remove_of_missing_key_is_noop<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[1], &[1]);
+
+    remove(&mut storage, Column::Metadata, &[2]);
+
+    assert_eq!(
+        keys(
+            &storage,
+            Column::Metadata,
+            None,
+            None,
+            IterDirection::Forward
+        ),
+        vec![vec![1]]
+    );
+}
+
+/// The `size_of_value` returns the length of the value without reading it.
+pub fn size_of_value_returns_value_length<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[1], &[0; 100]);
+    insert(&mut storage, Column::Metadata, &[2], &[]);
+
+    assert_eq!(
+        storage.size_of_value(&[1], Column::Metadata).unwrap(),
+        Some(100)
+    );
+    assert_eq!(
+        storage.size_of_value(&[2], Column::Metadata).unwrap(),
+        Some(0)
+    );
+    assert_eq!(storage.size_of_value(&[3], Column::Metadata).unwrap(), None);
+}
+
+/// The `read` copies the value into the buffer of the value size
+/// and returns the number of read bytes.
+pub fn read_copies_value_into_buffer<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[1], &[1, 2, 3]);
+    let mut buf = [0; 3];
+
+    let read = storage.read(&[1], Column::Metadata, &mut buf).unwrap();
+
+    assert_eq!(read, Some(3));
+    assert_eq!(buf, [1, 2, 3]);
+}
+
+/// The `read` into the part of the larger buffer at the offset
+/// doesn't touch the rest of the buffer.
+pub fn read_into_buffer_at_offset<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[1], &[1, 2, 3]);
+    insert(&mut storage, Column::Metadata, &[2], &[4, 5]);
+    let mut buf = [0xFF; 7];
+
+    let first = storage
+        .read(&[1], Column::Metadata, &mut buf[1..4])
+        .unwrap();
+    let second = storage
+        .read(&[2], Column::Metadata, &mut buf[4..6])
+        .unwrap();
+
+    assert_eq!(first, Some(3));
+    assert_eq!(second, Some(2));
+    assert_eq!(buf, [0xFF, 1, 2, 3, 4, 5, 0xFF]);
+}
+
+/// The `read` of the missing key returns `None` and leaves the buffer untouched.
+pub fn read_of_missing_key_returns_none<S>(storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    let mut buf = [0xFF; 3];
+
+    let read = storage.read(&[1], Column::Metadata, &mut buf).unwrap();
+
+    assert_eq!(read, None);
+    assert_eq!(buf, [0xFF; 3]);
+}
+
+/// The `read` fails if the buffer is smaller than the value.
+pub fn read_into_smaller_buffer_fails<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[1], &[1, 2, 3]);
+    let mut buf = [0; 2];
+
+    let result = storage.read(&[1], Column::Metadata, &mut buf);
+
+    assert!(result.is_err());
+}
+
+/// The empty key and the empty value are regular entries.
+pub fn supports_empty_key_and_value<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[], &[]);
+
+    assert_eq!(
+        storage.get(&[], Column::Metadata).unwrap(),
+        Some(Arc::new(vec![]))
+    );
+    assert!(storage.exists(&[], Column::Metadata).unwrap());
+    assert_eq!(
+        storage
+            .iter_store(Column::Metadata, None, None, IterDirection::Forward)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        vec![(vec![], Arc::new(vec![]))]
+    );
+
+    remove(&mut storage, Column::Metadata, &[]);
+
+    assert!(!storage.exists(&[], Column::Metadata).unwrap());
+
+    insert(&mut storage, Column::Metadata, &[], &[1, 2, 3]);
+
+    assert_eq!(
+        storage.get(&[], Column::Metadata).unwrap(),
+        Some(Arc::new(vec![1, 2, 3]))
+    );
+}
+
+/// The same key in different columns refers to different entries.
+pub fn columns_are_independent<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[1], &[1]);
+    insert(&mut storage, Column::Coins, &[1], &[2]);
+
+    remove(&mut storage, Column::Metadata, &[1]);
+
+    assert_eq!(storage.get(&[1], Column::Metadata).unwrap(), None);
+    assert_eq!(
+        storage.get(&[1], Column::Coins).unwrap(),
+        Some(Arc::new(vec![2]))
+    );
+    assert_eq!(
+        keys(&storage, Column::Coins, None, None, IterDirection::Forward),
+        vec![vec![1]]
+    );
+}
+
+/// The iteration without prefix and start returns all keys of the column.
+pub fn iter_all_in_both_directions<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert_keys(&mut storage, &[&[2], &[1, 0], &[1], &[0xFF, 0xFF]]);
+
+    let forward = keys(
+        &storage,
+        Column::Metadata,
+        None,
+        None,
+        IterDirection::Forward,
+    );
+    let reverse = keys(
+        &storage,
+        Column::Metadata,
+        None,
+        None,
+        IterDirection::Reverse,
+    );
+
+    assert_eq!(
+        forward,
+        vec![vec![1], vec![1, 0], vec![2], vec![0xFF, 0xFF]]
+    );
+    assert_eq!(
+        reverse,
+        vec![vec![0xFF, 0xFF], vec![2], vec![1, 0], vec![1]]
+    );
+}
+
+/// The iteration with the prefix returns only keys with the prefix.
+pub fn iter_prefix_in_both_directions<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert_keys(
+        &mut storage,
+        &[&[1, 0, 1], &[1, 1], &[1, 1, 2], &[1, 2, 1], &[2]],
+    );
+    let prefix = Some([1, 1].as_slice());
+
+    let forward = keys(
+        &storage,
+        Column::Metadata,
+        prefix,
+        None,
+        IterDirection::Forward,
+    );
+    let reverse = keys(
+        &storage,
+        Column::Metadata,
+        prefix,
+        None,
+        IterDirection::Reverse,
+    );
+
+    assert_eq!(forward, vec![vec![1, 1], vec![1, 1, 2]]);
+    assert_eq!(reverse, vec![vec![1, 1, 2], vec![1, 1]]);
+}
+
+/// The reverse iteration with the prefix works if there is no next prefix,
+/// like for the prefix of `0xFF` bytes, or there are no keys after the prefix.
+pub fn iter_reverse_prefix_at_the_end_of_key_space<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert_keys(&mut storage, &[&[1], &[0xFF], &[0xFF, 1], &[0xFF, 0xFF, 2]]);
+
+    let max_prefix = keys(
+        &storage,
+        Column::Metadata,
+        Some([0xFF, 0xFF].as_slice()),
+        None,
+        IterDirection::Reverse,
+    );
+    let last_prefix = keys(
+        &storage,
+        Column::Metadata,
+        Some([0xFF].as_slice()),
+        None,
+        IterDirection::Reverse,
+    );
+    let missing_prefix = keys(
+        &storage,
+        Column::Metadata,
+        Some([0xFE].as_slice()),
+        None,
+        IterDirection::Reverse,
+    );
+
+    assert_eq!(max_prefix, vec![vec![0xFF, 0xFF, 2]]);
+    assert_eq!(
+        last_prefix,
+        vec![vec![0xFF, 0xFF, 2], vec![0xFF, 1], vec![0xFF]]
+    );
+    assert!(missing_prefix.is_empty());
+}
+
+/// The iteration from the start key includes the start key.
+pub fn iter_start_in_both_directions<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert_keys(&mut storage, &[&[1], &[2], &[2, 1], &[3]]);
+
+    let forward = keys(
+        &storage,
+        Column::Metadata,
+        None,
+        Some([2].as_slice()),
+        IterDirection::Forward,
+    );
+    let reverse = keys(
+        &storage,
+        Column::Metadata,
+        None,
+        Some([2].as_slice()),
+        IterDirection::Reverse,
+    );
+    let missing_start = keys(
+        &storage,
+        Column::Metadata,
+        None,
+        Some([2, 0].as_slice()),
+        IterDirection::Reverse,
+    );
+
+    assert_eq!(forward, vec![vec![2], vec![2, 1], vec![3]]);
+    assert_eq!(reverse, vec![vec![2], vec![1]]);
+    assert_eq!(missing_start, vec![vec![2], vec![1]]);
+}
+
+/// The iteration with the prefix and the start stays within the prefix.
+/// If the start doesn't have the prefix, the iteration returns nothing.
+pub fn iter_prefix_and_start<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert_keys(&mut storage, &[&[0], &[1, 1], &[1, 2], &[1, 3], &[2]]);
+    let prefix = Some([1].as_slice());
+
+    let forward = keys(
+        &storage,
+        Column::Metadata,
+        prefix,
+        Some([1, 2].as_slice()),
+        IterDirection::Forward,
+    );
+    let reverse = keys(
+        &storage,
+        Column::Metadata,
+        prefix,
+        Some([1, 2].as_slice()),
+        IterDirection::Reverse,
+    );
+    let start_outside_of_prefix = keys(
+        &storage,
+        Column::Metadata,
+        prefix,
+        Some([0].as_slice()),
+        IterDirection::Forward,
+    );
+
+    assert_eq!(forward, vec![vec![1, 2], vec![1, 3]]);
+    assert_eq!(reverse, vec![vec![1, 2], vec![1, 1]]);
+    assert!(start_outside_of_prefix.is_empty());
+}
+
+/// All operations of the `Changes` are applied, including the operations
+/// for several columns.
+pub fn commit_changes_applies_all_operations<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    insert(&mut storage, Column::Metadata, &[1], &[1]);
+    let changes = Changes::from([
+        (
+            Column::Metadata as u32,
+            BTreeMap::from([
+                (vec![1], WriteOperation::Remove),
+                (vec![2], WriteOperation::Insert(Arc::new(vec![2]))),
+            ]),
+        ),
+        (
+            Column::Coins as u32,
+            BTreeMap::from([(vec![3], WriteOperation::Insert(Arc::new(vec![3])))]),
+        ),
+    ]);
+
+    storage.commit_changes(changes).unwrap();
+
+    assert_eq!(storage.get(&[1], Column::Metadata).unwrap(), None);
+    assert_eq!(
+        storage.get(&[2], Column::Metadata).unwrap(),
+        Some(Arc::new(vec![2]))
+    );
+    assert_eq!(
+        storage.get(&[3], Column::Coins).unwrap(),
+        Some(Arc::new(vec![3]))
+    );
+}
+
+/// The transaction with the [`ConflictPolicy::Fail`] rejects the `Changes`
+/// conflicting with the changes it already has, and the storage receives
+/// only the accepted ones. With the [`ConflictPolicy::Overwrite`] the
+/// latest changes win.
+pub fn conflicting_changes_follow_conflict_policy<S>(mut storage: S)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+{
+    let changes = |value: u8| {
+        Changes::from([(
+            Column::Metadata as u32,
+            BTreeMap::from([(vec![1], WriteOperation::Insert(Arc::new(vec![value])))]),
+        )])
+    };
+
+    let mut transaction = StorageTransaction::transaction(
+        &mut storage,
+        ConflictPolicy::Fail,
+        Default::default(),
+    );
+    transaction.commit_changes(changes(1)).unwrap();
+    let result = transaction.commit_changes(changes(2));
+    assert!(result.is_err());
+    transaction.commit().unwrap();
+    assert_eq!(
+        storage.get(&[1], Column::Metadata).unwrap(),
+        Some(Arc::new(vec![1]))
+    );
+
+    let mut transaction = StorageTransaction::transaction(
+        &mut storage,
+        ConflictPolicy::Overwrite,
+        Default::default(),
+    );
+    transaction.commit_changes(changes(3)).unwrap();
+    transaction.commit_changes(changes(4)).unwrap();
+    transaction.commit().unwrap();
+    assert_eq!(
+        storage.get(&[1], Column::Metadata).unwrap(),
+        Some(Arc::new(vec![4]))
+    );
+}
+
+/// The operation of the property test.
+#[derive(Debug, Clone)]
+enum Operation {
+    Insert(usize, Vec<u8>, Vec<u8>),
+    Remove(usize, Vec<u8>),
+}
+
+/// The keys are short and consist of the boundary bytes,
+/// so the operations often hit the same keys and prefixes.
+fn key_strategy() -> impl Strategy<Value = Vec<u8>> {
+    prop::collection::vec(prop::sample::select(vec![0u8, 1, 0xFF]), 0..4)
+}
+
+fn operation_strategy() -> impl Strategy<Value = Operation> {
+    let column = 0..COLUMNS.len();
+    prop_oneof![
+        (
+            column.clone(),
+            key_strategy(),
+            prop::collection::vec(any::<u8>(), 0..4)
+        )
+            .prop_map(|(column, key, value)| Operation::Insert(column, key, value)),
+        (column, key_strategy()).prop_map(|(column, key)| Operation::Remove(column, key)),
+    ]
+}
+
+/// Returns the entries of the `model` as the backend should iterate over them.
+fn model_entries(
+    model: &BTreeMap<Vec<u8>, Value>,
+    prefix: Option<&[u8]>,
+    start: Option<&[u8]>,
+    direction: IterDirection,
+) -> Vec<(Vec<u8>, Value)> {
+    if let (Some(prefix), Some(start)) = (prefix, start) {
+        if !start.starts_with(prefix) {
+            return vec![];
+        }
+    }
+
+    let entries = model
+        .iter()
+        .filter(|(key, _)| match prefix {
+            Some(prefix) => key.starts_with(prefix),
+            None => true,
+        })
+        .filter(|(key, _)| match (start, direction) {
+            (Some(start), IterDirection::Forward) => key.as_slice() >= start,
+            (Some(start), IterDirection::Reverse) => key.as_slice() <= start,
+            (None, _) => true,
+        })
+        .map(|(key, value)| (key.clone(), value.clone()));
+
+    match direction {
+        IterDirection::Forward => entries.collect(),
+        IterDirection::Reverse => entries.rev().collect(),
+    }
+}
+
+/// Applies the random batches of operations to the storage and to the `BTreeMap` model,
+/// and checks that the storage behaves as the model after each batch.
+/// The `storage` creates a new empty instance of the backend for each case.
+pub fn matches_btree_model<S, F>(storage: F)
+where
+    S: IterableStore<Column = Column> + Modifiable,
+    F: Fn() -> S,
+{
+    let batches =
+        prop::collection::vec(prop::collection::vec(operation_strategy(), 0..8), 1..6);
+    let queries = prop::collection::vec(
+        (
+            prop::option::of(key_strategy()),
+            prop::option::of(key_strategy()),
+        ),
+        1..8,
+    );
+
+    let mut runner = TestRunner::new(Config::with_cases(PROPERTY_CASES));
+    let result = runner.run(&(batches, queries), |(batches, queries)| {
+        let mut storage = storage();
+        let mut model: [BTreeMap<Vec<u8>, Value>; COLUMNS.len()] = Default::default();
+
+        for batch in batches {
+            let mut changes = Changes::default();
+            for operation in batch {
+                match operation {
+                    Operation::Insert(column, key, value) => {
+                        let value = Arc::new(value);
+                        model[column].insert(key.clone(), value.clone());
+                        changes
+                            .entry(COLUMNS[column] as u32)
+                            .or_default()
+                            .insert(key, WriteOperation::Insert(value));
+                    }
+                    Operation::Remove(column, key) => {
+                        model[column].remove(&key);
+                        changes
+                            .entry(COLUMNS[column] as u32)
+                            .or_default()
+                            .insert(key, WriteOperation::Remove);
+                    }
+                }
+            }
+            storage.commit_changes(changes).unwrap();
+
+            for (column, model) in COLUMNS.iter().zip(model.iter()) {
+                for (prefix, start) in &queries {
+                    if let Some(key) = start {
+                        prop_assert_eq!(
+                            storage.get(key, *column).unwrap(),
+                            model.get(key).cloned()
+                        );
+                        prop_assert_eq!(
+                            storage.size_of_value(key, *column).unwrap(),
+                            model.get(key).map(|value| value.len())
+                        );
+                    }
+
+                    for direction in [IterDirection::Forward, IterDirection::Reverse] {
+                        let prefix = prefix.as_deref();
+                        let start = start.as_deref();
+                        let entries = storage
+                            .iter_store(*column, prefix, start, direction)
+                            .collect::<Result<Vec<_>, _>>()
+                            .unwrap();
+                        prop_assert_eq!(
+                            entries,
+                            model_entries(model, prefix, start, direction),
+                            "prefix: {:?}, start: {:?}, direction: {:?}",
+                            prefix,
+                            start,
+                            direction
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    });
+
+    if let Err(error) = result {
+        panic!("The storage doesn't match the model: {error}");
+    }
+}
+
+/// The macro generates the conformance tests from the [`kv_store`](crate::test_helpers::kv_store)
+/// module for the key-value store backend. The `$storage` expression creates
+/// a new empty instance of the backend that implements [`IterableStore`]
+/// over the [`Column`] and [`Modifiable`].
+#[macro_export]
+macro_rules! kv_store_conformance_tests {
+    ($storage:expr) => {
+        $crate::kv_store_conformance_tests!(
+            @tests $storage;
+            get_returns_inserted_value,
+            insert_overrides_previous_value,
+            remove_deletes_value,
+            remove_of_missing_key_is_noop,
+            size_of_value_returns_value_length,
+            read_copies_value_into_buffer,
+            read_into_buffer_at_offset,
+            read_of_missing_key_returns_none,
+            read_into_smaller_buffer_fails,
+            supports_empty_key_and_value,
+            columns_are_independent,
+            iter_all_in_both_directions,
+            iter_prefix_in_both_directions,
+            iter_reverse_prefix_at_the_end_of_key_space,
+            iter_start_in_both_directions,
+            iter_prefix_and_start,
+            commit_changes_applies_all_operations,
+            conflicting_changes_follow_conflict_policy
+        );
+    };
+    (@tests $storage:expr; $($test:ident),*) => {
+        #[cfg(test)]
+        mod kv_store_conformance_tests {
+            use super::*;
+
+            $(
+                #[test]
+                fn $test() {
+                    $crate::test_helpers::kv_store::$test($storage)
+                }
+            )*
+
+            #[test]
+            fn matches_btree_model() {
+                $crate::test_helpers::kv_store::matches_btree_model(|| $storage)
+            }
+        }
+    };
+}
@@ -21,6 +21,9 @@ use std::{
     sync::Arc,
 };
 
+#[cfg(feature = "spill")]
+pub mod spilling;
+
 #[cfg(feature = "test-helpers")]
 use crate::{
     iter::{
@@ -47,7 +50,7 @@ pub trait AtomicView: Send + Sync {
     fn view_at(&self, height: &Self::Height) -> StorageResult<Self::View>;
 
     /// Returns the view of the storage for the latest block height.
-    fn latest_view(&self) -> Self::View;
+    fn latest_view(&self) -> StorageResult<Self::View>;
 }
 
 /// Storage transaction on top of the storage.
@@ -89,6 +92,11 @@ impl<S> StorageTransaction<S> {
         })
     }
 
+    /// Returns the reference to the changes to the storage.
+    pub fn changes(&self) -> &Changes {
+        &self.inner.changes
+    }
+
     /// Returns the changes to the storage.
     pub fn into_changes(self) -> Changes {
         self.inner.changes
@@ -0,0 +1,699 @@
+//! The storage transaction with the bounded memory usage.
+//!
+//! The [`SpillingTransaction`] works like the [`InMemoryTransaction`](super::InMemoryTransaction),
+//! but keeps the values of the changes in memory only until their total size exceeds
+//! the threshold. Values of the next changes are appended to a temporary file,
+//! which is removed when the transaction is dropped. Keys always stay in memory.
+//!
+//! The changes are committed atomically, like the changes of the `InMemoryTransaction`.
+//! The spilled values are read back from the file only on commit, so the memory
+//! is bounded while the transaction accumulates the changes.
+
+use crate::{
+    iter::{
+        iterator,
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        BatchOperations,
+        KVItem,
+        KeyValueInspect,
+        KeyValueMutate,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    structured_storage::StructuredStorage,
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        Modifiable,
+    },
+    Error as StorageError,
+    Result as StorageResult,
+};
+use itertools::{
+    EitherOrBoth,
+    Itertools,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fs::File,
+    io::{
+        Read,
+        Seek,
+        SeekFrom,
+        Write,
+    },
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+/// Storage transaction on top of the storage that spills the values to the disk.
+pub type SpillingStorageTransaction<S> = StructuredStorage<SpillingTransaction<S>>;
+
+/// The transaction accumulates changes over the storage and spills the values
+/// to the temporary file when their total size in memory exceeds the threshold.
+#[derive(Debug)]
+pub struct SpillingTransaction<S> {
+    changes: HashMap<u32, BTreeMap<Vec<u8>, SpillableOperation>>,
+    memory_usage: usize,
+    memory_threshold: usize,
+    spill_directory: Option<PathBuf>,
+    spill_file: Option<SpillFile>,
+    policy: ConflictPolicy,
+    storage: S,
+}
+
+/// The operation with the value either in memory or in the spill file.
+#[derive(Debug, Clone)]
+enum SpillableOperation {
+    Insert(Value),
+    Spilled { offset: u64, len: usize },
+    Remove,
+}
+
+impl SpillableOperation {
+    fn memory_usage(&self) -> usize {
+        match self {
+            Self::Insert(value) => value.len(),
+            Self::Spilled { .. } | Self::Remove => 0,
+        }
+    }
+}
+
+/// The append-only temporary file with the spilled values.
+/// Overwritten values are not reclaimed until the transaction is dropped.
+#[derive(Debug)]
+struct SpillFile {
+    file: Mutex<File>,
+    len: u64,
+}
+
+impl SpillFile {
+    fn create(directory: Option<&PathBuf>) -> StorageResult<Self> {
+        let file = match directory {
+            Some(directory) => tempfile::tempfile_in(directory),
+            None => tempfile::tempfile(),
+        }
+        .map_err(|e| {
+            StorageError::Other(anyhow::anyhow!("Failed to create the spill file: {e}"))
+        })?;
+
+        Ok(Self {
+            file: Mutex::new(file),
+            len: 0,
+        })
+    }
+
+    fn append(&mut self, value: &[u8]) -> StorageResult<u64> {
+        let offset = self.len;
+        let file = self.file.get_mut().map_err(|_| poisoned())?;
+        file.seek(SeekFrom::Start(offset))
+            .and_then(|_| file.write_all(value))
+            .map_err(|e| {
+                StorageError::Other(anyhow::anyhow!("Failed to spill the value: {e}"))
+            })?;
+        let len =
+            u64::try_from(value.len()).map_err(|e| StorageError::Other(e.into()))?;
+        self.len = offset.saturating_add(len);
+        Ok(offset)
+    }
+
+    fn read(&self, offset: u64, len: usize) -> StorageResult<Value> {
+        let mut file = self.file.lock().map_err(|_| poisoned())?;
+        let mut value = vec![0; len];
+        file.seek(SeekFrom::Start(offset))
+            .and_then(|_| file.read_exact(&mut value))
+            .map_err(|e| {
+                StorageError::Other(anyhow::anyhow!(
+                    "Failed to read the spilled value: {e}"
+                ))
+            })?;
+        Ok(Arc::new(value))
+    }
+}
+
+fn poisoned() -> StorageError {
+    StorageError::Other(anyhow::anyhow!("The lock of the spill file is poisoned"))
+}
+
+impl<S> SpillingStorageTransaction<S> {
+    /// Creates a new instance of the spilling transaction. Values are kept in memory
+    /// until their total size exceeds the `memory_threshold` in bytes.
+    pub fn spilling_transaction(
+        storage: S,
+        policy: ConflictPolicy,
+        memory_threshold: usize,
+    ) -> Self {
+        StructuredStorage::new(SpillingTransaction {
+            changes: Default::default(),
+            memory_usage: 0,
+            memory_threshold,
+            spill_directory: None,
+            spill_file: None,
+            policy,
+            storage,
+        })
+    }
+
+    /// Sets the directory for the spill file. By default, the file is created
+    /// in the temporary directory of the OS.
+    pub fn with_spill_directory(mut self, directory: PathBuf) -> Self {
+        self.inner.spill_directory = Some(directory);
+        self
+    }
+
+    /// Returns the total size of the values kept in memory.
+    pub fn memory_usage(&self) -> usize {
+        self.inner.memory_usage
+    }
+
+    /// Returns the number of bytes written into the spill file.
+    pub fn spilled_bytes(&self) -> u64 {
+        self.inner
+            .spill_file
+            .as_ref()
+            .map(|file| file.len)
+            .unwrap_or_default()
+    }
+
+    /// Returns the changes to the storage with the spilled values read back into memory.
+    pub fn into_changes(self) -> StorageResult<Changes> {
+        self.inner.into_changes().map(|(changes, _)| changes)
+    }
+}
+
+impl<Storage> SpillingStorageTransaction<Storage>
+where
+    Storage: Modifiable,
+{
+    /// Commits all changes into the storage at once, see [`Self::into_changes`].
+    pub fn commit(self) -> StorageResult<Storage> {
+        let (changes, mut storage) = self.inner.into_changes()?;
+        storage.commit_changes(changes)?;
+        Ok(storage)
+    }
+}
+
+impl<S> SpillingTransaction<S> {
+    /// Inserts the `operation` keeping the value in memory if it fits under the threshold.
+    /// Returns the previous operation for the `key`.
+    fn insert(
+        &mut self,
+        column: u32,
+        key: Vec<u8>,
+        operation: WriteOperation,
+    ) -> StorageResult<Option<SpillableOperation>> {
+        // The memory of the replaced value is released before the new value is placed.
+        let replaced_memory_usage = self
+            .changes
+            .get(&column)
+            .and_then(|btree| btree.get(&key))
+            .map(SpillableOperation::memory_usage)
+            .unwrap_or_default();
+        self.memory_usage = self.memory_usage.saturating_sub(replaced_memory_usage);
+
+        let operation = match operation {
+            WriteOperation::Insert(value) => {
+                let memory_usage = self.memory_usage.saturating_add(value.len());
+                if memory_usage <= self.memory_threshold {
+                    self.memory_usage = memory_usage;
+                    SpillableOperation::Insert(value)
+                } else {
+                    let spill_file = match self.spill_file.as_mut() {
+                        Some(spill_file) => spill_file,
+                        None => self
+                            .spill_file
+                            .insert(SpillFile::create(self.spill_directory.as_ref())?),
+                    };
+                    let offset = spill_file.append(value.as_ref())?;
+                    SpillableOperation::Spilled {
+                        offset,
+                        len: value.len(),
+                    }
+                }
+            }
+            WriteOperation::Remove => SpillableOperation::Remove,
+        };
+
+        let previous = self
+            .changes
+            .entry(column)
+            .or_default()
+            .insert(key, operation);
+        Ok(previous)
+    }
+
+    fn load(&self, operation: &SpillableOperation) -> StorageResult<Option<Value>> {
+        match operation {
+            SpillableOperation::Insert(value) => Ok(Some(value.clone())),
+            SpillableOperation::Spilled { offset, len } => {
+                let spill_file = self.spill_file.as_ref().ok_or_else(|| {
+                    StorageError::Other(anyhow::anyhow!("The spill file doesn't exist"))
+                })?;
+                spill_file.read(*offset, *len).map(Some)
+            }
+            SpillableOperation::Remove => Ok(None),
+        }
+    }
+
+    fn into_changes(mut self) -> StorageResult<(Changes, S)> {
+        let mut changes = Changes::default();
+        for (column, operations) in core::mem::take(&mut self.changes) {
+            let btree = changes.entry(column).or_default();
+            for (key, operation) in operations {
+                let operation = match self.load(&operation)? {
+                    Some(value) => WriteOperation::Insert(value),
+                    None => WriteOperation::Remove,
+                };
+                btree.insert(key, operation);
+            }
+        }
+        Ok((changes, self.storage))
+    }
+}
+
+impl<Storage> Modifiable for SpillingTransaction<Storage> {
+    fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+        for (column, value) in changes.into_iter() {
+            for (k, v) in value {
+                if self.policy == ConflictPolicy::Fail
+                    && self
+                        .changes
+                        .get(&column)
+                        .is_some_and(|btree| btree.contains_key(&k))
+                {
+                    return Err(anyhow::anyhow!(
+                        "Conflicting operation {v:?} for the {k:?}"
+                    )
+                    .into());
+                }
+                self.insert(column, k, v)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Column, S> KeyValueInspect for SpillingTransaction<S>
+where
+    Column: StorageColumn,
+    S: KeyValueInspect<Column = Column>,
+{
+    type Column = Column;
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        match self
+            .changes
+            .get(&column.id())
+            .and_then(|btree| btree.get(key))
+        {
+            Some(SpillableOperation::Insert(value)) => Ok(Some(value.len())),
+            Some(SpillableOperation::Spilled { len, .. }) => Ok(Some(*len)),
+            Some(SpillableOperation::Remove) => Ok(None),
+            None => self.storage.size_of_value(key, column),
+        }
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        if let Some(operation) = self
+            .changes
+            .get(&column.id())
+            .and_then(|btree| btree.get(key))
+        {
+            self.load(operation)
+        } else {
+            self.storage.get(key, column)
+        }
+    }
+}
+
+impl<Column, S> IterableStore for SpillingTransaction<S>
+where
+    Column: StorageColumn,
+    S: IterableStore<Column = Column>,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let inner = self.storage.iter_store(column, prefix, start, direction);
+
+        let Some(operations) = self.changes.get(&column.id()) else {
+            return inner
+        };
+
+        // The spilled values are read lazily while the iterator reaches them.
+        let overlay = iterator(operations, prefix, start, direction);
+
+        inner
+            .merge_join_by(overlay, move |item, (overlay_key, _)| {
+                let Ok((key, _)) = item else {
+                    // Errors are propagated as soon as possible.
+                    return core::cmp::Ordering::Less
+                };
+                match direction {
+                    IterDirection::Forward => key.as_slice().cmp(overlay_key.as_slice()),
+                    IterDirection::Reverse => overlay_key.as_slice().cmp(key.as_slice()),
+                }
+            })
+            .filter_map(|either| match either {
+                EitherOrBoth::Left(item) => Some(item),
+                EitherOrBoth::Right((key, operation))
+                | EitherOrBoth::Both(_, (key, operation)) => self
+                    .load(operation)
+                    .transpose()
+                    .map(|value| value.map(|value| (key.clone(), value))),
+            })
+            .into_boxed()
+    }
+}
+
+impl<Column, S> KeyValueMutate for SpillingTransaction<S>
+where
+    Column: StorageColumn,
+    S: KeyValueInspect<Column = Column>,
+{
+    fn put(
+        &mut self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<()> {
+        self.insert(column.id(), key.to_vec(), WriteOperation::Insert(value))?;
+        Ok(())
+    }
+
+    fn replace(
+        &mut self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        let previous =
+            self.insert(column.id(), key.to_vec(), WriteOperation::Insert(value))?;
+
+        match previous {
+            Some(operation) => self.load(&operation),
+            None => self.storage.get(key, column),
+        }
+    }
+
+    fn write(
+        &mut self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        self.put(key, column, Arc::new(buf.to_vec()))?;
+        Ok(buf.len())
+    }
+
+    fn take(&mut self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        let previous = self.insert(column.id(), key.to_vec(), WriteOperation::Remove)?;
+
+        match previous {
+            Some(operation) => self.load(&operation),
+            None => self.storage.get(key, column),
+        }
+    }
+
+    fn delete(&mut self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.insert(column.id(), key.to_vec(), WriteOperation::Remove)?;
+        Ok(())
+    }
+}
+
+impl<Column, S> BatchOperations for SpillingTransaction<S>
+where
+    Column: StorageColumn,
+    S: KeyValueInspect<Column = Column>,
+{
+    fn batch_write<I>(&mut self, column: Column, entries: I) -> StorageResult<()>
+    where
+        I: Iterator<Item = (Vec<u8>, WriteOperation)>,
+    {
+        for (key, operation) in entries {
+            self.insert(column.id(), key, operation)?;
+        }
+        Ok(())
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        column::Column,
+        structured_storage::test::InMemoryStorage,
+        tables::Messages,
+        transactional::WriteTransaction,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::entities::relayer::message::Message;
+
+    fn value(len: usize) -> Value {
+        Arc::new(vec![0xA; len])
+    }
+
+    #[test]
+    fn put__keeps_values_in_memory_until_threshold() {
+        // Given
+        let storage = InMemoryStorage::<Column>::default();
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &storage,
+            ConflictPolicy::Overwrite,
+            10,
+        );
+
+        // When
+        transaction.put(&[1], Column::Metadata, value(4)).unwrap();
+        transaction.put(&[2], Column::Metadata, value(6)).unwrap();
+
+        // Then
+        assert_eq!(transaction.memory_usage(), 10);
+        assert_eq!(transaction.spilled_bytes(), 0);
+    }
+
+    #[test]
+    fn put__spills_values_above_threshold() {
+        // Given
+        let storage = InMemoryStorage::<Column>::default();
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &storage,
+            ConflictPolicy::Overwrite,
+            10,
+        );
+        transaction.put(&[1], Column::Metadata, value(8)).unwrap();
+
+        // When
+        transaction.put(&[2], Column::Metadata, value(5)).unwrap();
+        transaction.put(&[3], Column::Metadata, value(7)).unwrap();
+
+        // Then
+        assert_eq!(transaction.memory_usage(), 8);
+        assert_eq!(transaction.spilled_bytes(), 12);
+        assert_eq!(
+            transaction.get(&[2], Column::Metadata).unwrap(),
+            Some(value(5))
+        );
+        assert_eq!(
+            transaction.size_of_value(&[3], Column::Metadata).unwrap(),
+            Some(7)
+        );
+        assert_eq!(
+            transaction.get(&[3], Column::Metadata).unwrap(),
+            Some(value(7))
+        );
+    }
+
+    #[test]
+    fn replace__returns_spilled_value_and_frees_memory() {
+        // Given
+        let storage = InMemoryStorage::<Column>::default();
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &storage,
+            ConflictPolicy::Overwrite,
+            4,
+        );
+        transaction.put(&[1], Column::Metadata, value(4)).unwrap();
+        transaction.put(&[2], Column::Metadata, value(8)).unwrap();
+
+        // When
+        let spilled = transaction.take(&[2], Column::Metadata).unwrap();
+        let in_memory = transaction
+            .replace(&[1], Column::Metadata, Arc::new(vec![1]))
+            .unwrap();
+
+        // Then
+        assert_eq!(spilled, Some(value(8)));
+        assert_eq!(in_memory, Some(value(4)));
+        assert_eq!(transaction.memory_usage(), 1);
+        assert_eq!(transaction.get(&[2], Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn commit__applies_in_memory_and_spilled_changes() {
+        // Given
+        let mut storage = InMemoryStorage::<Column>::default();
+        let mut write = storage.write_transaction();
+        write.put(&[3], Column::Metadata, value(1)).unwrap();
+        write.commit().unwrap();
+
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &mut storage,
+            ConflictPolicy::Overwrite,
+            2,
+        );
+        transaction.put(&[1], Column::Metadata, value(2)).unwrap();
+        transaction.put(&[2], Column::Metadata, value(3)).unwrap();
+        transaction.delete(&[3], Column::Metadata).unwrap();
+
+        // When
+        transaction.commit().unwrap();
+
+        // Then
+        assert_eq!(storage.get(&[1], Column::Metadata).unwrap(), Some(value(2)));
+        assert_eq!(storage.get(&[2], Column::Metadata).unwrap(), Some(value(3)));
+        assert_eq!(storage.get(&[3], Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn into_changes__reads_back_spilled_values() {
+        // Given
+        let storage = InMemoryStorage::<Column>::default();
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &storage,
+            ConflictPolicy::Overwrite,
+            4,
+        );
+        transaction.put(&[1], Column::Metadata, value(4)).unwrap();
+        transaction.put(&[2], Column::Metadata, value(3)).unwrap();
+        transaction.put(&[3], Column::Metadata, value(1)).unwrap();
+        transaction.delete(&[4], Column::Metadata).unwrap();
+
+        // When
+        let changes = transaction.into_changes().unwrap();
+
+        // Then
+        let mut expected = Changes::default();
+        let btree = expected.entry(Column::Metadata.id()).or_default();
+        btree.insert(vec![1], WriteOperation::Insert(value(4)));
+        btree.insert(vec![2], WriteOperation::Insert(value(3)));
+        btree.insert(vec![3], WriteOperation::Insert(value(1)));
+        btree.insert(vec![4], WriteOperation::Remove);
+        assert_eq!(changes, expected);
+    }
+
+    #[test]
+    fn iter_store__merges_spilled_values_with_storage() {
+        // Given
+        let mut storage = InMemoryStorage::<Column>::default();
+        let mut write = storage.write_transaction();
+        write.put(&[1], Column::Metadata, value(1)).unwrap();
+        write.put(&[3], Column::Metadata, value(1)).unwrap();
+        write.put(&[5], Column::Metadata, value(1)).unwrap();
+        write.commit().unwrap();
+
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &storage,
+            ConflictPolicy::Overwrite,
+            2,
+        );
+        transaction.put(&[2], Column::Metadata, value(2)).unwrap();
+        transaction.put(&[3], Column::Metadata, value(3)).unwrap();
+        transaction.delete(&[5], Column::Metadata).unwrap();
+
+        // When
+        let forward = transaction
+            .iter_store(Column::Metadata, None, None, IterDirection::Forward)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        let reverse = transaction
+            .iter_store(Column::Metadata, None, Some(&[2]), IterDirection::Reverse)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            forward,
+            vec![
+                (vec![1], value(1)),
+                (vec![2], value(2)),
+                (vec![3], value(3)),
+            ]
+        );
+        assert_eq!(reverse, vec![(vec![2], value(2)), (vec![1], value(1))]);
+    }
+
+    #[test]
+    fn commit_changes__fails_on_conflict_with_fail_policy() {
+        // Given
+        let storage = InMemoryStorage::<Column>::default();
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &storage,
+            ConflictPolicy::Fail,
+            0,
+        );
+        let mut changes = Changes::default();
+        changes
+            .entry(Column::Metadata.id())
+            .or_default()
+            .insert(vec![1], WriteOperation::Insert(value(1)));
+        transaction.commit_changes(changes.clone()).unwrap();
+
+        // When
+        let result = transaction.commit_changes(changes);
+
+        // Then
+        result.expect_err("Should fail because of the modification for the same key");
+    }
+
+    #[test]
+    fn storage_as_mut__works_with_spilled_values() {
+        // Given
+        let storage = InMemoryStorage::<Column>::default();
+        let mut transaction = SpillingStorageTransaction::spilling_transaction(
+            &storage,
+            ConflictPolicy::Overwrite,
+            0,
+        );
+        let message = Message::default();
+
+        // When
+        transaction
+            .storage_as_mut::<Messages>()
+            .insert(message.nonce(), &message)
+            .unwrap();
+
+        // Then
+        assert_eq!(transaction.memory_usage(), 0);
+        assert!(transaction.spilled_bytes() > 0);
+        let stored = transaction
+            .storage::<Messages>()
+            .get(message.nonce())
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(stored, message);
+    }
+}
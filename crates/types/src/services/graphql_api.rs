@@ -1,9 +1,13 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    fuel_merkle::sparse::proof::Proof,
+    fuel_types::{
+        Address,
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
 };
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
@@ -21,3 +25,25 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The sparse Merkle proof of the `key` in the tree of the `contract_id`.
+/// The proof proves the inclusion of the `value` if it is present,
+/// and the exclusion of the `key` otherwise.
+pub struct ContractProof<Key, Value> {
+    /// The contract that owns the tree.
+    pub contract_id: ContractId,
+    /// The key of the entry in the contract's tree.
+    pub key: Key,
+    /// The value of the entry, `None` if the contract doesn't have it.
+    pub value: Option<Value>,
+    /// The root of the contract's tree the proof is generated against.
+    pub root: Bytes32,
+    /// The proof of the entry.
+    pub proof: Proof,
+}
+
+/// The alias for the `ContractProof` of the storage slot.
+pub type ContractStorageProof = ContractProof<Bytes32, Vec<u8>>;
+
+/// The alias for the `ContractProof` of the asset balance.
+pub type ContractBalanceProof = ContractProof<AssetId, u64>;
@@ -0,0 +1,227 @@
+#![allow(non_snake_case)]
+
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        Asset,
+        TransactionStatus,
+    },
+};
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::*,
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+};
+use itertools::Itertools;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+const SEED: u64 = 2322;
+const MINT_AMOUNT: u32 = 100;
+
+/// The contract mints `MINT_AMOUNT` coins of the asset with the sub id equal to
+/// the contract id, which is the first field of the call frame.
+fn minting_contract() -> Vec<u8> {
+    [
+        op::movi(0x10, MINT_AMOUNT),
+        op::mint(0x10, RegId::FP),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn call_contract(rng: &mut StdRng, contract_id: ContractId) -> Script {
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = contract_id
+        .iter()
+        .copied()
+        .chain((0 as Word).to_be_bytes())
+        .chain((0 as Word).to_be_bytes())
+        .collect();
+
+    TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1_000,
+            Default::default(),
+            Default::default(),
+        )
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize()
+}
+
+#[tokio::test]
+async fn asset__returns_supply_of_asset_minted_by_contract() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(minting_contract(), vec![], None);
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let transactions = (0..2)
+        .map(|_| call_contract(&mut rng, contract_id))
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    let mut first_mint_height = None;
+    for tx in transactions {
+        let tx = tx.into();
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        let TransactionStatus::Success { block_height, .. } = status else {
+            panic!("The transaction should succeed: {status:?}");
+        };
+        first_mint_height.get_or_insert(block_height);
+    }
+    let sub_id = Bytes32::new(*contract_id);
+    let asset_id = contract_id.asset_id(&sub_id);
+
+    // When
+    let asset = client.asset(&asset_id).await.unwrap();
+
+    // Then
+    assert_eq!(
+        asset,
+        Some(Asset {
+            id: asset_id,
+            contract_id: Some(contract_id),
+            sub_id: Some(sub_id),
+            first_mint_height: first_mint_height.unwrap(),
+            total_supply: 2 * u128::from(MINT_AMOUNT),
+        })
+    );
+}
+
+#[tokio::test]
+async fn asset__returns_none_for_unknown_asset() {
+    // Given
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = TestSetupBuilder::new(SEED).finalize().await;
+
+    // When
+    let asset = client.asset(&AssetId::new([7; 32])).await.unwrap();
+
+    // Then
+    assert_eq!(asset, None);
+}
+
+#[tokio::test]
+async fn asset__returns_base_asset_registered_at_genesis() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let transactions = (1..=3)
+        .map(|_| {
+            TransactionBuilder::script(vec![], vec![])
+                .add_unsigned_coin_input(
+                    SecretKey::random(&mut rng),
+                    rng.gen(),
+                    1_000,
+                    Default::default(),
+                    Default::default(),
+                )
+                .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    let chain_info = client.chain_info().await.unwrap();
+    let base_asset_id = *chain_info.consensus_parameters.base_asset_id();
+    let genesis_supply: u128 = test_builder
+        .initial_coins
+        .iter()
+        .filter(|coin| coin.asset_id == base_asset_id)
+        .map(|coin| u128::from(coin.amount))
+        .sum();
+
+    // When
+    let asset = client.asset(&base_asset_id).await.unwrap();
+
+    // Then
+    assert_eq!(
+        asset,
+        Some(Asset {
+            id: base_asset_id,
+            contract_id: None,
+            sub_id: None,
+            first_mint_height: BlockHeight::default(),
+            total_supply: genesis_supply,
+        })
+    );
+}
+
+#[tokio::test]
+async fn assets__returns_assets_minted_by_contract() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, minting_contract_id) =
+        test_builder.setup_contract(minting_contract(), vec![], None);
+    let (_, other_contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let tx = call_contract(&mut StdRng::seed_from_u64(SEED), minting_contract_id);
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    client.submit_and_await_commit(&tx.into()).await.unwrap();
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // When
+    let minted = client
+        .assets(&minting_contract_id, request.clone())
+        .await
+        .unwrap();
+    let not_minted = client.assets(&other_contract_id, request).await.unwrap();
+
+    // Then
+    let asset_ids = minted.results.iter().map(|asset| asset.id).collect_vec();
+    assert_eq!(
+        asset_ids,
+        vec![minting_contract_id.asset_id(&Bytes32::new(*minting_contract_id))]
+    );
+    assert!(!minted.has_next_page);
+    assert!(not_minted.results.is_empty());
+}
@@ -31,6 +31,7 @@ use fuel_core_types::{
         Input,
         Output,
         TransactionBuilder,
+        UtxoId,
     },
 };
 
@@ -236,3 +237,106 @@ async fn first_5_balances() {
         assert_eq!(balances[i].amount, 300);
     }
 }
+
+#[tokio::test]
+async fn balances_follow_the_spent_and_created_coins_and_messages() {
+    let owner = Address::from([10u8; 32]);
+    let recipient = Address::from([20u8; 32]);
+    let base_asset_id = AssetId::BASE;
+    let other_asset_id = AssetId::new([2u8; 32]);
+
+    // setup config
+    let mut coin_generator = CoinConfigGenerator::new();
+    let base_coin = CoinConfig {
+        owner,
+        amount: 100,
+        asset_id: base_asset_id,
+        ..coin_generator.generate()
+    };
+    let other_coin = CoinConfig {
+        owner,
+        amount: 30,
+        asset_id: other_asset_id,
+        ..coin_generator.generate()
+    };
+    let other_utxo_id = UtxoId::new(other_coin.tx_id, other_coin.output_index);
+    let message = MessageConfig {
+        sender: owner,
+        recipient: owner,
+        nonce: 0u64.into(),
+        amount: 60,
+        data: vec![],
+        da_height: DaBlockHeight::from(0usize),
+    };
+    let state_config = StateConfig {
+        coins: vec![base_coin, other_coin],
+        messages: vec![message.clone()],
+        ..Default::default()
+    };
+    let config = Config {
+        snapshot_reader: SnapshotReader::local_testnet().with_state_config(state_config),
+        ..Config::local_node()
+    };
+
+    // setup server & client
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // spend the message and the coin of the other asset
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::message_coin_signed(
+            message.sender,
+            message.recipient,
+            message.amount,
+            message.nonce,
+            0,
+        ))
+        .add_input(Input::coin_signed(
+            other_utxo_id,
+            owner,
+            30,
+            other_asset_id,
+            Default::default(),
+            0,
+        ))
+        .add_output(Output::coin(recipient, 10, base_asset_id))
+        .add_output(Output::coin(recipient, 5, other_asset_id))
+        .add_output(Output::change(owner, 0, base_asset_id))
+        .add_output(Output::change(owner, 0, other_asset_id))
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // check the balances of both accounts
+    let all_balances = |owner| {
+        let client = &client;
+        async move {
+            client
+                .balances(
+                    &owner,
+                    PaginationRequest {
+                        cursor: None,
+                        results: 10,
+                        direction: PageDirection::Forward,
+                    },
+                )
+                .await
+                .unwrap()
+                .results
+                .into_iter()
+                .map(|balance| (balance.asset_id, balance.amount))
+                .collect::<Vec<_>>()
+        }
+    };
+    assert_eq!(
+        all_balances(owner).await,
+        vec![(base_asset_id, 150), (other_asset_id, 25)]
+    );
+    assert_eq!(
+        all_balances(recipient).await,
+        vec![(base_asset_id, 10), (other_asset_id, 5)]
+    );
+    let balance = client.balance(&owner, Some(&other_asset_id)).await.unwrap();
+    assert_eq!(balance, 25);
+}
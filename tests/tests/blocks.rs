@@ -394,3 +394,96 @@ mod full_block {
         assert_eq!(block.transactions.len(), 2 /* mint + our tx */);
     }
 }
+
+mod subscriptions {
+    use super::*;
+    use fuel_core_client::client::types::{
+        block::Header,
+        Block,
+    };
+    use futures::StreamExt;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    async fn heights<S, T>(stream: S, count: usize, height: impl Fn(T) -> u32) -> Vec<u32>
+    where
+        S: futures::Stream<Item = std::io::Result<T>>,
+    {
+        tokio::time::timeout(TIMEOUT, stream.take(count).collect::<Vec<_>>())
+            .await
+            .expect("Should receive the blocks before the timeout")
+            .into_iter()
+            .map(|item| height(item.unwrap()))
+            .collect()
+    }
+
+    /// The subscriptions connect on the first poll of the stream, so the blocks
+    /// are produced in the background until every stream receives them.
+    fn produce_blocks_in_background(client: FuelClient) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                client.produce_blocks(1, None).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+    }
+
+    fn assert_consecutive(heights: &[u32]) {
+        assert!(
+            heights.windows(2).all(|pair| pair[1] == pair[0] + 1),
+            "The heights should be consecutive: {heights:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn subscriptions_stream_produced_blocks() {
+        let srv = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        let new_blocks = client.subscribe_new_blocks(None).await.unwrap();
+        let new_headers = client.subscribe_new_headers(None).await.unwrap();
+        let finalized_blocks = client.subscribe_finalized_blocks(None).await.unwrap();
+
+        let producer = produce_blocks_in_background(client.clone());
+        let new_blocks = heights(new_blocks, 3, |block: Block| block.header.height).await;
+        let new_headers = heights(new_headers, 3, |header: Header| header.height).await;
+        let finalized_blocks =
+            heights(finalized_blocks, 3, |block: Block| block.header.height).await;
+        producer.abort();
+
+        assert_consecutive(&new_blocks);
+        assert_consecutive(&new_headers);
+        assert_consecutive(&finalized_blocks);
+    }
+
+    #[tokio::test]
+    async fn subscriptions_resume_from_the_given_height() {
+        let srv = FuelService::from_database(Database::default(), Config::local_node())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        client.produce_blocks(3, None).await.unwrap();
+        let new_blocks = client.subscribe_new_blocks(Some(2.into())).await.unwrap();
+        let new_headers = client.subscribe_new_headers(Some(2.into())).await.unwrap();
+        let finalized_blocks = client
+            .subscribe_finalized_blocks(Some(2.into()))
+            .await
+            .unwrap();
+
+        client.produce_blocks(1, None).await.unwrap();
+
+        assert_eq!(
+            heights(new_blocks, 3, |block: Block| block.header.height).await,
+            vec![2, 3, 4]
+        );
+        assert_eq!(
+            heights(new_headers, 3, |header: Header| header.height).await,
+            vec![2, 3, 4]
+        );
+        assert_eq!(
+            heights(finalized_blocks, 3, |block: Block| block.header.height).await,
+            vec![2, 3, 4]
+        );
+    }
+}
@@ -175,6 +175,124 @@ async fn test_contract_balance(
     assert_eq!(balance, test_balance);
 }
 
+#[tokio::test]
+async fn test_contract_balance_proof() {
+    use fuel_core::chain_config::ContractBalanceConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let present_asset = AssetId::new([1u8; 32]);
+    let missing_asset = AssetId::new([2u8; 32]);
+    let (_, contract_id) = test_builder.setup_contract(
+        vec![],
+        vec![ContractBalanceConfig {
+            asset_id: present_asset,
+            amount: 100,
+        }],
+        None,
+    );
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let present = client
+        .contract_balance_proof(&contract_id, &present_asset)
+        .await
+        .unwrap();
+    let missing = client
+        .contract_balance_proof(&contract_id, &missing_asset)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(present.amount, Some(100));
+    assert!(present.proof.is_inclusion());
+    assert!(present.verify(&present.root));
+    assert_eq!(missing.amount, None);
+    assert!(missing.proof.is_exclusion());
+    assert!(missing.verify(&present.root));
+}
+
+#[tokio::test]
+async fn test_contract_storage_proof_of_empty_slot() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let proof = client
+        .contract_storage_proof(&contract_id, &key(1))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(proof.value, None);
+    assert!(proof.proof.is_exclusion());
+    assert!(proof.verify(&proof.root));
+}
+
+#[tokio::test]
+async fn test_contract_storage_proof_of_present_slot() {
+    use fuel_core::chain_config::ContractStateConfig;
+    use fuel_core_client::client::types::verify_sparse_merkle_proof;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let value = vec![7u8; 32];
+    test_builder
+        .contracts
+        .get_mut(&contract_id)
+        .unwrap()
+        .states
+        .push(ContractStateConfig {
+            key: key(1),
+            value: value.clone(),
+        });
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let proof = client
+        .contract_storage_proof(&contract_id, &key(1))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(proof.value.as_deref(), Some(value.as_slice()));
+    assert!(proof.proof.is_inclusion());
+    let slot = [contract_id.as_ref(), key(1).as_ref()].concat();
+    assert!(verify_sparse_merkle_proof(
+        &proof.proof,
+        &proof.root,
+        &slot,
+        Some(&value)
+    ));
+    assert!(!verify_sparse_merkle_proof(
+        &proof.proof,
+        &proof.root,
+        &slot,
+        Some(&[8u8; 32])
+    ));
+    assert!(!verify_sparse_merkle_proof(
+        &proof.proof,
+        &proof.root,
+        &slot,
+        None
+    ));
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_5_contract_balances(
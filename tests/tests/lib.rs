@@ -1,6 +1,7 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+mod assets;
 mod balances;
 mod blocks;
 mod chain;
@@ -19,6 +20,8 @@ mod messages;
 mod metrics;
 mod node_info;
 mod poa;
+mod read_replica;
+mod receipts;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod snapshot;
@@ -0,0 +1,112 @@
+#![allow(non_snake_case)]
+
+use fuel_core::service::{
+    Config,
+    DbType,
+    FuelService,
+    ServiceTrait,
+};
+use fuel_core_client::client::{
+    types::Block,
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::TransactionBuilder;
+use futures::StreamExt;
+use std::time::Duration;
+use tempfile::TempDir;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+async fn primary_and_replica(
+    primary_dir: &TempDir,
+    replica_dir: &TempDir,
+) -> (FuelService, FuelService) {
+    let mut primary_config = Config::local_node();
+    primary_config.combined_db_config.database_path = primary_dir.path().to_path_buf();
+    primary_config.combined_db_config.database_type = DbType::RocksDb;
+    let primary = FuelService::new_node(primary_config).await.unwrap();
+
+    let mut replica_config = Config::local_node();
+    replica_config.combined_db_config.database_path = replica_dir.path().to_path_buf();
+    replica_config.combined_db_config.database_type = DbType::RocksDb;
+    replica_config.combined_db_config.read_replica_of =
+        Some(primary_dir.path().to_path_buf());
+    replica_config.read_replica_catch_up_interval = Duration::from_millis(100);
+    let replica = FuelService::new_node(replica_config).await.unwrap();
+
+    (primary, replica)
+}
+
+async fn heights<S>(stream: S) -> Vec<u32>
+where
+    S: futures::Stream<Item = std::io::Result<Block>>,
+{
+    tokio::time::timeout(TIMEOUT, stream.take(2).collect::<Vec<_>>())
+        .await
+        .expect("The replica should stream the blocks")
+        .into_iter()
+        .map(|block| block.unwrap().header.height)
+        .collect()
+}
+
+#[tokio::test]
+async fn read_replica__streams_blocks_committed_by_the_primary() {
+    // Given
+    let primary_dir = TempDir::new().unwrap();
+    let replica_dir = TempDir::new().unwrap();
+    let (primary, replica) = primary_and_replica(&primary_dir, &replica_dir).await;
+    let primary_client = FuelClient::from(primary.bound_address);
+    let replica_client = FuelClient::from(replica.bound_address);
+    let new_blocks = replica_client.subscribe_new_blocks(None).await.unwrap();
+    let finalized_blocks = replica_client
+        .subscribe_finalized_blocks(None)
+        .await
+        .unwrap();
+
+    // When
+    // The subscriptions connect on the first poll of the stream,
+    // so the primary produces blocks in the background.
+    let producer = tokio::spawn(async move {
+        loop {
+            primary_client.produce_blocks(1, None).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    });
+
+    // Then
+    let new_heights = heights(new_blocks).await;
+    let finalized_heights = heights(finalized_blocks).await;
+    producer.abort();
+    assert_eq!(new_heights[1], new_heights[0] + 1);
+    assert_eq!(finalized_heights[1], finalized_heights[0] + 1);
+    let block = replica_client
+        .block_by_height(finalized_heights[1].into())
+        .await
+        .unwrap();
+    assert!(block.is_some());
+
+    replica.stop_and_await().await.unwrap();
+    primary.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn read_replica__rejects_submitted_transactions() {
+    // Given
+    let primary_dir = TempDir::new().unwrap();
+    let replica_dir = TempDir::new().unwrap();
+    let (primary, replica) = primary_and_replica(&primary_dir, &replica_dir).await;
+    let replica_client = FuelClient::from(replica.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1000000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    // When
+    let result = replica_client.submit(&tx).await;
+
+    // Then
+    assert!(result.is_err());
+
+    replica.stop_and_await().await.unwrap();
+    primary.stop_and_await().await.unwrap();
+}
@@ -0,0 +1,324 @@
+#![allow(non_snake_case)]
+
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        receipt::ReceiptType,
+        ContractReceipt,
+        ReceiptFilter,
+        TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::*,
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+};
+use futures::StreamExt;
+use itertools::Itertools;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+const SEED: u64 = 2323;
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The contract emits two `Log` receipts with the ids `1` and `2` in the `rb`.
+fn logging_contract() -> Vec<u8> {
+    [
+        op::movi(0x10, 1),
+        op::log(RegId::ZERO, 0x10, RegId::ZERO, RegId::ZERO),
+        op::movi(0x10, 2),
+        op::log(RegId::ZERO, 0x10, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn call_contract(rng: &mut StdRng, contract_id: ContractId) -> Script {
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = contract_id
+        .iter()
+        .copied()
+        .chain((0 as Word).to_be_bytes())
+        .chain((0 as Word).to_be_bytes())
+        .collect();
+
+    TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1_000,
+            Default::default(),
+            Default::default(),
+        )
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize()
+}
+
+/// Sets up the node with the logging contract and `calls` transactions calling it.
+async fn setup(calls: usize) -> (TestContext, ContractId, Vec<Transaction>) {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(logging_contract(), vec![], None);
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let transactions = (0..calls)
+        .map(|_| call_contract(&mut rng, contract_id))
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let context = test_builder.finalize().await;
+    let transactions = transactions.into_iter().map(Into::into).collect();
+
+    (context, contract_id, transactions)
+}
+
+async fn submit(client: &FuelClient, transactions: &[Transaction]) -> Vec<BlockHeight> {
+    let mut heights = vec![];
+    for tx in transactions {
+        let status = client.submit_and_await_commit(tx).await.unwrap();
+        let TransactionStatus::Success { block_height, .. } = status else {
+            panic!("The transaction should succeed: {status:?}");
+        };
+        heights.push(block_height);
+    }
+    heights
+}
+
+fn request() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    }
+}
+
+fn heights_and_log_ids(receipts: &[ContractReceipt]) -> Vec<(BlockHeight, Word)> {
+    receipts
+        .iter()
+        .map(|receipt| {
+            let log_id = receipt.receipt.rb().expect("The receipt should be a log");
+            (receipt.block_height, log_id)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn contract_receipts__returns_logs_of_the_contract() {
+    // Given
+    let (context, contract_id, transactions) = setup(2).await;
+    let heights = submit(&context.client, &transactions).await;
+
+    // When
+    let all = context
+        .client
+        .contract_receipts(ReceiptFilter::new(contract_id), request())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        heights_and_log_ids(&all.results),
+        vec![
+            (heights[0], 1),
+            (heights[0], 2),
+            (heights[1], 1),
+            (heights[1], 2)
+        ]
+    );
+    assert!(all
+        .results
+        .iter()
+        .all(|receipt| receipt.receipt.id() == Some(&contract_id)));
+    assert!(!all.has_next_page);
+}
+
+#[tokio::test]
+async fn contract_receipts__applies_the_filter() {
+    // Given
+    let (context, contract_id, transactions) = setup(2).await;
+    let heights = submit(&context.client, &transactions).await;
+    let by_log_id = ReceiptFilter {
+        receipt_type: Some(ReceiptType::Log),
+        log_id: Some(2),
+        ..ReceiptFilter::new(contract_id)
+    };
+    let by_height = ReceiptFilter {
+        from_height: Some(heights[1]),
+        to_height: Some(heights[1]),
+        ..ReceiptFilter::new(contract_id)
+    };
+    let by_other_type = ReceiptFilter {
+        receipt_type: Some(ReceiptType::LogData),
+        ..ReceiptFilter::new(contract_id)
+    };
+
+    // When
+    let client = &context.client;
+    let by_log_id = client
+        .contract_receipts(by_log_id, request())
+        .await
+        .unwrap();
+    let by_height = client
+        .contract_receipts(by_height, request())
+        .await
+        .unwrap();
+    let by_other_type = client
+        .contract_receipts(by_other_type, request())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        heights_and_log_ids(&by_log_id.results),
+        vec![(heights[0], 2), (heights[1], 2)]
+    );
+    assert_eq!(
+        heights_and_log_ids(&by_height.results),
+        vec![(heights[1], 1), (heights[1], 2)]
+    );
+    assert!(by_other_type.results.is_empty());
+}
+
+#[tokio::test]
+async fn contract_receipts__paginates_logs() {
+    // Given
+    let (context, contract_id, transactions) = setup(2).await;
+    submit(&context.client, &transactions).await;
+    let filter = ReceiptFilter::new(contract_id);
+    let all = context
+        .client
+        .contract_receipts(filter.clone(), request())
+        .await
+        .unwrap();
+
+    // When
+    let first_page = context
+        .client
+        .contract_receipts(
+            filter.clone(),
+            PaginationRequest {
+                cursor: None,
+                results: 3,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = context
+        .client
+        .contract_receipts(
+            filter,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 3,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+    let paginated = first_page
+        .results
+        .into_iter()
+        .chain(second_page.results)
+        .collect_vec();
+    assert_eq!(paginated, all.results);
+}
+
+#[tokio::test]
+async fn subscribe_logs__streams_logs_of_new_blocks() {
+    // Given
+    let (context, contract_id, transactions) = setup(10).await;
+    let filter = ReceiptFilter {
+        log_id: Some(1),
+        ..ReceiptFilter::new(contract_id)
+    };
+    let logs = context.client.subscribe_logs(filter).await.unwrap();
+
+    // When
+    // The subscription connects on the first poll of the stream,
+    // so the transactions are submitted in the background.
+    let client = context.client.clone();
+    let submitter = tokio::spawn(async move {
+        for tx in transactions {
+            submit(&client, &[tx]).await;
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    // Then
+    let logs: Vec<ContractReceipt> =
+        tokio::time::timeout(TIMEOUT, logs.take(2).collect::<Vec<_>>())
+            .await
+            .expect("The subscription should stream the logs")
+            .into_iter()
+            .map(|log| log.unwrap())
+            .collect();
+    submitter.abort();
+    let [(first_height, 1), (second_height, 1)] = heights_and_log_ids(&logs)[..] else {
+        panic!("The subscription should stream the logs with the id 1: {logs:?}");
+    };
+    assert_eq!(*second_height, *first_height + 1);
+}
+
+#[tokio::test]
+async fn subscribe_logs__resumes_from_the_given_height() {
+    // Given
+    let (context, contract_id, transactions) = setup(3).await;
+    let (before, after) = transactions.split_at(2);
+    let mut heights = submit(&context.client, before).await;
+    let filter = ReceiptFilter {
+        log_id: Some(2),
+        from_height: Some(heights[1]),
+        ..ReceiptFilter::new(contract_id)
+    };
+    let logs = context.client.subscribe_logs(filter).await.unwrap();
+
+    // When
+    heights.extend(submit(&context.client, after).await);
+
+    // Then
+    let logs: Vec<ContractReceipt> =
+        tokio::time::timeout(TIMEOUT, logs.take(2).collect::<Vec<_>>())
+            .await
+            .expect("The subscription should stream the logs")
+            .into_iter()
+            .map(|log| log.unwrap())
+            .collect();
+    assert_eq!(
+        heights_and_log_ids(&logs),
+        vec![(heights[1], 2), (heights[2], 2)]
+    );
+}
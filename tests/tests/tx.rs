@@ -1,4 +1,7 @@
-use crate::helpers::TestContext;
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core::{
     schema::tx::receipt::all_receipts,
     service::{
@@ -646,6 +649,104 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[test_case::test_case(PageDirection::Forward; "forward")]
+#[test_case::test_case(PageDirection::Backward; "backward")]
+#[tokio::test]
+async fn get_transactions_by_contract_supports_cursor(direction: PageDirection) {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let (_, other_contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let mut transactions = (0..3)
+        .map(|val| create_contract_call_tx(val, contract_id))
+        .collect_vec();
+    transactions.push(create_contract_call_tx(3, other_contract_id));
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    for tx in &transactions {
+        client
+            .submit_and_await_commit(&tx.clone().into())
+            .await
+            .unwrap();
+    }
+    let mut expected = transactions[..3]
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect_vec();
+    if direction == PageDirection::Backward {
+        expected.reverse();
+    }
+
+    // When
+    let first_page = client
+        .transactions_by_contract(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .transactions_by_contract(
+            &contract_id,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let transactions = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|tx| {
+            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+            tx.transaction.id(&ChainId::default())
+        })
+        .collect_vec();
+    assert_eq!(transactions, expected);
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+}
+
+// add random val for unique coin input
+fn create_contract_call_tx(val: u64, contract_id: ContractId) -> Script {
+    let mut rng = StdRng::seed_from_u64(val);
+
+    TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1_000_000,
+        Default::default(),
+        Default::default(),
+    )
+    .add_input(Input::contract(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        contract_id,
+    ))
+    .add_output(Output::contract(1, Default::default(), Default::default()))
+    .finalize()
+}
+
 // add random val for unique tx
 fn create_mock_tx(val: u64) -> Transaction {
     let mut rng = StdRng::seed_from_u64(val);